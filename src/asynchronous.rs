@@ -0,0 +1,86 @@
+//! Parsing large batches of events without blocking an async runtime
+
+use jiff::Zoned;
+
+use crate::{EventParseError, NewEvent};
+
+/// How many lines each blocking task of [`NewEvent::parse_many_async`] parses
+const CHUNK_SIZE: usize = 256;
+
+impl NewEvent {
+    /// Parses each of `lines` as with [`NewEvent::parse_at_time`], using tokio's blocking thread
+    /// pool so that large batches don't stall the async runtime. The lines are parsed in chunks,
+    /// and the results are returned in the same order as the lines.
+    /// Requires the `tokio` feature.
+    /// ```rust
+    /// use jiff::civil::date;
+    /// use nlcep::NewEvent;
+    /// let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+    /// let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+    /// let lines = ["Retro tomorrow 14:00 @ A769", "no date here"];
+    /// let events = runtime.block_on(NewEvent::parse_many_async(lines, now));
+    /// assert_eq!(events[0].as_ref().unwrap().location, Some("A769".to_owned()));
+    /// assert!(events[1].is_err());
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if called outside of a tokio runtime, or if parsing panics.
+    pub async fn parse_many_async<I>(lines: I, now: Zoned) -> Vec<Result<Self, EventParseError>>
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        let lines: Vec<String> = lines.into_iter().map(Into::into).collect();
+        let tasks: Vec<_> = lines
+            .chunks(CHUNK_SIZE)
+            .map(|chunk| {
+                let chunk = chunk.to_vec();
+                let now = now.clone();
+                tokio::task::spawn_blocking(move || {
+                    chunk
+                        .iter()
+                        .map(|line| Self::parse_at_time(line, now.clone()))
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+        let mut events = Vec::with_capacity(lines.len());
+        for task in tasks {
+            match task.await {
+                Ok(parsed) => events.extend(parsed),
+                Err(e) => std::panic::resume_unwind(e.into_panic()),
+            }
+        }
+        events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use jiff::civil::date;
+
+    #[test]
+    fn parse_many_async_matches_sync() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let inputs = [
+            "Retro tomorrow 14:00 @ A769",
+            "John's birthday 18.11.",
+            "no date here",
+            "Dentist 3.6. 25:00",
+        ];
+        let lines: Vec<String> = (0..CHUNK_SIZE * 2 + 3)
+            .map(|i| inputs[i % inputs.len()].to_owned())
+            .collect();
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        let events = runtime.block_on(NewEvent::parse_many_async(lines.clone(), now.clone()));
+        let expected: Vec<_> = lines
+            .iter()
+            .map(|line| NewEvent::parse_at_time(line, now.clone()))
+            .collect();
+        assert_eq!(events, expected);
+    }
+}
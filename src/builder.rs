@@ -0,0 +1,197 @@
+//! Constructing a [`NewEvent`] without parsing
+
+use jiff::{
+    civil::{Date, Time},
+    Span,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::NewEvent;
+
+/// Builds a [`NewEvent`] field by field, see [`NewEvent::builder`].
+/// Fields without a setter are left empty.
+/// ```rust
+/// use jiff::{civil::date, ToSpan};
+/// use nlcep::NewEvent;
+/// let event = NewEvent::builder()
+///     .summary("Design review")
+///     .date(date(2024, 6, 2))
+///     .time(jiff::civil::time(11, 0, 0, 0))
+///     .location("A769")
+///     .duration(90.minutes())
+///     .build()
+///     .unwrap();
+/// assert_eq!(event.end_datetime(), Some(date(2024, 6, 2).at(12, 30, 0, 0)));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct NewEventBuilder {
+    /// Summary of the event, required
+    summary: Option<String>,
+    /// Date of the event, required
+    date: Option<Date>,
+    /// Time of the event
+    time: Option<Time>,
+    /// Where the event takes place
+    location: Option<String>,
+    /// For how long the event goes on
+    duration: Option<Span>,
+}
+
+impl NewEventBuilder {
+    /// Sets the summary, which must contain something other than whitespace
+    #[must_use]
+    pub fn summary(mut self, summary: impl Into<String>) -> Self {
+        self.summary = Some(summary.into());
+        self
+    }
+
+    /// Sets the date the event starts on
+    #[must_use]
+    pub const fn date(mut self, date: Date) -> Self {
+        self.date = Some(date);
+        self
+    }
+
+    /// Sets the time the event starts at, leaving it unspecified if never called
+    #[must_use]
+    pub const fn time(mut self, time: Time) -> Self {
+        self.time = Some(time);
+        self
+    }
+
+    /// Sets where the event takes place
+    #[must_use]
+    pub fn location(mut self, location: impl Into<String>) -> Self {
+        self.location = Some(location.into());
+        self
+    }
+
+    /// Sets for how long the event goes on
+    #[must_use]
+    pub const fn duration(mut self, duration: Span) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Builds the event, checking that it has a summary and a date and that it doesn't end before
+    /// it starts or after the latest representable time.
+    pub fn build(self) -> Result<NewEvent, NewEventBuildError> {
+        let summary = self
+            .summary
+            .map(|summary| summary.trim().to_owned())
+            .filter(|summary| !summary.is_empty())
+            .ok_or(NewEventBuildError::MissingSummary)?;
+        let date = self.date.ok_or(NewEventBuildError::MissingDate)?;
+        let event = NewEvent {
+            summary,
+            date,
+            time: self.time,
+            day_part: None,
+            location: self.location,
+            duration: self.duration,
+            timezone: None,
+            time_approximate: false,
+            all_day: false,
+            priority: None,
+            url: None,
+            tags: Vec::new(),
+            description: None,
+            geo: None,
+            is_online: false,
+            recurrence: None,
+        };
+        if let Some(duration) = event.duration {
+            if duration.is_negative() || event.datetime().checked_add(duration).is_err() {
+                return Err(NewEventBuildError::InvalidDuration);
+            }
+        }
+        Ok(event)
+    }
+}
+
+/// Contains all possible error variants that may occur while building a new event, see
+/// [`NewEventBuilder::build`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy, thiserror::Error, Serialize, Deserialize)]
+#[cfg_attr(feature = "wasm", derive(tsify::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+pub enum NewEventBuildError {
+    /// The summary was never set or is empty
+    #[error("Missing summary")]
+    MissingSummary,
+    /// The date was never set
+    #[error("Missing date")]
+    MissingDate,
+    /// The duration is negative or makes the event end after the latest representable time
+    #[error("Invalid duration")]
+    InvalidDuration,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use jiff::{civil::date, ToSpan};
+
+    #[test]
+    fn builder_minimal() {
+        let event = NewEvent::builder()
+            .summary("John's birthday")
+            .date(date(2024, 11, 18))
+            .build()
+            .unwrap();
+        assert_eq!(event.summary, "John's birthday");
+        assert_eq!(event.date, date(2024, 11, 18));
+        assert_eq!(event.time, None);
+        assert_eq!(event.location, None);
+        assert!(event.duration.is_none());
+    }
+
+    #[test]
+    fn builder_matches_parse() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let parsed = NewEvent::parse_at_time("Retro 2.6. 14:00-15:00 @ A769", now).unwrap();
+        let built = NewEvent::builder()
+            .summary("Retro")
+            .date(date(2024, 6, 2))
+            .time(Time::constant(14, 0, 0, 0))
+            .location("A769")
+            .duration(1.hour())
+            .build()
+            .unwrap();
+        assert_eq!(built, parsed);
+    }
+
+    #[test]
+    fn builder_validation() {
+        let tomorrow = date(2024, 6, 2);
+        assert_eq!(
+            NewEvent::builder().date(tomorrow).build(),
+            Err(NewEventBuildError::MissingSummary)
+        );
+        assert_eq!(
+            NewEvent::builder().summary("  ").date(tomorrow).build(),
+            Err(NewEventBuildError::MissingSummary)
+        );
+        assert_eq!(
+            NewEvent::builder().summary("Retro").build(),
+            Err(NewEventBuildError::MissingDate)
+        );
+        assert_eq!(
+            NewEvent::builder()
+                .summary("Retro")
+                .date(tomorrow)
+                .duration(-1.hour())
+                .build(),
+            Err(NewEventBuildError::InvalidDuration)
+        );
+        assert_eq!(
+            NewEvent::builder()
+                .summary("Forever")
+                .date(date(9999, 12, 31))
+                .time(Time::constant(23, 0, 0, 0))
+                .duration(2.hours())
+                .build(),
+            Err(NewEventBuildError::InvalidDuration)
+        );
+    }
+}
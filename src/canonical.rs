@@ -0,0 +1,145 @@
+//! A stable, serialization friendly representation of a [`NewEvent`]
+
+use jiff::Zoned;
+use serde::{Deserialize, Serialize};
+
+use crate::NewEvent;
+
+/// Format of a time with its offset from UTC, as in "2024-06-02T15:00:00-05:00"
+const OFFSET_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%:z";
+
+/// Wire format for a [`NewEvent`] that doesn't expose the internal date and time types.
+///
+/// `start` and `end` are ISO 8601 formatted strings. If the time was annotated with a time zone,
+/// see [`NewEvent::timezone`], they carry its offset from UTC at that instant, which makes them
+/// RFC 3339 timestamps as well. Otherwise they are civil times without an offset, meant to be read
+/// in the time zone of whoever reads them. Events without a time use the date form.
+/// ```rust
+/// use jiff::civil::date;
+/// let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+/// let event = nlcep::NewEvent::parse_at_time("Design review tomorrow 11:00-12:30 @ A769", now)
+///     .unwrap()
+///     .to_canonical();
+/// assert_eq!(event.start, "2024-06-02T11:00:00");
+/// assert_eq!(event.end.as_deref(), Some("2024-06-02T12:30:00"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "wasm", derive(tsify::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+pub struct CanonicalEvent {
+    /// Summary of the event
+    pub summary: String,
+    /// When the event starts, either `YYYY-MM-DDTHH:MM:SS±HH:MM` if the time zone is known,
+    /// `YYYY-MM-DDTHH:MM:SS` if it isn't, or `YYYY-MM-DD` if there is no time
+    pub start: String,
+    /// When the event ends, present only if the event has a duration
+    pub end: Option<String>,
+    /// Where the event takes place, not mandatory
+    pub location: Option<String>,
+}
+
+impl NewEvent {
+    /// Converts the event into its [`CanonicalEvent`] representation
+    pub fn to_canonical(&self) -> CanonicalEvent {
+        let in_time_zone = self.time.and(self.time_zone()).and_then(|tz| {
+            let start = self.to_zoned(&tz).ok()?;
+            let end = self.end_zoned(&tz).ok()?;
+            Some((start, end))
+        });
+        let (start, end) = if let Some((start, end)) = in_time_zone {
+            let with_offset = |instant: &Zoned| instant.strftime(OFFSET_FORMAT).to_string();
+            (with_offset(&start), end.as_ref().map(with_offset))
+        } else if self.time.is_some() {
+            let start = self.datetime();
+            let end = self
+                .duration
+                .and_then(|duration| start.checked_add(duration).ok());
+            (start.to_string(), end.map(|end| end.to_string()))
+        } else {
+            let end = self
+                .duration
+                .and_then(|duration| self.date.checked_add(duration).ok());
+            (self.date.to_string(), end.map(|end| end.to_string()))
+        };
+        CanonicalEvent {
+            summary: self.summary.clone(),
+            start,
+            end,
+            location: self.location.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use jiff::civil::date;
+
+    #[test]
+    fn canonical_timed_round_trip() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Deploy tomorrow 23:00-1:00 @ Server room", now)
+            .unwrap()
+            .to_canonical();
+        assert_eq!(
+            event,
+            CanonicalEvent {
+                summary: "Deploy".to_owned(),
+                start: "2024-06-02T23:00:00".to_owned(),
+                end: Some("2024-06-03T01:00:00".to_owned()),
+                location: Some("Server room".to_owned()),
+            }
+        );
+        let json = serde_json::to_string(&event).unwrap();
+        assert_eq!(
+            json,
+            r#"{"summary":"Deploy","start":"2024-06-02T23:00:00","end":"2024-06-03T01:00:00","location":"Server room"}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<CanonicalEvent>(&json).unwrap(),
+            event
+        );
+    }
+
+    #[test]
+    fn canonical_with_time_zone() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Call tomorrow 15:00-16:00 EST", now.clone())
+            .unwrap()
+            .to_canonical();
+        assert_eq!(event.start, "2024-06-02T15:00:00-05:00");
+        assert_eq!(event.end.as_deref(), Some("2024-06-02T16:00:00-05:00"));
+        let winter = NewEvent::parse_at_time("Call 18.11. 15:00 Europe/Helsinki", now)
+            .unwrap()
+            .to_canonical();
+        assert_eq!(winter.start, "2024-11-18T15:00:00+02:00");
+        assert_eq!(winter.end, None);
+    }
+
+    #[test]
+    fn canonical_date_only_round_trip() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("John's birthday 18.11.", now)
+            .unwrap()
+            .to_canonical();
+        assert_eq!(
+            event,
+            CanonicalEvent {
+                summary: "John's birthday".to_owned(),
+                start: "2024-11-18".to_owned(),
+                end: None,
+                location: None,
+            }
+        );
+        let json = serde_json::to_string(&event).unwrap();
+        assert_eq!(
+            json,
+            r#"{"summary":"John's birthday","start":"2024-11-18","end":null,"location":null}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<CanonicalEvent>(&json).unwrap(),
+            event
+        );
+    }
+}
@@ -0,0 +1,135 @@
+//! Scoring how much of an input a parse was able to make sense of
+
+use serde::{Deserialize, Serialize};
+
+use crate::NewEvent;
+
+/// Applied when the time is vague ("morning") or marked as approximate ("around 5")
+const VAGUE_TIME_FACTOR: f32 = 0.8;
+/// Applied when no time was given at all, unless the event was marked as lasting all day
+const NO_TIME_FACTOR: f32 = 0.7;
+/// Applied when the date could be read in another [`crate::DateOrder`], as with "3/4"
+const AMBIGUOUS_DATE_FACTOR: f32 = 0.8;
+
+/// A [`NewEvent`] along with how confident the parser is in it, see
+/// [`NewEvent::parse_with_options`].
+///
+/// The confidence is a number from 0 to 1, computed as `coverage * specificity * ambiguity`:
+/// - `coverage` is the share of the words from the date onwards that were recognized as the date,
+///   the time or the location. Ignored words between the date and the time, and trailing words
+///   that aren't a location, lower it.
+/// - `specificity` is 1 for an exact time or an event marked as lasting all day ("all day" or a
+///   range of days), 0.8 for a vague or approximate time such as "morning" or "around 5", and 0.7
+///   when there is no time.
+/// - `ambiguity` is 0.8 if the date could be read in another [`crate::DateOrder`], as with "3/4",
+///   and 1 otherwise.
+///
+/// The summary doesn't affect the confidence, as any text is a valid summary.
+/// ```rust
+/// use jiff::civil::date;
+/// use nlcep::{NewEvent, ParserOptions};
+/// let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+/// let parse = |s| NewEvent::parse_with_options(s, now.clone(), ParserOptions::default());
+/// let exact = parse("Meetup 18.11.2024 16:00 @ Place").unwrap();
+/// let vague = parse("Meetup sometime tomorrow").unwrap();
+/// assert!(exact.confidence > 0.99);
+/// assert!(vague.confidence < exact.confidence);
+/// ```
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "wasm", derive(tsify::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+pub struct ParsedEvent {
+    /// The parsed event
+    pub event: NewEvent,
+    /// How much of the input was recognized, from 0 to 1
+    pub confidence: f32,
+}
+
+/// Computes the confidence of a parse as documented on [`ParsedEvent`].
+/// `from_datetime` is the input from the start of the date onwards, `after_datetime` the input
+/// following the date and time. `explicit_all_day` is set when the date was marked as lasting all
+/// day.
+pub(crate) fn confidence(
+    event: &NewEvent,
+    from_datetime: &str,
+    after_datetime: &str,
+    skipped_words: usize,
+    ambiguous_date: bool,
+    explicit_all_day: bool,
+) -> f32 {
+    let total_words = from_datetime.split_whitespace().count().max(1);
+    let unrecognized_trailing_words = if event.location.is_some() {
+        0
+    } else {
+        after_datetime.split_whitespace().count()
+    };
+    let recognized_words = total_words.saturating_sub(skipped_words + unrecognized_trailing_words);
+    #[allow(clippy::cast_precision_loss)]
+    let coverage = recognized_words as f32 / total_words as f32;
+
+    let specificity = if explicit_all_day {
+        1.0
+    } else if event.time.is_none() {
+        NO_TIME_FACTOR
+    } else if event.day_part.is_some() || event.time_approximate {
+        VAGUE_TIME_FACTOR
+    } else {
+        1.0
+    };
+    let ambiguity = if ambiguous_date {
+        AMBIGUOUS_DATE_FACTOR
+    } else {
+        1.0
+    };
+    coverage * specificity * ambiguity
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use jiff::civil::date;
+
+    use crate::ParserOptions;
+
+    fn confidence_of(s: &str) -> f32 {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        NewEvent::parse_with_options(s, now, ParserOptions::default())
+            .unwrap()
+            .confidence
+    }
+
+    #[test]
+    fn confidence_fully_structured() {
+        assert!(confidence_of("Meetup 18.11.2024 16:00 @ Place") > 0.99);
+    }
+
+    #[test]
+    fn confidence_structured_above_vague() {
+        let structured = confidence_of("Meetup 18.11.2024 16:00 @ Place");
+        let vague = confidence_of("Meetup sometime tomorrow");
+        assert!(vague < structured);
+    }
+
+    #[test]
+    fn confidence_skipped_words() {
+        let direct = confidence_of("Dinner tomorrow at 17:00");
+        let skipped = confidence_of("Dinner tomorrow with Anna 17:00");
+        assert!(direct > 0.99);
+        assert!(skipped < direct);
+    }
+
+    #[test]
+    fn confidence_ambiguous_date() {
+        let unambiguous = confidence_of("Dentist 3/25 10:00");
+        let ambiguous = confidence_of("Dentist 3/4 10:00");
+        assert!(ambiguous < unambiguous);
+    }
+
+    #[test]
+    fn confidence_trailing_words() {
+        let located = confidence_of("Dentist tomorrow 10:00 @ Clinic");
+        let trailing = confidence_of("Dentist tomorrow 10:00 bring insurance card");
+        assert!(trailing < located);
+    }
+}
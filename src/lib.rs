@@ -12,7 +12,8 @@
 //!
 //! ### Usage
 //! The main logic can be accessed by constructing a [`NewEvent`] from a string. For example:
-//! ```rust
+#![cfg_attr(feature = "clock", doc = "```rust")]
+#![cfg_attr(not(feature = "clock"), doc = "```rust,ignore")]
 //! # use jiff::{ Zoned, ToSpan }; // nlcep uses jiff for storing dates
 //! # let tomorrow = Zoned::now().checked_add(1.day()).unwrap();
 //! #
@@ -33,8 +34,9 @@
 //! assert_eq!(time.minute(), 0);
 //! ```
 //!
-//! You can also use [`NewEvent::parse_at_time`] to supply an alternative basis for relative time
-//! formats such as "tomorrow" or "yesterday":
+//! Parsing with `.parse()` reads the system clock and requires the `clock` feature, which is
+//! enabled by default. Without it, use [`NewEvent::parse_at_time`] to supply the basis for relative
+//! time formats such as "tomorrow" or "yesterday" yourself:
 //! ```rust
 //! use jiff::{ Timestamp, Zoned };
 //! let now: Zoned = "2024-07-11T13:14:00Z"
@@ -48,6 +50,9 @@
 //! assert_eq!(event.date.month(), 7);
 //! assert_eq!(event.date.day(), 11 + 1);
 //! ```
+//!
+//! Disabling the `clock` feature only removes the functionality reading the system clock. The crate
+//! always links the standard library, so it can't be used in `no_std` environments.
 #![deny(unsafe_code)]
 #![warn(
     clippy::cognitive_complexity,
@@ -87,24 +92,363 @@
     clippy::wildcard_imports
 )]
 
+#[cfg(feature = "tokio")]
+mod asynchronous;
+mod builder;
+mod canonical;
+mod confidence;
+mod options;
+mod segments;
 pub(crate) mod temporal;
-pub use temporal::find_datetime;
+mod warnings;
+pub use builder::{NewEventBuildError, NewEventBuilder};
+pub use canonical::CanonicalEvent;
+pub use confidence::ParsedEvent;
+pub use options::{DateOrder, DayPartTimes, MeridiemInference, MidnightConvention, ParserOptions};
+pub use segments::MatchedSegments;
+pub use temporal::{
+    find_datetime, find_datetime_with_options,
+    recurrence::{Frequency, Recurrence},
+    time::DayPart,
+};
+pub use warnings::{ParseOutcome, ParseWarning};
 
 #[cfg(feature = "wasm")]
 pub mod wasm;
 #[cfg(feature = "wasm")]
 use wasm_bindgen::prelude::*;
 
-use std::str::FromStr;
+use std::borrow::Cow;
 
 use jiff::{
     civil::{Date, DateTime, Time},
-    Span, Zoned,
+    tz::{Offset, TimeZone},
+    Span, SpanRelativeTo, Zoned,
 };
-use lazy_regex::regex;
+use lazy_regex::{lazy_regex, Lazy, Regex};
 use serde::{Deserialize, Serialize};
 
-use crate::temporal::DateTimeMatch;
+use strum::IntoEnumIterator;
+
+use crate::temporal::{
+    date::DateRelativeLanguage,
+    normalize_word,
+    time::{find_skipped_words, find_trailing_duration, TimeZoneAnnotation},
+    DateTimeMatch,
+};
+
+/// Finds a location delimiter at the start of the text following the datetime, returning where
+/// the location after it starts.
+/// The delimiter is one of `delimiters`, with or without whitespace around it, as in "@ A769",
+/// "@A769" or ",A769". Nothing else, such as a run of spaces, starts a location.
+fn find_location_delimiter(after_time: &str, delimiters: &[char]) -> Option<usize> {
+    let trimmed = after_time.trim_start();
+    let delimiter = trimmed.chars().next()?;
+    if !delimiters.contains(&delimiter) {
+        return None;
+    }
+    let location = trimmed[delimiter.len_utf8()..].trim_start();
+    (!location.is_empty()).then_some(after_time.len() - location.len())
+}
+
+/// Finds a word introducing a location at the start of the text following the datetime, as in
+/// "at the office", "paikassa A769" or "Paikka: A769", see
+/// [`DateRelativeLanguage::get_location_markers`]. Returns where the location starts.
+/// Unless a time has already been found (`has_time`), a location starting with a digit is not
+/// accepted, so that a bare hour as in "at 5" is never mistaken for a location.
+fn find_location_marker(after_time: &str, has_time: bool) -> Option<usize> {
+    let marker_start = after_time.len() - after_time.trim_start().len();
+    if marker_start == 0 {
+        return None;
+    }
+    let marker = after_time[marker_start..].split_whitespace().next()?;
+    let normalized = normalize_word(marker);
+    DateRelativeLanguage::iter()
+        .any(|lang| lang.get_location_markers().contains(&normalized.as_str()))
+        .then_some(())?;
+    let rest = &after_time[marker_start + marker.len()..];
+    let location = rest.trim_start();
+    if location.is_empty() || (!has_time && location.starts_with(|c: char| c.is_ascii_digit())) {
+        return None;
+    }
+    Some(after_time.len() - location.len())
+}
+
+/// Matches a room code such as "A769", "B204a" or "B-wing"
+static ROOM_CODE_PATTERN: Lazy<Regex> = lazy_regex!(r"^\p{Lu}{1,2}(?:\d+\p{L}?|-\p{L}+)$");
+
+/// Finds a room at the start of the text following the datetime, as in "room 204", "huone 204"
+/// or "A769", see [`ParserOptions::room_locations`]. Returns where the location starts.
+fn find_room_location(after_time: &str) -> Option<usize> {
+    let room_start = after_time.len() - after_time.trim_start().len();
+    if room_start == 0 {
+        return None;
+    }
+    let mut words = after_time[room_start..].split_whitespace();
+    let first = words.next()?;
+    let normalized = normalize_word(first);
+    let room_word = DateRelativeLanguage::iter()
+        .any(|lang| lang.get_room_words().contains(&normalized.as_str()));
+    ((room_word && words.next().is_some()) || ROOM_CODE_PATTERN.is_match(first))
+        .then_some(room_start)
+}
+
+/// Removes words such as "on" or "at" that are left dangling at the end of the summary once the
+/// datetime following them is cut out, as in "Dentist appointment on tomorrow", see
+/// [`ParserOptions::trailing_connectors`]. The first word of the summary is always kept.
+fn trim_trailing_connectors<'a>(summary: &'a str, connectors: &[Cow<'_, str>]) -> &'a str {
+    let mut trimmed = summary;
+    while let Some((rest, last)) = trimmed.rsplit_once(char::is_whitespace) {
+        if !connectors
+            .iter()
+            .any(|connector| connector.eq_ignore_ascii_case(last))
+        {
+            break;
+        }
+        trimmed = rest.trim_end();
+    }
+    trimmed
+}
+
+/// Matches a location leading the text before the datetime, as in "@ Pompier lunch" or
+/// "@Pompier lunch". The location is the first word along with any capitalized words following it.
+static LEADING_LOCATION_PATTERN: Lazy<Regex> =
+    lazy_regex!(r"^@\s*(?P<location>\S+(?:\s+\p{Lu}\S*)*)");
+
+/// Matches a location ending the text before the datetime, as in "Lunch @ Pompier",
+/// "Lunch @Pompier" or "Lunch at Pompier". Like after the datetime, the `@` must start a word.
+static TRAILING_LOCATION_PATTERN: Lazy<Regex> =
+    lazy_regex!(r"\s(?P<delimiter>@\s*|(?i:at)\s+)(?P<location>\S.*)$");
+
+/// Finds a location in the text `before` the datetime, returning the rest of the text, the
+/// location, and the location along with its delimiter.
+fn find_location_before(before: &str) -> Option<(&str, &str, &str)> {
+    if let Some(captures) = LEADING_LOCATION_PATTERN.captures(before) {
+        let found = captures.name("location")?;
+        return Some((
+            before[found.end()..].trim_start(),
+            found.as_str(),
+            &before[..found.end()],
+        ));
+    }
+    let captures = TRAILING_LOCATION_PATTERN.captures(before)?;
+    let delimiter = captures.name("delimiter")?;
+    let found = captures.name("location")?;
+    if !delimiter.as_str().starts_with('@') && !found.as_str().starts_with(char::is_uppercase) {
+        return None;
+    }
+    Some((
+        before[..delimiter.start()].trim_end(),
+        found.as_str(),
+        &before[delimiter.start()..],
+    ))
+}
+
+/// Matches a priority marker ending a piece of text, as in "!!" or "(high priority)"
+static TRAILING_PRIORITY_PATTERN: Lazy<Regex> =
+    lazy_regex!(r"(?i)(?:^|\s)(?:(?P<marks>!{1,3})|\((?P<level>high|medium|low) priority\))\s*$");
+
+/// Matches a priority marker leading a piece of text, as in "!! Submit report"
+static LEADING_PRIORITY_PATTERN: Lazy<Regex> =
+    lazy_regex!(r"(?i)^(?:(?P<marks>!{1,3})|\((?P<level>high|medium|low) priority\))(?:\s|$)");
+
+/// Finds a priority marker at either end of `text`, returning the rest of the text, untrimmed,
+/// and the priority as documented on [`NewEvent::priority`].
+fn split_priority(text: &str) -> (&str, Option<u8>) {
+    let (captures, rest) = if let Some(captures) = TRAILING_PRIORITY_PATTERN.captures(text) {
+        let rest = &text[..captures.get(0).map_or(text.len(), |found| found.start())];
+        (captures, rest)
+    } else if let Some(captures) = LEADING_PRIORITY_PATTERN.captures(text) {
+        let rest = &text[captures.get(0).map_or(0, |found| found.end())..];
+        (captures, rest)
+    } else {
+        return (text, None);
+    };
+    let priority = match (captures.name("marks"), captures.name("level")) {
+        (Some(marks), _) => match marks.len() {
+            1 => 9,
+            2 => 5,
+            _ => 1,
+        },
+        (None, Some(level)) => match level.as_str().to_lowercase().as_str() {
+            "high" => 1,
+            "medium" => 5,
+            _ => 9,
+        },
+        (None, None) => return (text, None),
+    };
+    (rest, Some(priority))
+}
+
+/// Matches a http(s) URL, as in `https://meet.example.com/abc`
+static URL_PATTERN: Lazy<Regex> = lazy_regex!(r"\bhttps?://\S+");
+
+/// Finds a URL in the text following the datetime, returning the text around it that may hold a
+/// location and the URL without trailing punctuation.
+/// The text before the URL is preferred, as in "@ A769, https://...", falling back to the text
+/// after it, as in "https://... @ A769". URLs in a description after "//" are left alone.
+fn split_url(after_time: &str) -> (&str, Option<&str>) {
+    let before_description = DESCRIPTION_MARKER_PATTERN
+        .find(after_time)
+        .map_or(after_time, |marker| &after_time[..marker.start()]);
+    let Some(found) = URL_PATTERN.find(before_description) else {
+        return (after_time, None);
+    };
+    let url = found.as_str().trim_end_matches(['.', ',', ';', ')']);
+    let before_url = after_time[..found.start()].trim_end().trim_end_matches(',');
+    if before_url.trim().is_empty() {
+        (&after_time[found.start() + url.len()..], Some(url))
+    } else {
+        (before_url, Some(url))
+    }
+}
+
+/// Matches a hashtag, as in "#health". Tags must follow whitespace, so that the fragment of a URL
+/// such as `https://example.com/#top` is not one.
+static TAG_PATTERN: Lazy<Regex> = lazy_regex!(r"(?:^|\s)#(?P<tag>[\p{L}\p{N}_-]+)");
+
+/// Matches a hashtag ending a piece of text
+static TRAILING_TAG_PATTERN: Lazy<Regex> = lazy_regex!(r"(?:^|\s)#(?P<tag>[\p{L}\p{N}_-]+)\s*$");
+
+/// Matches a hashtag leading a piece of text
+static LEADING_TAG_PATTERN: Lazy<Regex> = lazy_regex!(r"^\s*#(?P<tag>[\p{L}\p{N}_-]+)(?:\s|$)");
+
+/// Strips the hashtags at either end of `text`, returning the rest of the text, untrimmed, and
+/// the tags without the `#` in the order they appear in.
+fn split_tags(text: &str) -> (&str, Vec<String>) {
+    let mut rest = text;
+    let mut leading = Vec::new();
+    while let Some(captures) = LEADING_TAG_PATTERN.captures(rest) {
+        leading.push(captures["tag"].to_owned());
+        rest = &rest[captures.get(0).map_or(rest.len(), |found| found.end())..];
+    }
+    let mut trailing = Vec::new();
+    while let Some(captures) = TRAILING_TAG_PATTERN.captures(rest) {
+        trailing.push(captures["tag"].to_owned());
+        rest = &rest[..captures.get(0).map_or(0, |found| found.start())];
+    }
+    leading.extend(trailing.into_iter().rev());
+    (rest, leading)
+}
+
+/// Matches a "//" marker starting a description, as in "Dentist 10:00 // bring the card".
+/// The marker must follow whitespace, so that the `//` of a URL is not one.
+static DESCRIPTION_MARKER_PATTERN: Lazy<Regex> = lazy_regex!(r"(?:^|\s)//");
+
+/// Splits a description off the text following the datetime, returning the text that may hold a
+/// location and the description.
+/// The description only follows a "//" marker, as in "@ Citydent // remember insurance card",
+/// so that commas in an address such as "@ Kanavaranta 7, 00160 Helsinki" stay in the location.
+fn split_description(after_time: &str) -> (&str, Option<&str>) {
+    let Some(marker) = DESCRIPTION_MARKER_PATTERN.find(after_time) else {
+        return (after_time, None);
+    };
+    let description = after_time[marker.end()..].trim();
+    (
+        &after_time[..marker.start()],
+        (!description.is_empty()).then_some(description),
+    )
+}
+
+/// Pairs of brackets that are kept around and within a location as long as they are matched
+const LOCATION_BRACKETS: [(char, char); 3] = [('(', ')'), ('[', ']'), ('{', '}')];
+
+/// Trims whitespace, trailing sentence punctuation and unmatched brackets from a location, so
+/// that "Oodi." becomes "Oodi" and "the park )" becomes "the park". Punctuation within the
+/// location is kept.
+fn trim_location(location: &str) -> &str {
+    let mut trimmed = location.trim();
+    loop {
+        if let Some(rest) = trimmed.strip_suffix(['.', ',', ';', '!', '?']) {
+            trimmed = rest.trim_end();
+            continue;
+        }
+        let unmatched = LOCATION_BRACKETS.iter().find_map(|&(open, close)| {
+            let opened = trimmed.matches(open).count();
+            let closed = trimmed.matches(close).count();
+            if closed > opened {
+                trimmed.strip_suffix(close)
+            } else if opened > closed {
+                trimmed.strip_prefix(open)
+            } else {
+                None
+            }
+        });
+        match unmatched {
+            Some(rest) => trimmed = rest.trim(),
+            None => return trimmed,
+        }
+    }
+}
+
+/// Matches an online meeting at the start of the text following the datetime, as in "on Zoom",
+/// "via Teams" or "etänä"
+static ONLINE_PATTERN: Lazy<Regex> = lazy_regex!(
+    r"(?ix)^\s*(?P<preposition>(?:on|via|over|in)\s+)?
+    (?P<provider>google\s+meet|microsoft\s+teams|zoom|meet|teams|webex|online|etänä)\b"
+);
+
+/// Returns the name of the online meeting provider written as `provider`, as in "Zoom" for
+/// "zoom". Generic words such as "online" have no provider.
+fn online_provider_name(provider: &str) -> Option<&'static str> {
+    let normalized = provider
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase();
+    match normalized.as_str() {
+        "zoom" => Some("Zoom"),
+        "meet" | "google meet" => Some("Google Meet"),
+        "teams" | "microsoft teams" => Some("Microsoft Teams"),
+        "webex" => Some("Webex"),
+        _ => None,
+    }
+}
+
+/// Finds an online meeting at the start of the text following the datetime, see
+/// [`ONLINE_PATTERN`]. Returns the provider, if one was named, and where the match ends.
+/// "meet" alone is only a provider after a preposition, as in "on Meet", since it's more often
+/// a verb.
+fn find_online(after_time: &str) -> Option<(Option<&'static str>, usize)> {
+    let captures = ONLINE_PATTERN.captures(after_time)?;
+    let provider = &captures["provider"];
+    if captures.name("preposition").is_none() && provider.eq_ignore_ascii_case("meet") {
+        return None;
+    }
+    Some((online_provider_name(provider), captures.get(0)?.end()))
+}
+
+/// Matches a latitude and a longitude in decimal degrees separated by a comma, as in
+/// "60.1699, 24.9384"
+static GEO_PATTERN: Lazy<Regex> =
+    lazy_regex!(r"^(?P<lat>[+-]?\d{1,2}\.\d+)\s*,\s*(?P<lon>[+-]?\d{1,3}\.\d+)\b");
+
+/// Reads a location consisting of coordinates only, as in "60.1699, 24.9384", returning the
+/// latitude and the longitude if they are within range
+fn parse_geo(location: &str) -> Option<(f64, f64)> {
+    let captures = GEO_PATTERN.captures(location)?;
+    if captures.get(0)?.end() != location.len() {
+        return None;
+    }
+    let lat = captures["lat"].parse::<f64>().ok()?;
+    let lon = captures["lon"].parse::<f64>().ok()?;
+    ((-90.0..=90.0).contains(&lat) && (-180.0..=180.0).contains(&lon)).then_some((lat, lon))
+}
+
+/// Everything [`NewEvent::parse_detailed`] finds out about an input
+struct DetailedParse<'a> {
+    /// The event and the confidence in it
+    parsed: ParsedEvent,
+    /// What the parser had to assume
+    warnings: Vec<ParseWarning>,
+    /// The text each component was read from
+    segments: MatchedSegments<'a>,
+    /// The text not claimed by any component, see [`NewEvent::parse_with_remainder`]
+    remainder: String,
+}
+
+/// The maximum number of events returned by [`NewEvent::parse_candidates`]
+const MAX_CANDIDATES: usize = 4;
 
 /// Represents a parsed event
 #[derive(Debug, Serialize, Deserialize)]
@@ -115,10 +459,161 @@ pub struct NewEvent {
     pub summary: String,
     pub date: Date,
     pub time: Option<Time>,
+    /// Set when `time` was derived from a vague time of day such as "morning" or "lunchtime", in
+    /// which case `time` holds the configured clock time from [`DayPartTimes`]
+    pub day_part: Option<DayPart>,
     /// Where the event takes place, not mandatory
     pub location: Option<String>,
-    /// For how long the event goes on, not mandatory
+    /// For how long the event goes on, not mandatory.
+    /// A range of dates such as "monday until friday" counts both the first and the last day.
+    /// Serialized as an ISO 8601 duration such as "PT1H30M".
+    #[serde(default, with = "duration_iso8601")]
+    #[cfg_attr(feature = "wasm", tsify(type = "string | undefined"))]
     pub duration: Option<Span>,
+    /// The time zone the time was annotated with: an abbreviation such as "UTC" or "EET", an
+    /// offset such as "+02:00", or an IANA time zone such as "Europe/Helsinki", not mandatory.
+    /// See [`NewEvent::time_zone`] and [`NewEvent::offset`].
+    #[serde(default)]
+    pub timezone: Option<EventTimeZone>,
+    /// Whether the time was marked as approximate, as in "around 5", "~17:00" or "5ish"
+    #[serde(default)]
+    pub time_approximate: bool,
+    /// Whether only a date was given, so the event lasts the whole day rather than having an
+    /// unknown time, as in "John's birthday 18.11.", "Conference 18.11. all day" or
+    /// "Offsite 18.-20.11."
+    #[serde(default)]
+    pub all_day: bool,
+    /// Priority of the event on the iCalendar scale from 1 (highest) to 9 (lowest), not mandatory.
+    /// Read from exclamation marks at either end of the summary or the input, "!" being low (9),
+    /// "!!" medium (5) and "!!!" high (1), or from a "(low priority)", "(medium priority)" or
+    /// "(high priority)" phrase in the same places.
+    #[serde(default)]
+    pub priority: Option<u8>,
+    /// A http(s) link following the datetime, such as a video meeting link, not mandatory.
+    /// Kept separate from `location`, as in `@ A769, https://meet.example.com/abc`.
+    #[serde(default)]
+    pub url: Option<String>,
+    /// Categories given as hashtags anywhere in the input, as in "#health", without the `#`.
+    /// The tags are removed from the summary.
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Free-form notes following the datetime or the location, not mandatory.
+    /// Separated from them by "//", as in "Dentist tomorrow 10:00 @ Citydent // remember insurance
+    /// card". Commas don't start a description, they are part of the location.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// The latitude and longitude of the location in decimal degrees, set when the location
+    /// consists of coordinates only, as in "@ 60.1699, 24.9384". The coordinates are kept in
+    /// `location` as written. Serialized as a `[latitude, longitude]` pair.
+    #[serde(default)]
+    pub geo: Option<(f64, f64)>,
+    /// Whether the event takes place online, as in "Planning tomorrow 10:00 on Zoom",
+    /// "… via Teams", "… online" or "… etänä". The `location` is then the URL of the meeting if
+    /// there is one, otherwise the name of the provider.
+    #[serde(default)]
+    pub is_online: bool,
+    /// How the event repeats, as in "Gym every other monday 18:00" or "Standup daily 9:00", not
+    /// mandatory. The date is the first occurrence. See [`Recurrence::rrule`].
+    #[serde(default)]
+    pub recurrence: Option<Recurrence>,
+}
+
+/// A time zone an event was annotated with, see [`NewEvent::timezone`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "wasm", derive(tsify::Tsify))]
+pub enum EventTimeZone {
+    /// An IANA time zone such as "Europe/Helsinki", following its daylight saving time rules
+    Iana(String),
+    /// A fixed offset from UTC, written as an abbreviation such as "CET" or as an offset such as
+    /// "+02:00" or "UTC+5:30"
+    Fixed {
+        /// The time zone as written
+        name: String,
+        /// Offset from UTC, serialized in seconds
+        #[serde(with = "offset_seconds")]
+        #[cfg_attr(feature = "wasm", tsify(type = "number"))]
+        offset: Offset,
+    },
+}
+impl EventTimeZone {
+    /// Name of the time zone, as in "Europe/Helsinki" or "CET"
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Iana(name) | Self::Fixed { name, .. } => name,
+        }
+    }
+
+    /// The time zone itself, or `None` if an IANA time zone is not in the time zone database
+    pub fn to_time_zone(&self) -> Option<TimeZone> {
+        match self {
+            Self::Iana(name) => TimeZone::get(name).ok(),
+            Self::Fixed { offset, .. } => Some(TimeZone::fixed(*offset)),
+        }
+    }
+}
+impl From<TimeZoneAnnotation> for EventTimeZone {
+    fn from(zone: TimeZoneAnnotation) -> Self {
+        match zone.tz.to_fixed_offset() {
+            Ok(offset) => Self::Fixed {
+                name: zone.name,
+                offset,
+            },
+            Err(_e) => Self::Iana(zone.name),
+        }
+    }
+}
+
+/// Name of `tz` for error messages: its IANA name, or its offset if it is a fixed offset
+fn time_zone_name(tz: &TimeZone) -> String {
+    tz.iana_name().map_or_else(
+        || {
+            tz.to_fixed_offset()
+                .map_or_else(|_e| String::new(), |offset| offset.to_string())
+        },
+        ToOwned::to_owned,
+    )
+}
+
+/// (De)serializes an [`Offset`] as a number of seconds from UTC
+mod offset_seconds {
+    use jiff::tz::Offset;
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    /// Serializes the offset in seconds
+    pub fn serialize<S: Serializer>(offset: &Offset, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i32(offset.seconds())
+    }
+
+    /// Deserializes an offset in seconds, rejecting offsets that are out of range
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Offset, D::Error> {
+        Offset::from_seconds(i32::deserialize(deserializer)?).map_err(D::Error::custom)
+    }
+}
+
+/// (De)serializes an optional [`Span`] as an ISO 8601 duration, as in "PT1H30M" or "P5D"
+mod duration_iso8601 {
+    use jiff::Span;
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    /// Serializes the duration as an ISO 8601 string, or `None` if there is no duration
+    pub fn serialize<S: Serializer>(
+        duration: &Option<Span>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match duration {
+            Some(duration) => serializer.serialize_some(&duration.to_string()),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    /// Deserializes a duration from an ISO 8601 string, rejecting anything else
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Span>, D::Error> {
+        Option::<String>::deserialize(deserializer)?
+            .map(|duration| duration.parse::<Span>().map_err(D::Error::custom))
+            .transpose()
+    }
 }
 
 impl PartialEq for NewEvent {
@@ -127,100 +622,684 @@ impl PartialEq for NewEvent {
             (None, None) => true,
             (Some(_), None) => false,
             (None, Some(_)) => false,
+            // Durations of whole days, as from "18.-20.11.", can't be compared without
+            // assuming that days are 24 hours long
             (Some(a), Some(b)) => a
-                .compare(b)
-                .map(|ord| matches!(ord, std::cmp::Ordering::Equal))
+                .compare((b, SpanRelativeTo::days_are_24_hours()))
+                .map(|ord| matches!(ord, core::cmp::Ordering::Equal))
                 .unwrap_or(false),
         };
         self.summary == other.summary
             && self.date == other.date
             && self.time == other.time
+            && self.day_part == other.day_part
             && self.location == other.location
+            && self.timezone == other.timezone
+            && self.time_approximate == other.time_approximate
+            && self.all_day == other.all_day
+            && self.priority == other.priority
+            && self.url == other.url
+            && self.tags == other.tags
+            && self.description == other.description
+            && self.geo == other.geo
+            && self.is_online == other.is_online
+            && self.recurrence == other.recurrence
             && duration_same
     }
 }
 
 impl NewEvent {
+    /// Starts building an event without parsing, see [`NewEventBuilder`]
+    pub fn builder() -> NewEventBuilder {
+        NewEventBuilder::default()
+    }
+
     pub fn parse_at_time(s: &str, now: Zoned) -> Result<Self, EventParseError> {
+        Self::parse_at_time_with_options(s, now, ParserOptions::default())
+    }
+
+    /// Like parsing with `.parse()`, but also returns the current time of the system clock that
+    /// relative terms such as "tomorrow" were resolved against.
+    /// Requires the `clock` feature.
+    /// ```rust
+    /// use nlcep::NewEvent;
+    /// let (event, now) = NewEvent::parse_capturing("Lunch tomorrow 12:00").unwrap();
+    /// assert_eq!(event, NewEvent::parse_at_time("Lunch tomorrow 12:00", now).unwrap());
+    /// ```
+    #[cfg(feature = "clock")]
+    pub fn parse_capturing(s: &str) -> Result<(Self, Zoned), EventParseError> {
+        let now = Zoned::now();
+        Self::parse_at_time(s, now.clone()).map(|event| (event, now))
+    }
+
+    /// Like [`NewEvent::parse_at_time`], but allows tuning how ambiguous inputs are interpreted
+    /// through [`ParserOptions`].
+    pub fn parse_at_time_with_options(
+        s: &str,
+        now: Zoned,
+        options: ParserOptions,
+    ) -> Result<Self, EventParseError> {
+        Self::parse_with_options(s, now, options).map(|parsed| parsed.event)
+    }
+
+    /// Like [`NewEvent::parse_at_time_with_options`], but also reports how confident the parser
+    /// is in the result, see [`ParsedEvent`].
+    pub fn parse_with_options(
+        s: &str,
+        now: Zoned,
+        options: ParserOptions,
+    ) -> Result<ParsedEvent, EventParseError> {
+        Self::parse_detailed(s, now, options).map(|detailed| detailed.parsed)
+    }
+
+    /// Like [`NewEvent::parse_at_time_with_options`], but also reports what the parser had to
+    /// assume, such as the order of an ambiguous date, see [`ParseOutcome`].
+    pub fn parse_with_warnings(
+        s: &str,
+        now: Zoned,
+        options: ParserOptions,
+    ) -> Result<ParseOutcome, EventParseError> {
+        Self::parse_detailed(s, now, options).map(|detailed| ParseOutcome {
+            event: detailed.parsed.event,
+            warnings: detailed.warnings,
+        })
+    }
+
+    /// Like [`NewEvent::parse_at_time`], but returns the text left over after the summary, date,
+    /// time, location and other components were read separately instead of appending it to the
+    /// summary, see [`ParserOptions::append_remainder`].
+    ///
+    /// Everything before the date is the summary, so the remainder consists of the words skipped
+    /// between the date and the time, as in "with Anna" for "Retro tomorrow with Anna 14:00", and
+    /// the text following the datetime that isn't a location, duration, URL, tag, priority or
+    /// description, as in "re: budget" for "Call mom tomorrow re: budget". The two are joined by a
+    /// space, and the remainder is empty for typical inputs.
+    /// ```rust
+    /// use jiff::civil::date;
+    /// use nlcep::NewEvent;
+    /// let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+    /// let (event, remainder) =
+    ///     NewEvent::parse_with_remainder("Call mom tomorrow re: budget", now).unwrap();
+    /// assert_eq!(event.summary, "Call mom");
+    /// assert_eq!(remainder, "re: budget");
+    /// ```
+    pub fn parse_with_remainder(s: &str, now: Zoned) -> Result<(Self, String), EventParseError> {
+        let options = ParserOptions {
+            append_remainder: false,
+            ..Default::default()
+        };
+        Self::parse_detailed(s, now, options)
+            .map(|detailed| (detailed.parsed.event, detailed.remainder))
+    }
+
+    /// Like [`NewEvent::parse_at_time_with_options`], but also returns the text each component
+    /// was read from, see [`MatchedSegments`].
+    pub fn parse_with_segments(
+        s: &str,
+        now: Zoned,
+        options: ParserOptions,
+    ) -> Result<(Self, MatchedSegments<'_>), EventParseError> {
+        Self::parse_detailed(s, now, options)
+            .map(|detailed| (detailed.parsed.event, detailed.segments))
+    }
+
+    /// Parses `s`, returning everything that is known about the result
+    fn parse_detailed(
+        s: &str,
+        now: Zoned,
+        options: ParserOptions,
+    ) -> Result<DetailedParse<'_>, EventParseError> {
         let mut summary: Option<String> = None;
         let mut location: Option<String> = None;
         let DateTimeMatch {
             date,
             time,
+            day_part,
+            mut duration,
+            time_zone,
+            approximate: time_approximate,
+            ambiguous_date,
+            short_year,
+            skipped_words,
+            explicit_all_day,
+            recurrence,
             start_char: time_starts,
             end_char: time_ends,
-        } = find_datetime(s, now, false)?.ok_or(EventParseError::MissingTime)?;
+            date_end_char,
+            time_chars,
+        } = find_datetime_with_options(s, now.clone(), false, options.clone())?
+            .ok_or(EventParseError::MissingTime)?;
         let (before_time, _) = s.split_at(time_starts);
         let (_, after_time) = s.split_at(time_ends);
+        // "Submit report tomorrow 17:00 !!"
+        let (after_time, mut priority) = split_priority(after_time);
+        // "1:1 with Maria tomorrow 14:00 https://meet.example.com/abc"
+        let (after_time, url) = split_url(after_time);
+        // "Dentist tomorrow 9:00 #health #personal"
+        let (after_time, after_time_tags) = split_tags(after_time);
+        // "Dentist tomorrow 10:00 @ Citydent // remember insurance card"
+        let (after_time, description) = split_description(after_time);
+        let mut tags: Vec<String> = TAG_PATTERN
+            .captures_iter(before_time)
+            .map(|captures| captures["tag"].to_owned())
+            .collect();
+        tags.extend(after_time_tags);
 
-        let before_time_trimmed = before_time.trim();
-        if !before_time_trimmed.is_empty() {
-            summary = Some(before_time_trimmed.to_owned());
+        let mut summary_text = split_tags(before_time).0.trim();
+        let mut location_text = None;
+        // Whatever follows the date and the time in the datetime is its duration
+        let datetime_end = time_chars.map_or(date_end_char, |(_, time_end)| time_end);
+        let mut duration_text = Some(s[datetime_end..time_ends].trim()).filter(|d| !d.is_empty());
+        // "Planning tomorrow 10:00 on Zoom"
+        let online = find_online(after_time);
+        let location_start = find_location_delimiter(after_time, &options.location_delimiters)
+            .or_else(|| find_location_marker(after_time, time.is_some() || explicit_all_day))
+            .or_else(|| find_room_location(after_time).filter(|_| options.room_locations))
+            .filter(|_| online.is_none());
+        if let Some((provider, online_end)) = online {
+            location = url.or(provider).map(str::to_owned);
+            let online_start = after_time.len() - after_time.trim_start().len();
+            location_text = location
+                .as_ref()
+                .map(|_| &after_time[online_start..online_end]);
+        }
+        if let Some(location_start) = location_start {
+            let mut found_location = trim_location(&after_time[location_start..]);
+            // "@ A769 for 45 min"
+            if duration.is_none() {
+                if let Some((trailing, duration_start)) = find_trailing_duration(found_location) {
+                    duration_text = Some(found_location[duration_start..].trim());
+                    found_location = trim_location(&found_location[..duration_start]);
+                    duration = Some(trailing);
+                }
+            }
+            let delimiter_start = after_time.len() - after_time.trim_start().len();
+            // Trimming may have dropped an unmatched bracket preceding the location
+            let found_start = after_time[location_start..]
+                .find(found_location)
+                .unwrap_or(0);
+            let location_end = location_start + found_start + found_location.len();
+            location_text = Some(&after_time[delimiter_start..location_end]);
+            location = Some(found_location.to_owned());
+        }
+        // "Tomorrow 11:00 dentist appointment @ Citydent"
+        let summary_after = summary_text.is_empty() && location_start.is_none() && online.is_none();
+        if summary_after {
+            summary_text = after_time.trim();
+            if duration.is_none() {
+                if let Some((trailing, duration_start)) = find_trailing_duration(summary_text) {
+                    duration_text = Some(summary_text[duration_start..].trim());
+                    summary_text = summary_text[..duration_start].trim_end();
+                    duration = Some(trailing);
+                }
+            }
         }
+        if location.is_none() {
+            if let Some((rest, found_location, found_text)) = find_location_before(summary_text) {
+                summary_text = rest;
+                location_text = Some(found_text);
+                location = Some(trim_location(found_location).to_owned());
+            }
+        }
+        if priority.is_none() {
+            // "!! Submit report tomorrow" or "Submit report (high priority) tomorrow"
+            let (rest, found) = split_priority(summary_text);
+            summary_text = rest.trim();
+            priority = found;
+        }
+        // "Dentist appointment on tomorrow"
+        summary_text = trim_trailing_connectors(summary_text, &options.trailing_connectors);
+        if !summary_text.is_empty() {
+            // "Lunch #food with Anna tomorrow"
+            summary = Some(TAG_PATTERN.replace_all(summary_text, "").trim().to_owned());
+        }
+
+        let geo = location.as_deref().and_then(parse_geo);
+        // "@ Zoom"
+        let is_online = online.is_some()
+            || location.as_deref().is_some_and(|location| {
+                find_online(location).is_some_and(|(_, end)| end == location.len())
+            });
 
-        let location_start_pattern = regex!(r"\s*[@ | ,]\s+.+");
-        if location_start_pattern.is_match(after_time) {
-            let trimmed_location = after_time
-                .trim()
-                .trim_start_matches(['@', ','])
-                .trim_start();
-            location = Some(trimmed_location.to_owned());
+        let timezone = match time_zone {
+            Some(zone) => {
+                let datetime = time.map_or_else(|| date.into(), |time| date.to_datetime(time));
+                let (time_start, time_end) = time_chars.unwrap_or((time_starts, time_ends));
+                datetime
+                    .to_zoned(zone.tz.clone())
+                    .map_err(|_e| EventParseError::InvalidTime {
+                        text: s[time_start..time_end].to_owned(),
+                        at: time_start,
+                    })?;
+                Some(zone.into())
+            }
+            None => None,
+        };
+
+        let skipped = time_chars.map_or("", |(time_start, _)| &s[date_end_char..time_start]);
+        let mut remainder = find_skipped_words(skipped).collect::<Vec<_>>().join(" ");
+        let trailing = after_time[online.map_or(0, |(_, online_end)| online_end)..].trim();
+        if location_start.is_none() && !summary_after && !trailing.is_empty() {
+            if !remainder.is_empty() {
+                remainder.push(' ');
+            }
+            remainder.push_str(trailing);
+        }
+        if options.append_remainder && !remainder.is_empty() {
+            // "Standup tomorrow 9:15 with the design team"
+            summary = Some(summary.filter(|summary| !summary.is_empty()).map_or_else(
+                || remainder.clone(),
+                |summary| format!("{summary} {remainder}"),
+            ));
         }
 
-        Ok(Self {
-            summary: summary.ok_or(EventParseError::MissingSummary)?,
+        let event = Self {
+            summary: match summary {
+                Some(summary) => summary,
+                None if options.allow_missing_summary => options.untitled_summary.to_string(),
+                None => return Err(EventParseError::MissingSummary),
+            },
             date,
             time,
+            day_part,
             location,
-            duration: None,
+            duration,
+            timezone,
+            time_approximate,
+            all_day: time.is_none(),
+            priority,
+            url: url.map(str::to_owned),
+            tags,
+            description: description.map(str::to_owned),
+            geo,
+            is_online,
+            recurrence,
+        };
+        if options.future_only && !event.is_future(&now) {
+            return Err(EventParseError::PastEvent);
+        }
+        let confidence = confidence::confidence(
+            &event,
+            &s[time_starts..],
+            after_time,
+            skipped_words,
+            ambiguous_date,
+            explicit_all_day,
+        );
+        let warnings = warnings::warnings(&event, ambiguous_date, short_year);
+        let segments = MatchedSegments {
+            summary_text,
+            date_text: s[time_starts..date_end_char].trim_end(),
+            time_text: time_chars.map(|(start, end)| &s[start..end]),
+            location_text,
+            duration_text,
+        };
+        Ok(DetailedParse {
+            parsed: ParsedEvent { event, confidence },
+            warnings,
+            segments,
+            remainder,
         })
     }
 
+    /// Returns every plausible interpretation of `s`, best first.
+    ///
+    /// Inputs such as "3/4" could mean either the 3rd of April or the 4th of March, in which case
+    /// both are returned, the one matching the default [`DateOrder`] first. Unambiguous inputs
+    /// return a single event, and inputs that can't be parsed at all return none.
+    /// ```rust
+    /// use jiff::civil::date;
+    /// use nlcep::NewEvent;
+    /// let now = date(2024, 1, 1).in_tz("UTC").unwrap();
+    /// let candidates = NewEvent::parse_candidates("Dentist 3/4 10:00", now);
+    /// assert_eq!(candidates.len(), 2);
+    /// assert_eq!(candidates[0].date, date(2024, 4, 3));
+    /// assert_eq!(candidates[1].date, date(2024, 3, 4));
+    /// ```
+    pub fn parse_candidates(s: &str, now: Zoned) -> Vec<Self> {
+        Self::parse_candidates_with_options(s, now, ParserOptions::default())
+    }
+
+    /// Like [`NewEvent::parse_candidates`], but allows tuning how ambiguous inputs are
+    /// interpreted through [`ParserOptions`]. The interpretation matching `options` comes first.
+    pub fn parse_candidates_with_options(s: &str, now: Zoned, options: ParserOptions) -> Vec<Self> {
+        let alternative = ParserOptions {
+            date_order: options.date_order.swapped(),
+            ..options.clone()
+        };
+        let mut candidates: Vec<Self> = Vec::new();
+        for candidate_options in [options, alternative] {
+            if let Ok(event) = Self::parse_at_time_with_options(s, now.clone(), candidate_options) {
+                if !candidates.contains(&event) {
+                    candidates.push(event);
+                }
+            }
+        }
+        candidates.truncate(MAX_CANDIDATES);
+        candidates
+    }
+
     pub fn datetime(&self) -> DateTime {
         self.time
             .map_or_else(|| self.date.into(), |time| self.date.to_datetime(time))
     }
+
+    /// When the event ends: [`NewEvent::datetime`] plus the duration, or `None` if the event has
+    /// no duration.
+    ///
+    /// Ends past the largest datetime supported by [`jiff`] saturate to [`DateTime::MAX`].
+    /// ```rust
+    /// use jiff::civil::date;
+    /// use nlcep::NewEvent;
+    /// let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+    /// let event = NewEvent::parse_at_time("Deploy 2.6. 23:00-1:00", now.clone()).unwrap();
+    /// assert_eq!(event.end_datetime(), Some(date(2024, 6, 3).at(1, 0, 0, 0)));
+    /// let event = NewEvent::parse_at_time("Deploy 2.6. 23:00", now).unwrap();
+    /// assert_eq!(event.end_datetime(), None);
+    /// ```
+    pub fn end_datetime(&self) -> Option<DateTime> {
+        self.duration
+            .map(|duration| self.datetime().saturating_add(duration))
+    }
+
+    /// Whether the event starts at or after `now`. The start is placed in the time zone the event
+    /// was annotated with, or the time zone of `now` if there was none, see
+    /// [`NewEvent::zoned_datetime`].
+    /// Events without a time are in the future for the whole of their first day.
+    /// ```rust
+    /// use jiff::civil::date;
+    /// use nlcep::NewEvent;
+    /// let now = date(2024, 6, 1).at(12, 0, 0, 0).in_tz("UTC").unwrap();
+    /// let event = NewEvent::parse_at_time("Lunch 1.6.2024 11:00", now.clone()).unwrap();
+    /// assert!(!event.is_future(&now));
+    /// let event = NewEvent::parse_at_time("Lunch 1.6.2024", now.clone()).unwrap();
+    /// assert!(event.is_future(&now));
+    /// ```
+    pub fn is_future(&self, now: &Zoned) -> bool {
+        if self.time.is_none() {
+            return self.date >= now.date();
+        }
+        self.zoned_datetime(now.time_zone().clone()).map_or_else(
+            |_e| self.datetime() >= now.datetime(),
+            |start| start >= *now,
+        )
+    }
+
+    /// The time zone the time was annotated with, see [`NewEvent::timezone`].
+    /// ```rust
+    /// use jiff::civil::date;
+    /// use nlcep::NewEvent;
+    /// let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+    /// let event = NewEvent::parse_at_time("Call tomorrow 15:00 Europe/Helsinki", now).unwrap();
+    /// let tz = event.time_zone().unwrap();
+    /// assert_eq!(tz.iana_name(), Some("Europe/Helsinki"));
+    /// ```
+    pub fn time_zone(&self) -> Option<TimeZone> {
+        self.timezone.as_ref()?.to_time_zone()
+    }
+
+    /// Offset from UTC of the time zone the time was annotated with at the start of the event,
+    /// see [`NewEvent::time_zone`].
+    /// ```rust
+    /// use jiff::{civil::date, tz::Offset};
+    /// use nlcep::NewEvent;
+    /// let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+    /// let summer = NewEvent::parse_at_time("Call 1.7. 15:00 Europe/Helsinki", now.clone());
+    /// assert_eq!(summer.unwrap().offset(), Some(Offset::constant(3)));
+    /// let winter = NewEvent::parse_at_time("Call 18.11. 15:00 Europe/Helsinki", now);
+    /// assert_eq!(winter.unwrap().offset(), Some(Offset::constant(2)));
+    /// ```
+    pub fn offset(&self) -> Option<Offset> {
+        if let Some(EventTimeZone::Fixed { offset, .. }) = self.timezone {
+            return Some(offset);
+        }
+        let start = self.to_zoned(&self.time_zone()?).ok()?;
+        Some(start.offset())
+    }
+
+    /// Like [`NewEvent::datetime`], but placed in the time zone the event was annotated with, or
+    /// `default_tz` if there was none.
+    ///
+    /// IANA time zones keep their daylight saving time rules, abbreviations and offsets are fixed.
+    /// ```rust
+    /// use jiff::{civil::date, tz::TimeZone};
+    /// use nlcep::NewEvent;
+    /// let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+    /// let event = NewEvent::parse_at_time("Sync tomorrow 15:00 EET @ Meet", now).unwrap();
+    /// let start = event.zoned_datetime(TimeZone::UTC).unwrap();
+    /// assert_eq!(start.to_string(), "2024-06-02T15:00:00+02:00[+02:00]");
+    /// ```
+    pub fn zoned_datetime(&self, default_tz: TimeZone) -> Result<Zoned, EventParseError> {
+        let invalid = |name: String| EventParseError::InvalidTimeZone { name };
+        let Some(zone) = &self.timezone else {
+            return self
+                .to_zoned(&default_tz)
+                .map_err(|_e| invalid(time_zone_name(&default_tz)));
+        };
+        let tz = zone
+            .to_time_zone()
+            .ok_or_else(|| invalid(zone.name().to_owned()))?;
+        self.to_zoned(&tz)
+            .map_err(|_e| invalid(zone.name().to_owned()))
+    }
+
+    /// Places the start of the event in the time zone `tz`, ignoring any time zone the event was
+    /// annotated with, see [`NewEvent::zoned_datetime`] for that.
+    /// Events without a time start at midnight.
+    ///
+    /// Local times that don't exist or occur twice in `tz` because of a daylight saving time
+    /// transition are resolved the way [`jiff::tz::Disambiguation::Compatible`] does:
+    /// - times in a gap, such as 03:30 when clocks jump from 03:00 to 04:00, are moved forward by
+    ///   the length of the gap, to 04:30
+    /// - times in a fold, such as 03:30 when clocks go back from 04:00 to 03:00, use the earlier
+    ///   of the two instants, the one before the clocks go back
+    /// ```rust
+    /// use jiff::{civil::date, tz::TimeZone};
+    /// use nlcep::NewEvent;
+    /// let now = date(2024, 3, 1).in_tz("UTC").unwrap();
+    /// let helsinki = TimeZone::get("Europe/Helsinki").unwrap();
+    /// // Clocks in Finland jump from 03:00 to 04:00 on 31.3.2024
+    /// let event = NewEvent::parse_at_time("Night shift 31.3. 3:30", now).unwrap();
+    /// let start = event.to_zoned(&helsinki).unwrap();
+    /// assert_eq!(start.to_string(), "2024-03-31T04:30:00+03:00[Europe/Helsinki]");
+    /// ```
+    pub fn to_zoned(&self, tz: &TimeZone) -> Result<Zoned, jiff::Error> {
+        self.datetime().to_zoned(tz.clone())
+    }
+
+    /// Places the end of the event in the time zone `tz`, see [`NewEvent::to_zoned`].
+    /// Returns `None` if the event has no duration.
+    ///
+    /// The duration is added to the zoned start, so a duration in hours is exact even across a
+    /// daylight saving time transition, while a duration in days keeps the same wall clock time.
+    pub fn end_zoned(&self, tz: &TimeZone) -> Result<Option<Zoned>, jiff::Error> {
+        let Some(duration) = self.duration else {
+            return Ok(None);
+        };
+        self.to_zoned(tz)?.checked_add(duration).map(Some)
+    }
 }
 
 /// Contains all possible error variants that may occur while parsing a new event.
-#[derive(Debug, PartialEq, Clone, Copy, thiserror::Error, Serialize, Deserialize)]
+/// Variants about a specific part of the input carry the offending `text` along with the byte
+/// offset it starts `at`.
+#[derive(Debug, PartialEq, Eq, Clone, thiserror::Error, Serialize, Deserialize)]
 #[cfg_attr(feature = "wasm", derive(tsify::Tsify))]
 #[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
 pub enum EventParseError {
     /// No valid datetime could be parsed, other details might be valid.
     /// For example:
     /// ```rust
+    /// use jiff::civil::date;
     /// use nlcep::{ NewEvent, EventParseError };
-    /// let err = "Meet Saara @ Local Library".parse::<NewEvent>();
+    /// let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+    /// let err = NewEvent::parse_at_time("Meet Saara @ Local Library", now);
     /// assert_eq!(err, Err(EventParseError::MissingTime));
     /// ```
     #[error("Missing time")]
     MissingTime,
-    /// Reserved for future use
-    #[error("Invalid time")]
-    InvalidTime,
-    /// Reserved for future use
-    #[error("Ambiguous time")]
-    AmbiguousTime,
-    /// The event contains a valid time, but a summary couldn't be found.
+    /// Something that was clearly meant as a time or a date is out of range.
+    /// For example:
+    /// ```rust
+    /// use jiff::civil::date;
+    /// use nlcep::{ NewEvent, EventParseError };
+    /// let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+    /// let err = NewEvent::parse_at_time("Deploy tomorrow 25:00", now);
+    /// assert_eq!(err, Err(EventParseError::InvalidTime { text: "25:00".to_owned(), at: 16 }));
+    /// ```
+    #[error("Invalid time \"{text}\" at byte {at}")]
+    InvalidTime {
+        /// The time or date that is out of range
+        text: String,
+        /// Byte offset of `text` in the input
+        at: usize,
+    },
+    /// The event contains more than one start time, and [`ParserOptions::strict_times`] is set.
     /// For example:
     /// ```rust
+    /// use jiff::civil::date;
+    /// use nlcep::{ NewEvent, EventParseError, ParserOptions };
+    /// let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+    /// let options = ParserOptions { strict_times: true, ..Default::default() };
+    /// let input = "Gym tomorrow 10:00 and 18:00";
+    /// let err = NewEvent::parse_at_time_with_options(input, now, options);
+    /// assert_eq!(err, Err(EventParseError::AmbiguousTime { text: "18:00".to_owned(), at: 23 }));
+    /// ```
+    /// Also returned for a date that could be read in either order, such as "3/4", when
+    /// [`ParserOptions::strict_dates`] is set, and when a date or time can't be represented, such
+    /// as one overflowing the supported range of years.
+    #[error("Ambiguous time \"{text}\" at byte {at}")]
+    AmbiguousTime {
+        /// The second time, the ambiguous date, or the date or time that couldn't be represented
+        text: String,
+        /// Byte offset of `text` in the input
+        at: usize,
+    },
+    /// The event contains a valid time, but a summary couldn't be found either before or after it.
+    /// For example:
+    /// ```rust
+    /// use jiff::civil::date;
     /// use nlcep::{ NewEvent, EventParseError };
-    /// let err = "tomorrow 11:00".parse::<NewEvent>();
+    /// let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+    /// let err = NewEvent::parse_at_time("tomorrow 11:00", now);
     /// assert_eq!(err, Err(EventParseError::MissingSummary));
     /// ```
     #[error("Missing summary")]
     MissingSummary,
-    /// Reserved for future use
-    #[error("Ambiguous duration")]
-    AmbiguousDuration,
+    /// A duration couldn't be represented, as with a range of dates spanning thousands of years
+    #[error("Ambiguous duration \"{text}\" at byte {at}")]
+    AmbiguousDuration {
+        /// The date, time or range the duration was computed from
+        text: String,
+        /// Byte offset of `text` in the input
+        at: usize,
+    },
+    /// A range of dates ends before it starts.
+    /// For example:
+    /// ```rust
+    /// use jiff::civil::date;
+    /// use nlcep::{ NewEvent, EventParseError };
+    /// let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+    /// let err = NewEvent::parse_at_time("Vacation 7.6.2024 until 3.6.2024", now);
+    /// let text = "7.6.2024 until 3.6.2024".to_owned();
+    /// assert_eq!(err, Err(EventParseError::InvalidDateRange { text, at: 9 }));
+    /// ```
+    #[error("Invalid date range \"{text}\" at byte {at}")]
+    InvalidDateRange {
+        /// The whole range of dates
+        text: String,
+        /// Byte offset of `text` in the input
+        at: usize,
+    },
+    /// The event starts before the time of parsing, and [`ParserOptions::future_only`] is set.
+    /// For example:
+    /// ```rust
+    /// use jiff::civil::date;
+    /// use nlcep::{ NewEvent, EventParseError, ParserOptions };
+    /// let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+    /// let options = ParserOptions { future_only: true, ..Default::default() };
+    /// let err = NewEvent::parse_at_time_with_options("Exam 18.11.2023 9:00", now, options);
+    /// assert_eq!(err, Err(EventParseError::PastEvent));
+    /// ```
+    #[error("Event is in the past")]
+    PastEvent,
+    /// The time zone of an event isn't known, or the event can't be placed in it, as with
+    /// [`NewEvent::zoned_datetime`].
+    /// For example:
+    /// ```rust
+    /// use jiff::{civil::date, tz::TimeZone};
+    /// use nlcep::{ NewEvent, EventParseError, EventTimeZone };
+    /// let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+    /// let mut event = NewEvent::parse_at_time("Sync tomorrow 15:00", now).unwrap();
+    /// event.timezone = Some(EventTimeZone::Iana("Mars/Olympus_Mons".to_owned()));
+    /// let name = "Mars/Olympus_Mons".to_owned();
+    /// let err = event.zoned_datetime(TimeZone::UTC);
+    /// assert_eq!(err, Err(EventParseError::InvalidTimeZone { name }));
+    /// ```
+    #[error("Invalid time zone \"{name}\"")]
+    InvalidTimeZone {
+        /// Name of the time zone, as in "Europe/Helsinki" or "+02:00"
+        name: String,
+    },
+}
+impl EventParseError {
+    /// An [`EventParseError::InvalidTime`] to be given its context with [`Self::located`]
+    pub(crate) const fn invalid_time() -> Self {
+        Self::InvalidTime {
+            text: String::new(),
+            at: 0,
+        }
+    }
+
+    /// An [`EventParseError::AmbiguousTime`] to be given its context with [`Self::located`]
+    pub(crate) const fn ambiguous_time() -> Self {
+        Self::AmbiguousTime {
+            text: String::new(),
+            at: 0,
+        }
+    }
+
+    /// An [`EventParseError::AmbiguousDuration`] to be given its context with [`Self::located`]
+    pub(crate) const fn ambiguous_duration() -> Self {
+        Self::AmbiguousDuration {
+            text: String::new(),
+            at: 0,
+        }
+    }
+
+    /// Sets the offending `text` and the byte offset it starts `at` for the variants that carry
+    /// them, leaving other variants as is
+    pub(crate) fn located(self, text: &str, at: usize) -> Self {
+        match self {
+            Self::InvalidTime { .. } => Self::InvalidTime {
+                text: text.to_owned(),
+                at,
+            },
+            Self::AmbiguousTime { .. } => Self::AmbiguousTime {
+                text: text.to_owned(),
+                at,
+            },
+            Self::AmbiguousDuration { .. } => Self::AmbiguousDuration {
+                text: text.to_owned(),
+                at,
+            },
+            Self::InvalidDateRange { .. } => Self::InvalidDateRange {
+                text: text.to_owned(),
+                at,
+            },
+            other => other,
+        }
+    }
 }
-impl FromStr for NewEvent {
+/// Parses an event relative to the current time of the system clock, see
+/// [`NewEvent::parse_at_time`] for parsing at another time.
+/// Requires the `clock` feature.
+#[cfg(feature = "clock")]
+impl core::str::FromStr for NewEvent {
     type Err = EventParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let now = Zoned::now();
-        Self::parse_at_time(s, now)
+        Self::parse_capturing(s).map(|(event, _)| event)
     }
 }
 
@@ -228,14 +1307,90 @@ impl FromStr for NewEvent {
 mod tests {
     use super::*;
 
-    use jiff::civil::date;
+    use jiff::{civil::date, ToSpan};
+
+    #[test]
+    #[cfg(feature = "clock")]
+    fn parse_capturing_returns_now() {
+        let before = Zoned::now();
+        let (event, now) = NewEvent::parse_capturing("Lunch tomorrow 12:00 @ Pompier").unwrap();
+        let after = Zoned::now();
+        assert!(before <= now && now <= after);
+        assert_eq!(
+            event,
+            NewEvent::parse_at_time("Lunch tomorrow 12:00 @ Pompier", now).unwrap()
+        );
+        assert_eq!(
+            NewEvent::parse_capturing("John's birthday"),
+            Err(EventParseError::MissingTime)
+        );
+    }
 
     #[test]
+    #[cfg(feature = "clock")]
     fn fail_only_summary() {
         let event = "John's birthday".parse::<NewEvent>();
         assert_eq!(event, Err(EventParseError::MissingTime));
     }
 
+    #[test]
+    fn with_summary_after_datetime() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let parse = |s| NewEvent::parse_with_segments(s, now.clone(), ParserOptions::default());
+        let (dentist, segments) = parse("Tomorrow 11:00 dentist appointment").unwrap();
+        assert_eq!(dentist.summary, "dentist appointment");
+        assert_eq!(dentist.datetime(), date(2024, 6, 2).at(11, 0, 0, 0));
+        assert_eq!(segments.summary_text, "dentist appointment");
+        let (checkup, _) = parse("Tomorrow 11:00 checkup @ Citydent for 45 min").unwrap();
+        assert_eq!(checkup.summary, "checkup");
+        assert_eq!(checkup.location, Some("Citydent".to_owned()));
+        assert_eq!(
+            checkup.duration.map(|d| d.fieldwise()),
+            Some(45.minutes().fieldwise())
+        );
+        let (party, _) = parse("18.11. John's birthday party").unwrap();
+        assert_eq!(party.summary, "John's birthday party");
+        // A location directly after the datetime leaves no summary
+        assert_eq!(
+            parse("Tomorrow 11:00 @ Citydent"),
+            Err(EventParseError::MissingSummary)
+        );
+        let (_, remainder) =
+            NewEvent::parse_with_remainder("Tomorrow 11:00 dentist appointment", now).unwrap();
+        assert_eq!(remainder, "");
+    }
+
+    #[test]
+    fn fail_only_time() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("tomorrow 11:00", now);
+        assert_eq!(event, Err(EventParseError::MissingSummary));
+    }
+
+    #[test]
+    fn allow_missing_summary() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let options = ParserOptions {
+            allow_missing_summary: true,
+            ..Default::default()
+        };
+        let event = NewEvent::parse_at_time_with_options("tomorrow 11:00", now, options).unwrap();
+        assert_eq!(event.summary, "(untitled)");
+        assert_eq!(event.datetime(), date(2024, 6, 2).at(11, 0, 0, 0));
+    }
+
+    #[test]
+    fn allow_missing_summary_custom() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let options = ParserOptions {
+            allow_missing_summary: true,
+            untitled_summary: "Nimetön".into(),
+            ..Default::default()
+        };
+        let event = NewEvent::parse_at_time_with_options("huomenna 11:00", now, options).unwrap();
+        assert_eq!(event.summary, "Nimetön");
+    }
+
     #[test]
     fn trivial_a() {
         let now = date(2024, 6, 1).in_tz("UTC").unwrap();
@@ -246,56 +1401,2259 @@ mod tests {
         assert_eq!(event.datetime().month(), 11);
         assert_eq!(event.datetime().hour(), 0);
         assert_eq!(event.location, None);
+        assert!(event.all_day);
     }
 
     #[test]
-    fn with_time_short() {
+    fn all_day_phrase() {
         let now = date(2024, 6, 1).in_tz("UTC").unwrap();
-        let event = NewEvent::parse_at_time("John's birthday 18.11. 16", now).unwrap();
-        assert_eq!(event.summary, "John's birthday");
-        assert_eq!(event.datetime().year(), 2024);
-        assert_eq!(event.datetime().day(), 18);
-        assert_eq!(event.datetime().month(), 11);
-        assert_eq!(event.datetime().hour(), 16);
-        assert_eq!(event.datetime().minute(), 0);
-        assert_eq!(event.location, None);
+        let event =
+            NewEvent::parse_at_time("Conference 18.11. all day @ Messukeskus", now).unwrap();
+        assert_eq!(event.summary, "Conference");
+        assert_eq!(event.date, date(2024, 11, 18));
+        assert_eq!(event.time, None);
+        assert_eq!(event.location, Some("Messukeskus".to_owned()));
+        assert!(event.all_day);
     }
 
     #[test]
-    fn with_time_long_a() {
+    fn all_day_phrase_finnish() {
         let now = date(2024, 6, 1).in_tz("UTC").unwrap();
-        let event = NewEvent::parse_at_time("John's birthday 18.11. 16:00", now).unwrap();
-        assert_eq!(event.summary, "John's birthday");
-        assert_eq!(event.datetime().year(), 2024);
-        assert_eq!(event.datetime().day(), 18);
-        assert_eq!(event.datetime().month(), 11);
-        assert_eq!(event.datetime().hour(), 16);
-        assert_eq!(event.datetime().minute(), 0);
+        let event = NewEvent::parse_at_time("Messut huomenna koko päivän", now).unwrap();
+        assert_eq!(event.summary, "Messut");
+        assert_eq!(event.date, date(2024, 6, 2));
         assert_eq!(event.location, None);
+        assert!(event.all_day);
     }
 
     #[test]
-    fn with_time_long_b() {
+    fn all_day_day_range() {
         let now = date(2024, 6, 1).in_tz("UTC").unwrap();
-        let event = NewEvent::parse_at_time("John's birthday 18.11. 1:59", now).unwrap();
-        assert_eq!(event.summary, "John's birthday");
-        assert_eq!(event.datetime().year(), 2024);
-        assert_eq!(event.datetime().day(), 18);
-        assert_eq!(event.datetime().month(), 11);
-        assert_eq!(event.datetime().hour(), 1);
-        assert_eq!(event.datetime().minute(), 59);
-        assert_eq!(event.location, None);
+        let parse = |s| NewEvent::parse_at_time(s, now.clone()).unwrap();
+        assert!(parse("Offsite 18.-20.11. @ Nuuksio").all_day);
+        assert!(!parse("Offsite 18.-20.11. 9-17 @ Nuuksio").all_day);
+        assert!(parse("Vacation tomorrow for 2 weeks").all_day);
+        assert!(!parse("Planning tomorrow morning").all_day);
     }
 
     #[test]
-    fn trivial_with_location_a() {
+    fn all_day_serde() {
         let now = date(2024, 6, 1).in_tz("UTC").unwrap();
-        let event = NewEvent::parse_at_time("John's birthday 18.11. @ Memory Plaza", now).unwrap();
-        assert_eq!(event.summary, "John's birthday");
-        assert_eq!(event.date.year(), 2024);
-        assert_eq!(event.date.day(), 18);
-        assert_eq!(event.date.month(), 11);
-        assert_eq!(event.location, Some("Memory Plaza".to_owned()));
+        let event = NewEvent::parse_at_time("Conference 18.11. all day", now).unwrap();
+        let json = serde_json::to_string(&event).unwrap();
+        assert!(json.contains("\"all_day\":true"));
+        let parsed: NewEvent = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, event);
+    }
+
+    #[test]
+    fn duration_serde() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let parse = |s| NewEvent::parse_at_time(s, now.clone()).unwrap();
+        for (input, iso) in [
+            ("Retro tomorrow 14:00-15:30", "PT1H30M"),
+            ("Retro tomorrow 14:00 for 45 min", "PT45M"),
+            ("Vacation from monday until friday", "P5D"),
+        ] {
+            let event = parse(input);
+            let json = serde_json::to_string(&event).unwrap();
+            assert!(json.contains(&format!("\"duration\":\"{iso}\"")), "{json}");
+            let parsed: NewEvent = serde_json::from_str(&json).unwrap();
+            assert_eq!(parsed, event);
+        }
+        let event = parse("Retro tomorrow 14:00");
+        let json = serde_json::to_string(&event).unwrap();
+        assert!(json.contains("\"duration\":null"));
+        let parsed: NewEvent = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, event);
+        let invalid = json.replace("\"duration\":null", "\"duration\":\"soon\"");
+        assert!(serde_json::from_str::<NewEvent>(&invalid).is_err());
+    }
+
+    #[test]
+    fn with_tonight() {
+        let now = date(2024, 6, 1).at(12, 0, 0, 0).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Call tonight @ Home", now).unwrap();
+        assert_eq!(event.summary, "Call");
+        assert_eq!(event.datetime(), date(2024, 6, 1).at(22, 0, 0, 0));
+        assert_eq!(event.day_part, Some(DayPart::Night));
+        assert_eq!(event.location, Some("Home".to_owned()));
+    }
+
+    #[test]
+    fn with_tonight_after_night_began() {
+        let now = date(2024, 6, 1).at(23, 30, 0, 0).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Call tonight", now).unwrap();
+        assert_eq!(event.datetime(), date(2024, 6, 1).at(22, 0, 0, 0));
+    }
+
+    #[test]
+    fn with_this_morning() {
+        let now = date(2024, 6, 1).at(7, 0, 0, 0).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Run this morning", now).unwrap();
+        assert_eq!(event.summary, "Run");
+        assert_eq!(event.datetime(), date(2024, 6, 1).at(9, 0, 0, 0));
+        assert_eq!(event.day_part, Some(DayPart::Morning));
+    }
+
+    #[test]
+    fn with_this_afternoon() {
+        let now = date(2024, 6, 1).at(7, 0, 0, 0).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Meeting this afternoon, A769", now).unwrap();
+        assert_eq!(event.summary, "Meeting");
+        assert_eq!(event.datetime(), date(2024, 6, 1).at(15, 0, 0, 0));
+        assert_eq!(event.day_part, Some(DayPart::Afternoon));
+        assert_eq!(event.location, Some("A769".to_owned()));
+    }
+
+    #[test]
+    fn with_this_evening_custom_time() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let options = ParserOptions {
+            day_parts: DayPartTimes {
+                evening: Time::constant(19, 30, 0, 0),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let event =
+            NewEvent::parse_at_time_with_options("Sauna tänä iltana", now, options).unwrap();
+        assert_eq!(event.datetime(), date(2024, 6, 1).at(19, 30, 0, 0));
+    }
+
+    #[test]
+    fn end_datetime_saturates() {
+        let event = NewEvent {
+            summary: "Forever".to_owned(),
+            date: date(9999, 12, 31),
+            time: Some(Time::constant(23, 0, 0, 0)),
+            day_part: None,
+            location: None,
+            duration: Some(2.hours()),
+            timezone: None,
+            time_approximate: false,
+            all_day: false,
+            priority: None,
+            url: None,
+            tags: Vec::new(),
+            description: None,
+            geo: None,
+            is_online: false,
+            recurrence: None,
+        };
+        assert_eq!(event.end_datetime(), Some(DateTime::MAX));
+    }
+
+    #[test]
+    fn end_datetime_day_range() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Offsite 18.-20.11.", now).unwrap();
+        assert_eq!(
+            event.end_datetime(),
+            Some(date(2024, 11, 21).at(0, 0, 0, 0))
+        );
+    }
+
+    #[test]
+    fn with_bare_weekday() {
+        // A wednesday
+        let now = date(2024, 6, 5).in_tz("UTC").unwrap();
+        let parse = |s| NewEvent::parse_at_time(s, now.clone()).unwrap();
+        let party = parse("Party saturday 19:00 @ Kanavaranta 7");
+        assert_eq!(party.summary, "Party");
+        assert_eq!(party.datetime(), date(2024, 6, 8).at(19, 0, 0, 0));
+        assert_eq!(party.location, Some("Kanavaranta 7".to_owned()));
+        assert_eq!(parse("Juhlat lauantaina 19:00").date, date(2024, 6, 8));
+        // The weekday of today is today, "next wednesday" is a week away
+        assert_eq!(parse("Sauna wednesday 18:00").date, date(2024, 6, 5));
+        assert_eq!(parse("Sauna next wednesday 18:00").date, date(2024, 6, 12));
+        // Any other date is preferred over a weekday in the summary
+        let brunch = parse("Saturday brunch 18.11. 10:00");
+        assert_eq!(brunch.summary, "Saturday brunch");
+        assert_eq!(brunch.date, date(2024, 11, 18));
+        let call = parse("Friday sync in 2 hours");
+        assert_eq!(call.summary, "Friday sync");
+        assert_eq!(call.datetime(), date(2024, 6, 5).at(2, 0, 0, 0));
+    }
+
+    #[test]
+    fn with_time_until() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Hackathon saturday 9:00 until 17:00 @ Main hall", now)
+            .unwrap();
+        assert_eq!(event.summary, "Hackathon");
+        assert_eq!(event.datetime(), date(2024, 6, 1).at(9, 0, 0, 0));
+        assert_eq!(
+            event.duration.map(Span::fieldwise),
+            Some(8.hours().fieldwise())
+        );
+        assert_eq!(event.location, Some("Main hall".to_owned()));
+    }
+
+    #[test]
+    fn with_time_until_past_midnight() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Party 8.6. 22:00 till 2:00, Club", now).unwrap();
+        assert_eq!(
+            event.duration.map(Span::fieldwise),
+            Some(4.hours().fieldwise())
+        );
+        assert_eq!(event.end_datetime(), Some(date(2024, 6, 9).at(2, 0, 0, 0)));
+        assert_eq!(event.location, Some("Club".to_owned()));
+    }
+
+    #[test]
+    fn fuzzy_relative_word() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let fuzzy = ParserOptions {
+            fuzzy: true,
+            ..Default::default()
+        };
+        let parse = |s, options| NewEvent::parse_at_time_with_options(s, now.clone(), options);
+        let event = parse("Dinner tommorow 18:00", fuzzy).unwrap();
+        assert_eq!(event.summary, "Dinner");
+        assert_eq!(event.datetime(), date(2024, 6, 2).at(18, 0, 0, 0));
+        assert!(parse("Dinner tommorow 18:00", ParserOptions::default()).is_err());
+    }
+
+    #[test]
+    fn with_end_of_month() {
+        let now = date(2024, 2, 10).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Invoice end of month 12:00 @ Office", now).unwrap();
+        assert_eq!(event.summary, "Invoice");
+        assert_eq!(event.datetime(), date(2024, 2, 29).at(12, 0, 0, 0));
+        assert_eq!(event.location, Some("Office".to_owned()));
+    }
+
+    #[test]
+    fn with_date_range_until() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Vacation from monday until friday", now).unwrap();
+        assert_eq!(event.summary, "Vacation");
+        assert_eq!(event.date, date(2024, 6, 3));
+        assert!(event.duration.unwrap().fieldwise() == 5.days());
+        assert!(event.all_day);
+    }
+
+    #[test]
+    fn with_date_range_asti() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Loma 3.6. 7.6. asti @ Mökki", now.clone()).unwrap();
+        assert_eq!(event.summary, "Loma");
+        assert_eq!(event.location, Some("Mökki".to_owned()));
+        assert_eq!(event.date, date(2024, 6, 3));
+        assert!(event.duration.unwrap().fieldwise() == 5.days());
+        let trip = NewEvent::parse_at_time("Trip 3.6.-4.6.", now).unwrap();
+        assert_eq!(trip.summary, "Trip");
+        assert!(trip.duration.unwrap().fieldwise() == 2.days());
+    }
+
+    #[test]
+    fn with_date_range_reversed() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Vacation 7.6.2024 to 3.6.2024 @ Home", now);
+        let text = "7.6.2024 to 3.6.2024".to_owned();
+        assert_eq!(
+            event,
+            Err(EventParseError::InvalidDateRange { text, at: 9 })
+        );
+    }
+
+    #[test]
+    fn strict_times() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let parse = |s, options| NewEvent::parse_at_time_with_options(s, now.clone(), options);
+        let strict = ParserOptions {
+            strict_times: true,
+            ..Default::default()
+        };
+        let input = "Gym tomorrow 10:00 and 18:00";
+        let second = |at| EventParseError::AmbiguousTime {
+            text: "18:00".to_owned(),
+            at,
+        };
+        assert_eq!(parse(input, strict.clone()), Err(second(23)));
+        assert_eq!(
+            parse("Gym tomorrow 10:00, 18:00", strict.clone()),
+            Err(second(20))
+        );
+        assert!(parse(input, ParserOptions::default()).is_ok());
+        let event = parse("Gym tomorrow 10:00-11:00 @ Room 5", strict).unwrap();
+        assert_eq!(event.location, Some("Room 5".to_owned()));
+    }
+
+    #[test]
+    fn strict_dates() {
+        let now = date(2024, 1, 1).in_tz("UTC").unwrap();
+        let parse = |s, options| NewEvent::parse_at_time_with_options(s, now.clone(), options);
+        let strict = ParserOptions {
+            strict_dates: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            parse("Dentist 3/4 10:00", strict.clone()),
+            Err(EventParseError::AmbiguousTime {
+                text: "3/4".to_owned(),
+                at: 8,
+            })
+        );
+        let day_first = ParserOptions {
+            date_order: DateOrder::DayFirst,
+            ..Default::default()
+        };
+        assert_eq!(
+            parse("Dentist 3/4 10:00", day_first.clone()).unwrap().date,
+            date(2024, 4, 3)
+        );
+        let month_first = ParserOptions {
+            date_order: DateOrder::MonthFirst,
+            ..Default::default()
+        };
+        assert_eq!(
+            parse("Dentist 3/4 10:00", month_first.clone())
+                .unwrap()
+                .date,
+            date(2024, 3, 4)
+        );
+        // Dates that can only be read one way are accepted regardless
+        for options in [strict.clone(), day_first, month_first] {
+            assert_eq!(
+                parse("Dentist 18/3 10:00", options).unwrap().date,
+                date(2024, 3, 18)
+            );
+        }
+        assert_eq!(
+            parse("Dentist 4/4 10:00", strict).unwrap().date,
+            date(2024, 4, 4)
+        );
+    }
+
+    #[test]
+    fn with_spelled_out_duration() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let parse = |s| NewEvent::parse_at_time(s, now.clone()).unwrap();
+        for (input, minutes) in [
+            ("Dentist tomorrow 10:00 an hour @ Clinic", 60),
+            ("Dentist tomorrow 10:00 half an hour @ Clinic", 30),
+            ("Dentist tomorrow 10:00 an hour and a half @ Clinic", 90),
+            ("Dentist tomorrow 10:00 two hours @ Clinic", 120),
+            ("Dentist tomorrow 10:00 ninety minutes @ Clinic", 90),
+            ("Dentist tomorrow 10:00 @ Clinic for an hour and a half", 90),
+        ] {
+            let event = parse(input);
+            assert_eq!(event.summary, "Dentist");
+            assert_eq!(event.location, Some("Clinic".to_owned()));
+            let duration = jiff::SignedDuration::try_from(event.duration.unwrap()).unwrap();
+            assert_eq!(
+                duration,
+                jiff::SignedDuration::from_mins(minutes),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn matched_segments() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let parse = |s| NewEvent::parse_with_segments(s, now.clone(), ParserOptions::default());
+        let input = "Memory meetup 18.11. 14:00-16:00 EET @ Memory Plaza";
+        let (event, segments) = parse(input).unwrap();
+        assert_eq!(event.location, Some("Memory Plaza".to_owned()));
+        assert_eq!(
+            segments,
+            MatchedSegments {
+                summary_text: "Memory meetup",
+                date_text: "18.11.",
+                time_text: Some("14:00-16:00 EET"),
+                location_text: Some("@ Memory Plaza"),
+                duration_text: None,
+            }
+        );
+        let (_, retro) = parse("Retro tomorrow with Anna 14:00,A769 for 45 min").unwrap();
+        assert_eq!(retro.date_text, "tomorrow");
+        assert_eq!(retro.time_text, Some("14:00"));
+        assert_eq!(retro.location_text, Some(",A769"));
+        assert_eq!(retro.duration_text, Some("for 45 min"));
+        let (_, standup) = parse("Standup tomorrow 9:00 +03:00 (15min)").unwrap();
+        assert_eq!(standup.time_text, Some("9:00 +03:00"));
+        assert_eq!(standup.duration_text, Some("(15min)"));
+        let (_, vacation) = parse("Vacation from monday until friday").unwrap();
+        assert_eq!(vacation.date_text, "from monday until friday");
+        assert_eq!(vacation.time_text, None);
+        assert_eq!(vacation.location_text, None);
+        assert_eq!(vacation.duration_text, None);
+        let (_, holiday) = parse("Holiday tomorrow for 2 weeks").unwrap();
+        assert_eq!(holiday.duration_text, Some("for 2 weeks"));
+        let (_, tea) = parse("Tea in 10 minutes").unwrap();
+        assert_eq!(tea.date_text, "");
+        assert_eq!(tea.time_text, Some("in 10 minutes"));
+    }
+
+    #[test]
+    fn with_address_location() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let parse = |s| NewEvent::parse_at_time(s, now.clone()).unwrap();
+        let party = parse("Party saturday 19:00 @ Kanavaranta 7, 00160 Helsinki");
+        assert_eq!(
+            party.location,
+            Some("Kanavaranta 7, 00160 Helsinki".to_owned())
+        );
+        assert_eq!(party.description, None);
+        let visit = parse("Visit tomorrow 12:00, Mannerheimintie 5 A 3, 00100 Helsinki, Finland");
+        assert_eq!(
+            visit.location,
+            Some("Mannerheimintie 5 A 3, 00100 Helsinki, Finland".to_owned())
+        );
+        // Only "//" starts a description
+        let dinner =
+            parse("Dinner tomorrow 18:00 @ 1600 Pennsylvania Ave NW, Washington, DC // dress up");
+        assert_eq!(
+            dinner.location,
+            Some("1600 Pennsylvania Ave NW, Washington, DC".to_owned())
+        );
+        assert_eq!(dinner.description, Some("dress up".to_owned()));
+    }
+
+    #[test]
+    fn with_description() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let parse = |s| NewEvent::parse_at_time(s, now.clone()).unwrap();
+        let dentist = parse("Dentist tomorrow 10:00 @ Citydent // remember insurance card");
+        assert_eq!(dentist.location, Some("Citydent".to_owned()));
+        assert_eq!(
+            dentist.description,
+            Some("remember insurance card".to_owned())
+        );
+        let checkup = parse("Checkup tomorrow 10:00 // bring the referral, see https://x.fi");
+        assert_eq!(checkup.location, None);
+        assert_eq!(
+            checkup.description,
+            Some("bring the referral, see https://x.fi".to_owned())
+        );
+        let retro = parse("Retro tomorrow 14:00 @ A769 // agenda in the wiki #work");
+        assert_eq!(retro.location, Some("A769".to_owned()));
+        assert_eq!(retro.description, Some("agenda in the wiki".to_owned()));
+        assert_eq!(retro.tags, vec!["work"]);
+        // A location introduced by a comma may contain commas itself
+        let party = parse("Party 8.6. 22:00, Club, Helsinki");
+        assert_eq!(party.location, Some("Club, Helsinki".to_owned()));
+        assert_eq!(party.description, None);
+        let json = serde_json::to_string(&dentist).unwrap();
+        assert!(json.contains("\"description\":\"remember insurance card\""));
+        assert_eq!(serde_json::from_str::<NewEvent>(&json).unwrap(), dentist);
+    }
+
+    #[test]
+    fn with_tags() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let parse = |s| NewEvent::parse_at_time(s, now.clone()).unwrap();
+        let dentist = parse("Dentist tomorrow 9:00 #health #Personal");
+        assert_eq!(dentist.summary, "Dentist");
+        assert_eq!(dentist.tags, vec!["health", "Personal"]);
+        let lunch = parse("#food Lunch #social with Anna tomorrow 12:00 @ Pompier #work");
+        assert_eq!(lunch.summary, "Lunch with Anna");
+        assert_eq!(lunch.location, Some("Pompier".to_owned()));
+        assert_eq!(lunch.tags, vec!["food", "social", "work"]);
+        let call = parse("Call tomorrow 14:00 https://meet.example.com/#room #work");
+        assert_eq!(call.url, Some("https://meet.example.com/#room".to_owned()));
+        assert_eq!(call.tags, vec!["work"]);
+    }
+
+    #[test]
+    fn without_tags() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Issue 1#2 review tomorrow 9:00", now).unwrap();
+        assert_eq!(event.summary, "Issue 1#2 review");
+        assert!(event.tags.is_empty());
+    }
+
+    #[test]
+    fn with_url() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let parse = |s| NewEvent::parse_at_time(s, now.clone()).unwrap();
+        let one_on_one = parse("1:1 with Maria tomorrow 14:00 https://meet.example.com/abc");
+        assert_eq!(one_on_one.summary, "1:1 with Maria");
+        assert_eq!(
+            one_on_one.url,
+            Some("https://meet.example.com/abc".to_owned())
+        );
+        assert_eq!(one_on_one.location, None);
+        let standup = parse("Standup tomorrow 9:15 @ A769, https://meet.example.com/abc.");
+        assert_eq!(standup.location, Some("A769".to_owned()));
+        assert_eq!(standup.url, Some("https://meet.example.com/abc".to_owned()));
+        let retro = parse("Retro tomorrow 14:00, http://meet.example.com/xyz @ A769");
+        assert_eq!(retro.location, Some("A769".to_owned()));
+        assert_eq!(retro.url, Some("http://meet.example.com/xyz".to_owned()));
+        let json = serde_json::to_string(&retro).unwrap();
+        assert!(json.contains("\"url\":\"http://meet.example.com/xyz\""));
+        assert_eq!(serde_json::from_str::<NewEvent>(&json).unwrap(), retro);
+    }
+
+    #[test]
+    fn with_priority() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let parse = |s| NewEvent::parse_at_time(s, now.clone()).unwrap();
+        let report = parse("Submit report tomorrow 17:00 !!");
+        assert_eq!(report.summary, "Submit report");
+        assert_eq!(report.priority, Some(5));
+        assert_eq!(report.datetime(), date(2024, 6, 2).at(17, 0, 0, 0));
+        let review = parse("Code review (high priority) tomorrow 10:00 @ A769");
+        assert_eq!(review.summary, "Code review");
+        assert_eq!(review.priority, Some(1));
+        assert_eq!(review.location, Some("A769".to_owned()));
+        let taxes = parse("! Taxes 18.11.");
+        assert_eq!(taxes.summary, "Taxes");
+        assert_eq!(taxes.priority, Some(9));
+        let deploy = parse("Deploy tomorrow 9:00 @ Server room !!!");
+        assert_eq!(deploy.location, Some("Server room".to_owned()));
+        assert_eq!(deploy.priority, Some(1));
+        assert_eq!(parse("Yay! tomorrow 9:00").priority, None);
+        assert_eq!(parse("Meetup tomorrow 9:00").priority, None);
+    }
+
+    #[test]
+    fn with_geo_coordinates() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let parse = |s| NewEvent::parse_at_time(s, now.clone()).unwrap();
+        let inspection = parse("Site inspection tomorrow 8:00 @ 60.1699, 24.9384");
+        assert_eq!(inspection.location, Some("60.1699, 24.9384".to_owned()));
+        assert_eq!(inspection.geo, Some((60.1699, 24.9384)));
+        assert_eq!(inspection.description, None);
+        let survey = parse("Survey tomorrow 8:00 @ -33.8688,151.2093 // bring the drone");
+        assert_eq!(survey.geo, Some((-33.8688, 151.2093)));
+        assert_eq!(survey.description, Some("bring the drone".to_owned()));
+        let json = serde_json::to_string(&survey).unwrap();
+        assert!(json.contains("\"geo\":[-33.8688,151.2093]"));
+        assert_eq!(serde_json::from_str::<NewEvent>(&json).unwrap(), survey);
+    }
+
+    #[test]
+    fn without_geo_coordinates() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let parse = |s| NewEvent::parse_at_time(s, now.clone()).unwrap();
+        let invalid = parse("Site inspection tomorrow 8:00 @ 95.0, 24.9384");
+        assert_eq!(invalid.location, Some("95.0, 24.9384".to_owned()));
+        assert_eq!(invalid.geo, None);
+        let room = parse("Retro tomorrow 14:00 @ A769 // bring snacks");
+        assert_eq!(room.location, Some("A769".to_owned()));
+        assert_eq!(room.geo, None);
+    }
+
+    #[test]
+    fn with_online_meeting() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let parse = |s| NewEvent::parse_with_segments(s, now.clone(), ParserOptions::default());
+        let (planning, segments) = parse("Planning tomorrow 10:00 on Zoom").unwrap();
+        assert_eq!(planning.summary, "Planning");
+        assert!(planning.is_online);
+        assert_eq!(planning.location, Some("Zoom".to_owned()));
+        assert_eq!(segments.location_text, Some("on Zoom"));
+        let (sync, _) = parse("Sync tomorrow 10:00 via teams").unwrap();
+        assert!(sync.is_online);
+        assert_eq!(sync.location, Some("Microsoft Teams".to_owned()));
+        let (standup, _) = parse("Standup tomorrow 9:00 on Google Meet").unwrap();
+        assert_eq!(standup.location, Some("Google Meet".to_owned()));
+        let (demo, _) = parse("Demo tomorrow 14:00 on Zoom https://zoom.us/j/123").unwrap();
+        assert!(demo.is_online);
+        assert_eq!(demo.location, Some("https://zoom.us/j/123".to_owned()));
+        assert_eq!(demo.url, Some("https://zoom.us/j/123".to_owned()));
+        let (review, _) = parse("Review tomorrow 11:00 @ Webex").unwrap();
+        assert!(review.is_online);
+        assert_eq!(review.location, Some("Webex".to_owned()));
+        let (lecture, lecture_segments) = parse("Lecture tomorrow 12:00 online").unwrap();
+        assert!(lecture.is_online);
+        assert_eq!(lecture.location, None);
+        assert_eq!(lecture_segments.location_text, None);
+        let (palaveri, _) = parse("Palaveri huomenna klo 9 etänä").unwrap();
+        assert!(palaveri.is_online);
+        let (meeting, _) = parse("Meeting tomorrow 10:00 @ A769").unwrap();
+        assert!(!meeting.is_online);
+        // "meet" alone is a verb rather than a provider
+        let (coffee, _) = parse("Coffee tomorrow 10:00 meet Anna").unwrap();
+        assert!(!coffee.is_online);
+        let input = "Call tomorrow 9:00 on Zoom re: budget";
+        let (call, call_rest) = NewEvent::parse_with_remainder(input, now).unwrap();
+        assert!(call.is_online);
+        assert_eq!(call_rest, "re: budget");
+    }
+
+    #[test]
+    fn with_room_locations() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let options = ParserOptions {
+            room_locations: true,
+            ..Default::default()
+        };
+        let parse = |s| {
+            NewEvent::parse_detailed(s, now.clone(), options.clone())
+                .map(|detailed| (detailed.parsed.event, detailed.remainder))
+        };
+        let (sync, sync_rest) = parse("Architecture sync tomorrow 13:00 room 204").unwrap();
+        assert_eq!(sync.summary, "Architecture sync");
+        assert_eq!(sync.time, Some(Time::constant(13, 0, 0, 0)));
+        assert_eq!(sync.location, Some("room 204".to_owned()));
+        assert_eq!(sync_rest, "");
+        let (palaveri, _) = parse("Palaveri huomenna klo 9 Huone 3.14").unwrap();
+        assert_eq!(palaveri.location, Some("Huone 3.14".to_owned()));
+        let (review, _) = parse("Design review tomorrow 11:00 A769 for 45 min").unwrap();
+        assert_eq!(review.location, Some("A769".to_owned()));
+        assert_eq!(
+            review.duration.map(|d| d.fieldwise()),
+            Some(45.minutes().fieldwise())
+        );
+        let (demo, _) = parse("Demo tomorrow 15:00 B-wing 2nd floor").unwrap();
+        assert_eq!(demo.location, Some("B-wing 2nd floor".to_owned()));
+        let (call, call_rest) = parse("Call tomorrow 13:00 regarding budget").unwrap();
+        assert_eq!(call.location, None);
+        assert_eq!(call_rest, "regarding budget");
+        let (cleanup, _) = parse("Cleanup tomorrow 13:00 room").unwrap();
+        assert_eq!(cleanup.location, None);
+        // Disabled by default
+        let default = NewEvent::parse_at_time("Architecture sync tomorrow 13:00 room 204", now);
+        assert_eq!(default.unwrap().location, None);
+    }
+
+    #[test]
+    fn with_location_delimiters() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let parse = |s, options| NewEvent::parse_at_time_with_options(s, now.clone(), options);
+        let options = ParserOptions {
+            location_delimiters: vec!['@', ',', ';', '|'],
+            ..Default::default()
+        };
+        let meeting = parse("Meeting tomorrow 11:00 ; Room 2", options.clone()).unwrap();
+        assert_eq!(meeting.summary, "Meeting");
+        assert_eq!(meeting.datetime(), date(2024, 6, 2).at(11, 0, 0, 0));
+        assert_eq!(meeting.location, Some("Room 2".to_owned()));
+        let review = parse("Review tomorrow 11:00 |Room 2", options).unwrap();
+        assert_eq!(review.location, Some("Room 2".to_owned()));
+        let default = parse("Meeting tomorrow 11:00 ; Room 2", ParserOptions::default());
+        assert_eq!(default.unwrap().location, None);
+        let only_semicolon = ParserOptions {
+            location_delimiters: vec![';'],
+            ..Default::default()
+        };
+        let standup = parse("Standup tomorrow 9:00 @ Room 2", only_semicolon).unwrap();
+        assert_eq!(standup.location, None);
+    }
+
+    #[test]
+    fn with_email_address() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let parse = |s| NewEvent::parse_at_time(s, now.clone()).unwrap();
+        let invite = parse("Send invite to anna@example.com tomorrow 9:00");
+        assert_eq!(invite.summary, "Send invite to anna@example.com");
+        assert_eq!(invite.datetime(), date(2024, 6, 2).at(9, 0, 0, 0));
+        assert_eq!(invite.location, None);
+        let sync = parse("Sync tomorrow 9:00 with anna@example.com");
+        assert_eq!(sync.summary, "Sync with anna@example.com");
+        assert_eq!(sync.location, None);
+        let review = parse("Review tomorrow 9:00 @A769 // ask anna@example.com");
+        assert_eq!(review.location, Some("A769".to_owned()));
+        assert_eq!(review.description, Some("ask anna@example.com".to_owned()));
+        let lunch = parse("Lunch @Pompier with bob@example.com tomorrow 12:00");
+        assert_eq!(lunch.summary, "Lunch");
+        assert_eq!(
+            lunch.location,
+            Some("Pompier with bob@example.com".to_owned())
+        );
+    }
+
+    #[test]
+    fn with_finnish_location_marker() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let parse = |s| NewEvent::parse_at_time(s, now.clone()).unwrap();
+        let meeting = parse("Kokous huomenna klo 10 paikassa A769");
+        assert_eq!(meeting.summary, "Kokous");
+        assert_eq!(meeting.location, Some("A769".to_owned()));
+        let board = parse("Hallitus huomenna klo 10 Paikka: Kirjasto // kahvit tarjolla");
+        assert_eq!(board.location, Some("Kirjasto".to_owned()));
+        assert_eq!(board.description, Some("kahvit tarjolla".to_owned()));
+        let visit = parse("Käynti 18.11. klo 12 OSOITTEESSA Mannerheimintie 1");
+        assert_eq!(visit.location, Some("Mannerheimintie 1".to_owned()));
+        // Before the datetime the marker is part of the summary
+        let coffee = parse("Kahvit paikassa Fazer huomenna klo 10");
+        assert_eq!(coffee.summary, "Kahvit paikassa Fazer");
+        assert_eq!(coffee.location, None);
+    }
+
+    #[test]
+    fn with_messy_location() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let parse = |s| NewEvent::parse_with_segments(s, now.clone(), ParserOptions::default());
+        let (meet, segments) = parse("Meet Sam tomorrow 18:00 @ Oodi.").unwrap();
+        assert_eq!(meet.location, Some("Oodi".to_owned()));
+        assert_eq!(segments.location_text, Some("@ Oodi"));
+        let (picnic, _) = parse("Picnic tomorrow 12:00 , the park )").unwrap();
+        assert_eq!(picnic.location, Some("the park".to_owned()));
+        let (visit, _) = parse("Visit tomorrow 10:00 @ Mannerheimintie 5 A 3.").unwrap();
+        assert_eq!(visit.location, Some("Mannerheimintie 5 A 3".to_owned()));
+        let (party, _) = parse("Party tomorrow 20:00 @   Kallio!!  ").unwrap();
+        assert_eq!(party.location, Some("Kallio".to_owned()));
+        let (sauna, _) = parse("Sauna tomorrow 19:00 @ (Kulttuurisauna").unwrap();
+        assert_eq!(sauna.location, Some("Kulttuurisauna".to_owned()));
+        let (talk, _) = parse("Talk tomorrow 14:00 @ Hall (B).").unwrap();
+        assert_eq!(talk.location, Some("Hall (B)".to_owned()));
+        let (review, _) = parse("Review tomorrow 9:00 @ St. Mary's, 3rd fl.?").unwrap();
+        assert_eq!(review.location, Some("St. Mary's, 3rd fl".to_owned()));
+        let (standup, _) = parse("Standup tomorrow 9:00 @ A769; for 15 min.").unwrap();
+        assert_eq!(standup.location, Some("A769".to_owned()));
+        assert_eq!(
+            standup.duration.map(|d| d.fieldwise()),
+            Some(15.minutes().fieldwise())
+        );
+        let (lunch, _) = parse("Lunch @ Pompier. tomorrow 12:00").unwrap();
+        assert_eq!(lunch.location, Some("Pompier".to_owned()));
+    }
+
+    #[test]
+    fn with_location_before_datetime() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let parse = |s| NewEvent::parse_with_segments(s, now.clone(), ParserOptions::default());
+        let (lunch, segments) = parse("Lunch @ Pompier tomorrow 12:00").unwrap();
+        assert_eq!(lunch.summary, "Lunch");
+        assert_eq!(lunch.location, Some("Pompier".to_owned()));
+        assert_eq!(lunch.datetime(), date(2024, 6, 2).at(12, 0, 0, 0));
+        assert_eq!(segments.summary_text, "Lunch");
+        assert_eq!(segments.location_text, Some("@ Pompier"));
+        let (brunch, _) = parse("@ Pompier lunch tomorrow").unwrap();
+        assert_eq!(brunch.summary, "lunch");
+        assert_eq!(brunch.location, Some("Pompier".to_owned()));
+        let (dinner, _) = parse("Dinner at Cafe Ursula tomorrow 18:00").unwrap();
+        assert_eq!(dinner.summary, "Dinner");
+        assert_eq!(dinner.location, Some("Cafe Ursula".to_owned()));
+        // A location after the datetime takes precedence
+        let (drinks, _) = parse("Drinks @ Pompier tomorrow 18:00 @ Kallio").unwrap();
+        assert_eq!(drinks.summary, "Drinks @ Pompier");
+        assert_eq!(drinks.location, Some("Kallio".to_owned()));
+        // "at" only introduces a capitalized location
+        let (stars, _) = parse("Look at the stars tomorrow 22:00").unwrap();
+        assert_eq!(stars.summary, "Look at the stars");
+        assert_eq!(stars.location, None);
+    }
+
+    #[test]
+    fn with_preposition_location() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let parse = |s| NewEvent::parse_at_time(s, now.clone()).unwrap();
+        let event = parse("Standup tomorrow 9:15 at the office");
+        assert_eq!(event.summary, "Standup");
+        assert_eq!(event.datetime(), date(2024, 6, 2).at(9, 15, 0, 0));
+        assert_eq!(event.location, Some("the office".to_owned()));
+        let dinner = parse("Dinner tomorrow at 5 at Cafe X");
+        assert_eq!(dinner.datetime(), date(2024, 6, 2).at(5, 0, 0, 0));
+        assert_eq!(dinner.location, Some("Cafe X".to_owned()));
+        let trip = parse("Conference 18.11. all day in Helsinki");
+        assert_eq!(trip.location, Some("Helsinki".to_owned()));
+        let luento = parse("Luento huomenna klo 10 paikassa Kirjasto for 2 hours");
+        assert_eq!(luento.location, Some("Kirjasto".to_owned()));
+        assert!(luento.duration.unwrap().fieldwise() == 2.hours());
+        // Without a time, only a location not starting with a number is accepted
+        assert_eq!(
+            parse("Party 18.11. at home").location,
+            Some("home".to_owned())
+        );
+        assert_eq!(parse("Party 18.11. at 25 Main St").location, None);
+    }
+
+    #[test]
+    fn with_time_after_at() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let parse = |s| NewEvent::parse_at_time(s, now.clone()).unwrap();
+        let dinner = parse("Dinner tomorrow at 7");
+        assert_eq!(dinner.datetime(), date(2024, 6, 2).at(7, 0, 0, 0));
+        assert_eq!(dinner.location, None);
+        let late = parse("Dinner tomorrow at 17 @ Home");
+        assert_eq!(late.datetime(), date(2024, 6, 2).at(17, 0, 0, 0));
+        assert_eq!(late.location, Some("Home".to_owned()));
+        let cafe = parse("Dinner tomorrow at Cafe Ursula");
+        assert_eq!(cafe.time, None);
+        assert_eq!(cafe.location, Some("Cafe Ursula".to_owned()));
+        let kahvit = parse("Kahvit huomenna klo 9 paikassa Fazer");
+        assert_eq!(kahvit.datetime(), date(2024, 6, 2).at(9, 0, 0, 0));
+        assert_eq!(kahvit.location, Some("Fazer".to_owned()));
+        let options = ParserOptions {
+            bare_hour_times: false,
+            bare_hour_meridiem: MeridiemInference::Daytime {
+                first_morning_hour: 8,
+            },
+            ..Default::default()
+        };
+        let evening = NewEvent::parse_at_time_with_options("Dinner tomorrow at 7", now, options);
+        assert_eq!(
+            evening.unwrap().datetime(),
+            date(2024, 6, 2).at(19, 0, 0, 0)
+        );
+    }
+
+    #[test]
+    fn with_trailing_connectors() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let summary = |s| NewEvent::parse_at_time(s, now.clone()).unwrap().summary;
+        assert_eq!(
+            summary("Dentist appointment on tomorrow"),
+            "Dentist appointment"
+        );
+        assert_eq!(summary("Meet Anna at 18.11."), "Meet Anna");
+        assert_eq!(summary("Lunch - tomorrow 12:00"), "Lunch");
+        assert_eq!(summary("Palaveri KLO huomenna"), "Palaveri");
+        assert_eq!(summary("Meeting – on tomorrow 10:00"), "Meeting");
+        // Connectors elsewhere are part of the title
+        assert_eq!(summary("Day at the museum tomorrow"), "Day at the museum");
+        assert_eq!(summary("On tomorrow"), "On");
+        let options = ParserOptions {
+            trailing_connectors: Vec::new(),
+            ..Default::default()
+        };
+        let raw = NewEvent::parse_at_time_with_options("Meet Anna at 18.11.", now, options);
+        assert_eq!(raw.unwrap().summary, "Meet Anna at");
+    }
+
+    #[test]
+    fn with_remainder_in_summary() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let summary = |s| NewEvent::parse_at_time(s, now.clone()).unwrap().summary;
+        assert_eq!(
+            summary("Standup tomorrow 9:15 with the design team"),
+            "Standup with the design team"
+        );
+        assert_eq!(summary("Retro tomorrow with Anna 14:00"), "Retro with Anna");
+        assert_eq!(
+            summary("Call mom tomorrow re: budget (urgent?!)"),
+            "Call mom re: budget (urgent?!)"
+        );
+        assert_eq!(
+            summary("Lunch tomorrow 12:00 w/ Ben & Jerry's"),
+            "Lunch w/ Ben & Jerry's"
+        );
+        assert_eq!(
+            summary("Vuosikokous huomenna klo 18 ja sauna"),
+            "Vuosikokous ja sauna"
+        );
+        // Text claimed by other components isn't repeated
+        assert_eq!(
+            summary("Standup tomorrow 9:15 @ A769 for 15 min #work"),
+            "Standup"
+        );
+        // The remainder may be the only summary
+        let options = ParserOptions {
+            allow_missing_summary: true,
+            ..Default::default()
+        };
+        let untitled = NewEvent::parse_at_time_with_options(
+            "tomorrow 9:15 with the design team",
+            now,
+            options,
+        )
+        .unwrap();
+        assert_eq!(untitled.summary, "with the design team");
+    }
+
+    #[test]
+    fn with_remainder_nothing_lost() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        for input in [
+            "Standup tomorrow 9:15 with the design team",
+            "Retro tomorrow with Anna 14:00",
+            "Call mom tomorrow re: budget (urgent?!)",
+            "Lunch tomorrow 12:00 w/ Ben & Jerry's",
+            "Vuosikokous huomenna klo 18 ja sauna",
+            "Standup tomorrow 9:15 @ A769 for 15 min #work",
+            "Retro tomorrow with Anna 14:00,A769 for 45 min",
+            "Sync tomorrow 15:00 EET (1h) w/ the Helsinki team",
+            "Dentist tomorrow 10:00 @ Citydent // remember insurance card",
+            "1:1 with Maria #work tomorrow 14:00 https://meet.example.com/abc",
+            "Party saturday 19:00 @ Kanavaranta 7, 00160 Helsinki",
+        ] {
+            let (event, segments) =
+                NewEvent::parse_with_segments(input, now.clone(), ParserOptions::default())
+                    .unwrap();
+            let tags = event.tags.iter().map(|tag| format!("#{tag}"));
+            let parts: Vec<String> = [
+                Some(event.summary.as_str()),
+                event.location.as_deref(),
+                event.description.as_deref(),
+                event.url.as_deref(),
+                Some(segments.date_text),
+                segments.time_text,
+                segments.duration_text,
+                segments.location_text,
+            ]
+            .into_iter()
+            .flatten()
+            .map(str::to_owned)
+            .chain(tags)
+            .collect();
+            // Only the "//" separating the description is dropped
+            let words = input
+                .split([' ', ','])
+                .filter(|word| !word.is_empty() && *word != "//");
+            for word in words {
+                assert!(
+                    parts.iter().any(|part| part.contains(word)),
+                    "{word:?} of {input:?} was lost, got {parts:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn with_remainder() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let parse = |s| NewEvent::parse_with_remainder(s, now.clone()).unwrap();
+        let (call, remainder) = parse("Call mom tomorrow re: budget");
+        assert_eq!(call.summary, "Call mom");
+        assert_eq!(remainder, "re: budget");
+        let (retro, leftover) = parse("Retro tomorrow with Anna at 14:00 re: sprint 12");
+        assert_eq!(retro.datetime(), date(2024, 6, 2).at(14, 0, 0, 0));
+        assert_eq!(leftover, "with Anna re: sprint 12");
+    }
+
+    #[test]
+    fn without_remainder() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let parse = |s| NewEvent::parse_with_remainder(s, now.clone()).unwrap().1;
+        assert_eq!(parse("Meetup tomorrow 16:00 @ Memory Plaza"), "");
+        assert_eq!(parse("Standup tomorrow 9:15 at the office for 15 min"), "");
+        assert_eq!(
+            parse("Call tomorrow 14:00 https://meet.example.com/abc #work !!"),
+            ""
+        );
+        assert_eq!(
+            parse("Dentist tomorrow 10:00 // remember insurance card"),
+            ""
+        );
+        assert_eq!(parse("Vacation tomorrow for 2 weeks"), "");
+    }
+
+    #[test]
+    fn with_duration_in_seconds() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let parse = |s| NewEvent::parse_at_time(s, now.clone()).unwrap();
+        let backup = parse("Backup tomorrow 3:00 for 90 seconds");
+        assert_eq!(
+            backup.duration.map(|d| d.fieldwise()),
+            Some(90.seconds().fieldwise())
+        );
+        assert_eq!(
+            backup.end_datetime(),
+            Some(date(2024, 6, 2).at(3, 1, 30, 0))
+        );
+        let alarm = parse("Alarm test tomorrow 12:00 30s @ Lobby");
+        assert_eq!(
+            alarm.duration.map(|d| d.fieldwise()),
+            Some(30.seconds().fieldwise())
+        );
+        assert_eq!(alarm.location, Some("Lobby".to_owned()));
+        let party = parse("Party tomorrow 20:00 1990s theme");
+        assert!(party.duration.is_none());
+    }
+
+    #[test]
+    fn with_duration_in_days() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let parse = |s| NewEvent::parse_at_time(s, now.clone()).unwrap();
+        let vacation = parse("Vacation tomorrow for 2 weeks @ Lapland");
+        assert_eq!(vacation.date, date(2024, 6, 2));
+        assert_eq!(vacation.time, None);
+        assert!(vacation.duration.unwrap().fieldwise() == 14.days());
+        assert_eq!(vacation.location, Some("Lapland".to_owned()));
+        assert_eq!(
+            vacation.end_datetime(),
+            Some(date(2024, 6, 16).at(0, 0, 0, 0))
+        );
+        let trip = parse("Trip 18.11. for 3 days");
+        assert!(trip.duration.unwrap().fieldwise() == 3.days());
+        let course = parse("Kurssi huomenna 2 viikkoa");
+        assert!(course.duration.unwrap().fieldwise() == 14.days());
+        assert!(parse("Nap tomorrow for 2 hours").duration.is_none());
+        assert!(parse("Nap tomorrow for 90 seconds").duration.is_none());
+        let hike = parse("Hike tomorrow 9:00 for 2 days");
+        assert!(hike.duration.unwrap().fieldwise() == 2.days());
+    }
+
+    #[test]
+    fn with_duration_after_location() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let parse = |s| NewEvent::parse_at_time(s, now.clone()).unwrap();
+        for input in [
+            "Retro tomorrow 14:00 for 45 min @ A769",
+            "Retro tomorrow 14:00 @ A769 for 45 min",
+        ] {
+            let event = parse(input);
+            assert_eq!(event.summary, "Retro");
+            assert_eq!(event.datetime(), date(2024, 6, 2).at(14, 0, 0, 0));
+            assert_eq!(event.location, Some("A769".to_owned()));
+            assert!(event.duration.unwrap().fieldwise() == 45.minutes());
+        }
+        let sauna = parse("Sauna huomenna klo 18 @ Mökki puoli tuntia");
+        assert_eq!(sauna.location, Some("Mökki".to_owned()));
+        assert!(sauna.duration.unwrap().fieldwise() == 30.minutes());
+        let lunch = parse("Lunch tomorrow 12:00 @ Diner for 2");
+        assert_eq!(lunch.location, Some("Diner for 2".to_owned()));
+        assert!(lunch.duration.is_none());
+    }
+
+    #[test]
+    fn future_only() {
+        let now = date(2024, 6, 1).at(12, 0, 0, 0).in_tz("UTC").unwrap();
+        let parse = |s, options| NewEvent::parse_at_time_with_options(s, now.clone(), options);
+        let future_only = ParserOptions {
+            future_only: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            parse("Exam 18.11.2023 9:00", future_only.clone()),
+            Err(EventParseError::PastEvent)
+        );
+        assert_eq!(
+            parse("Exam 1.6.2024 11:59", future_only.clone()),
+            Err(EventParseError::PastEvent)
+        );
+        assert!(parse("Exam 18.11.2023 9:00", ParserOptions::default()).is_ok());
+        let event = parse("Exam 18.11.2024 9:00", future_only.clone()).unwrap();
+        assert_eq!(event.date, date(2024, 11, 18));
+        assert!(parse("Exam 1.6.2024", future_only.clone()).is_ok());
+        // 12:30 in Helsinki is 9:30 UTC
+        let zoned = parse("Exam 1.6.2024 12:30 Europe/Helsinki", future_only);
+        assert_eq!(zoned, Err(EventParseError::PastEvent));
+    }
+
+    #[test]
+    fn two_digit_year() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let parse = |s, options| NewEvent::parse_at_time_with_options(s, now.clone(), options);
+        let defaults = ParserOptions::default();
+        assert_eq!(
+            parse("Reunion 18.11.99", defaults.clone()).unwrap().date,
+            date(1999, 11, 18)
+        );
+        assert_eq!(
+            parse("Reunion 18.11.2024", defaults).unwrap().date,
+            date(2024, 11, 18)
+        );
+        let options = ParserOptions {
+            two_digit_year_pivot: 0,
+            ..Default::default()
+        };
+        assert_eq!(
+            parse("Reunion 18.11.24", options).unwrap().date,
+            date(1924, 11, 18)
+        );
+    }
+
+    #[test]
+    fn with_time_short() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("John's birthday 18.11. 16", now).unwrap();
+        assert_eq!(event.summary, "John's birthday");
+        assert_eq!(event.datetime().year(), 2024);
+        assert_eq!(event.datetime().day(), 18);
+        assert_eq!(event.datetime().month(), 11);
+        assert_eq!(event.datetime().hour(), 16);
+        assert_eq!(event.datetime().minute(), 0);
+        assert_eq!(event.location, None);
+        assert!(!event.all_day);
+    }
+
+    #[test]
+    fn with_time_long_a() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("John's birthday 18.11. 16:00", now).unwrap();
+        assert_eq!(event.summary, "John's birthday");
+        assert_eq!(event.datetime().year(), 2024);
+        assert_eq!(event.datetime().day(), 18);
+        assert_eq!(event.datetime().month(), 11);
+        assert_eq!(event.datetime().hour(), 16);
+        assert_eq!(event.datetime().minute(), 0);
+        assert_eq!(event.location, None);
+    }
+
+    #[test]
+    fn with_time_long_b() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("John's birthday 18.11. 1:59", now).unwrap();
+        assert_eq!(event.summary, "John's birthday");
+        assert_eq!(event.datetime().year(), 2024);
+        assert_eq!(event.datetime().day(), 18);
+        assert_eq!(event.datetime().month(), 11);
+        assert_eq!(event.datetime().hour(), 1);
+        assert_eq!(event.datetime().minute(), 59);
+        assert_eq!(event.location, None);
+    }
+
+    #[test]
+    fn with_time_dot() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Palaveri huomenna 16.30 @ Neukkari", now).unwrap();
+        assert_eq!(event.summary, "Palaveri");
+        assert_eq!(event.datetime(), date(2024, 6, 2).at(16, 30, 0, 0));
+        assert_eq!(event.location, Some("Neukkari".to_owned()));
+    }
+
+    #[test]
+    fn with_time_klo() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Sauna huomenna klo 18 @ Mökki", now).unwrap();
+        assert_eq!(event.summary, "Sauna");
+        assert_eq!(event.datetime(), date(2024, 6, 2).at(18, 0, 0, 0));
+        assert_eq!(event.location, Some("Mökki".to_owned()));
+    }
+
+    #[test]
+    fn with_day_part() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Dentist tomorrow morning", now).unwrap();
+        assert_eq!(event.summary, "Dentist");
+        assert_eq!(event.datetime(), date(2024, 6, 2).at(9, 0, 0, 0));
+        assert_eq!(event.day_part, Some(DayPart::Morning));
+    }
+
+    #[test]
+    fn with_meal_time() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Catch-up next friday lunchtime", now).unwrap();
+        assert_eq!(event.summary, "Catch-up");
+        assert_eq!(event.datetime(), date(2024, 6, 7).at(12, 0, 0, 0));
+        assert_eq!(event.day_part, Some(DayPart::Lunch));
+    }
+
+    #[test]
+    fn with_meal_in_summary() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Lunch with Anna tomorrow", now).unwrap();
+        assert_eq!(event.summary, "Lunch with Anna");
+        assert_eq!(event.time, None);
+        assert_eq!(event.day_part, None);
+    }
+
+    #[test]
+    fn with_meal_time_configured() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let options = ParserOptions {
+            day_parts: DayPartTimes {
+                dinner: Time::constant(19, 30, 0, 0),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let event =
+            NewEvent::parse_at_time_with_options("Family tomorrow dinner", now, options).unwrap();
+        assert_eq!(event.datetime(), date(2024, 6, 2).at(19, 30, 0, 0));
+        assert_eq!(event.day_part, Some(DayPart::Dinner));
+    }
+
+    #[test]
+    fn with_time_range() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event =
+            NewEvent::parse_at_time("Design review tomorrow 11:00-12:30 @ A769", now).unwrap();
+        assert_eq!(event.summary, "Design review");
+        assert_eq!(event.datetime(), date(2024, 6, 2).at(11, 0, 0, 0));
+        assert_eq!(
+            event.duration.map(Span::fieldwise),
+            Some(1.hour().minutes(30).fieldwise())
+        );
+        assert_eq!(event.location, Some("A769".to_owned()));
+    }
+
+    #[test]
+    fn with_time_range_words_a() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Meeting tomorrow from 11:00 to 13:00", now).unwrap();
+        assert_eq!(event.summary, "Meeting");
+        assert_eq!(event.datetime(), date(2024, 6, 2).at(11, 0, 0, 0));
+        assert_eq!(
+            event.duration.map(Span::fieldwise),
+            Some(2.hours().fieldwise())
+        );
+        assert_eq!(event.location, None);
+    }
+
+    #[test]
+    fn with_time_range_words_b() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event =
+            NewEvent::parse_at_time("Meeting tomorrow 11 to 13, Room to Rent", now).unwrap();
+        assert_eq!(event.summary, "Meeting");
+        assert_eq!(event.datetime(), date(2024, 6, 2).at(11, 0, 0, 0));
+        assert_eq!(
+            event.duration.map(Span::fieldwise),
+            Some(2.hours().fieldwise())
+        );
+        assert_eq!(event.location, Some("Room to Rent".to_owned()));
+    }
+
+    #[test]
+    fn with_time_range_words_c() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event =
+            NewEvent::parse_at_time("Meeting tomorrow 9:30 until 10:00 @ Lobby", now).unwrap();
+        assert_eq!(event.summary, "Meeting");
+        assert_eq!(
+            event.duration.map(Span::fieldwise),
+            Some(30.minutes().fieldwise())
+        );
+        assert_eq!(event.location, Some("Lobby".to_owned()));
+    }
+
+    #[test]
+    fn relative_time() {
+        let now = date(2024, 6, 1).at(18, 0, 0, 0).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Take the pizza out in 25 minutes", now).unwrap();
+        assert_eq!(event.summary, "Take the pizza out");
+        assert_eq!(event.datetime(), date(2024, 6, 1).at(18, 25, 0, 0));
+        assert_eq!(event.location, None);
+    }
+
+    #[test]
+    fn relative_time_large_amount() {
+        let now = date(2024, 6, 1).at(18, 0, 0, 0).in_tz("UTC").unwrap();
+        let minutes = NewEvent::parse_at_time("Call in 150 minutes", now.clone()).unwrap();
+        assert_eq!(minutes.summary, "Call");
+        assert_eq!(minutes.datetime(), date(2024, 6, 1).at(20, 30, 0, 0));
+        let hours = NewEvent::parse_at_time("Call in 200 hours", now.clone()).unwrap();
+        assert_eq!(hours.datetime(), date(2024, 6, 10).at(2, 0, 0, 0));
+        assert_eq!(
+            NewEvent::parse_at_time("Call in 99999999999 hours", now),
+            Err(EventParseError::InvalidTime {
+                text: "in 99999999999 hours".to_owned(),
+                at: 5,
+            })
+        );
+    }
+
+    #[test]
+    fn with_clock_time_without_date() {
+        let now = date(2024, 6, 1).at(12, 0, 0, 0).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Palaveri kello 16.30", now.clone()).unwrap();
+        assert_eq!(event.summary, "Palaveri");
+        assert_eq!(event.datetime(), date(2024, 6, 1).at(16, 30, 0, 0));
+        let options = ParserOptions {
+            dot_times: true,
+            ..ParserOptions::default()
+        };
+        let with_dot_times =
+            NewEvent::parse_at_time_with_options("Palaveri klo 16.30", now, options).unwrap();
+        assert_eq!(with_dot_times.summary, "Palaveri");
+        assert_eq!(with_dot_times.datetime(), date(2024, 6, 1).at(16, 30, 0, 0));
+    }
+
+    #[test]
+    fn with_recurrence() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let parse = |s| NewEvent::parse_at_time(s, now.clone()).unwrap();
+        let gym = parse("Gym every other monday 18:00");
+        assert_eq!(gym.summary, "Gym");
+        assert_eq!(gym.datetime(), date(2024, 6, 3).at(18, 0, 0, 0));
+        assert_eq!(
+            gym.recurrence,
+            Some(Recurrence {
+                frequency: Frequency::Weekly,
+                interval: 2
+            })
+        );
+        let plants = parse("Water plants every 3 days 18:00 @ Home");
+        assert_eq!(plants.summary, "Water plants");
+        assert_eq!(plants.datetime(), date(2024, 6, 1).at(18, 0, 0, 0));
+        assert_eq!(plants.location, Some("Home".to_owned()));
+        assert_eq!(
+            plants.recurrence.map(|recurrence| recurrence.rrule()),
+            Some("FREQ=DAILY;INTERVAL=3".to_owned())
+        );
+        let standup = parse("Standup daily 9:00");
+        assert_eq!(standup.summary, "Standup");
+        assert_eq!(standup.datetime(), date(2024, 6, 1).at(9, 0, 0, 0));
+        assert_eq!(
+            standup.recurrence.map(|recurrence| recurrence.rrule()),
+            Some("FREQ=DAILY".to_owned())
+        );
+        let sync = parse("Sync every week 10:00");
+        assert_eq!(
+            sync.recurrence.map(|recurrence| recurrence.rrule()),
+            Some("FREQ=WEEKLY".to_owned())
+        );
+        // "Weekly" describes the event here
+        let review = parse("Weekly review tomorrow 10:00");
+        assert_eq!(review.summary, "Weekly review");
+        assert_eq!(review.recurrence, None);
+    }
+
+    #[test]
+    fn recurrence_serde_roundtrip() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Gym every other monday 18:00", now).unwrap();
+        let json = serde_json::to_string(&event).unwrap();
+        assert!(json.contains(r#""recurrence":{"frequency":"Weekly","interval":2}"#));
+        let parsed: NewEvent = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, event);
+    }
+
+    #[test]
+    fn with_compact_duration_parenthesized() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Meeting tomorrow 11:00 (2h) @ A769", now).unwrap();
+        assert_eq!(event.summary, "Meeting");
+        assert_eq!(event.datetime(), date(2024, 6, 2).at(11, 0, 0, 0));
+        assert_eq!(
+            event.duration.map(Span::fieldwise),
+            Some(2.hours().fieldwise())
+        );
+        assert_eq!(event.location, Some("A769".to_owned()));
+    }
+
+    #[test]
+    fn with_duration_for_hours() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event =
+            NewEvent::parse_at_time("Workshop tomorrow 13:00 for 2 hours @ A769", now).unwrap();
+        assert_eq!(event.summary, "Workshop");
+        assert_eq!(event.datetime(), date(2024, 6, 2).at(13, 0, 0, 0));
+        assert_eq!(
+            event.duration.map(Span::fieldwise),
+            Some(2.hours().fieldwise())
+        );
+        assert_eq!(event.location, Some("A769".to_owned()));
+    }
+
+    #[test]
+    fn with_duration_finnish_single_hour() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Treeni huomenna klo 17 tunnin ajan", now).unwrap();
+        assert_eq!(event.summary, "Treeni");
+        assert_eq!(event.datetime(), date(2024, 6, 2).at(17, 0, 0, 0));
+        assert_eq!(
+            event.duration.map(Span::fieldwise),
+            Some(1.hour().fieldwise())
+        );
+        assert_eq!(event.location, None);
+    }
+
+    #[test]
+    fn with_duration_finnish_hours() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("palaveri 3.6. 14:00 2 tuntia", now).unwrap();
+        assert_eq!(event.summary, "palaveri");
+        assert_eq!(
+            event.duration.map(Span::fieldwise),
+            Some(2.hours().fieldwise())
+        );
+    }
+
+    #[test]
+    fn with_duration_bare_minutes() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Run tomorrow 7:00 90 minutes, Park", now).unwrap();
+        assert_eq!(event.summary, "Run");
+        assert_eq!(event.datetime(), date(2024, 6, 2).at(7, 0, 0, 0));
+        assert_eq!(
+            event.duration.map(Span::fieldwise),
+            Some(90.minutes().fieldwise())
+        );
+        assert_eq!(event.location, Some("Park".to_owned()));
+    }
+
+    #[test]
+    fn with_day_range() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Offsite 18.-20.11. @ Nuuksio", now).unwrap();
+        assert_eq!(event.summary, "Offsite");
+        assert_eq!(event.date, date(2024, 11, 18));
+        assert_eq!(event.time, None);
+        assert_eq!(
+            event.duration.map(Span::fieldwise),
+            Some(3.days().fieldwise())
+        );
+        assert_eq!(event.location, Some("Nuuksio".to_owned()));
+    }
+
+    #[test]
+    fn with_day_range_crossing_month() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Offsite 30.-2.12.", now).unwrap();
+        assert_eq!(event.date, date(2024, 11, 30));
+        assert_eq!(
+            event.duration.map(Span::fieldwise),
+            Some(3.days().fieldwise())
+        );
+    }
+
+    #[test]
+    fn with_day_range_and_time_range() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Conference 18.-20.11. 9-17", now).unwrap();
+        assert_eq!(event.datetime(), date(2024, 11, 18).at(9, 0, 0, 0));
+        assert_eq!(
+            event.duration.map(Span::fieldwise),
+            Some(2.days().hours(8).fieldwise())
+        );
+    }
+
+    #[test]
+    fn with_time_meridiem() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Call mom tomorrow 4pm", now).unwrap();
+        assert_eq!(event.summary, "Call mom");
+        assert_eq!(event.datetime(), date(2024, 6, 2).at(16, 0, 0, 0));
+    }
+
+    #[test]
+    fn with_time_range_shared_meridiem() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Workshop tomorrow 2-4pm @ Lab", now).unwrap();
+        assert_eq!(event.datetime(), date(2024, 6, 2).at(14, 0, 0, 0));
+        assert_eq!(
+            event.duration.map(Span::fieldwise),
+            Some(2.hours().fieldwise())
+        );
+        assert_eq!(event.location, Some("Lab".to_owned()));
+    }
+
+    #[test]
+    fn with_time_range_shared_meridiem_words() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Standup tomorrow 9 to 11am", now).unwrap();
+        assert_eq!(event.datetime(), date(2024, 6, 2).at(9, 0, 0, 0));
+        assert_eq!(
+            event.duration.map(Span::fieldwise),
+            Some(2.hours().fieldwise())
+        );
+    }
+
+    #[test]
+    fn with_time_range_shared_meridiem_crossing_noon() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Brunch tomorrow 11-1pm", now).unwrap();
+        assert_eq!(event.datetime(), date(2024, 6, 2).at(11, 0, 0, 0));
+        assert_eq!(
+            event.duration.map(Span::fieldwise),
+            Some(2.hours().fieldwise())
+        );
+    }
+
+    #[test]
+    fn with_time_range_between() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event =
+            NewEvent::parse_at_time("Interview slot tomorrow between 2 and 3 pm", now).unwrap();
+        assert_eq!(event.summary, "Interview slot");
+        assert_eq!(event.datetime(), date(2024, 6, 2).at(14, 0, 0, 0));
+        assert_eq!(
+            event.duration.map(Span::fieldwise),
+            Some(1.hour().fieldwise())
+        );
+        assert_eq!(event.location, None);
+    }
+
+    #[test]
+    fn with_time_range_from_to() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event =
+            NewEvent::parse_at_time("Open house overmorrow from 10 to 12, Main St 1", now).unwrap();
+        assert_eq!(event.summary, "Open house");
+        assert_eq!(event.datetime(), date(2024, 6, 3).at(10, 0, 0, 0));
+        assert_eq!(
+            event.duration.map(Span::fieldwise),
+            Some(2.hours().fieldwise())
+        );
+        assert_eq!(event.location, Some("Main St 1".to_owned()));
+    }
+
+    #[test]
+    fn with_time_range_own_meridiems() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Shift tomorrow 10pm-2am", now).unwrap();
+        assert_eq!(event.datetime(), date(2024, 6, 2).at(22, 0, 0, 0));
+        assert_eq!(
+            event.duration.map(Span::fieldwise),
+            Some(4.hours().fieldwise())
+        );
+    }
+
+    #[test]
+    fn with_compact_duration_hours_minutes() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Workshop tomorrow 13:00 2h30, Lab 2", now).unwrap();
+        assert_eq!(
+            event.duration.map(Span::fieldwise),
+            Some(2.hours().minutes(30).fieldwise())
+        );
+        assert_eq!(event.location, Some("Lab 2".to_owned()));
+    }
+
+    #[test]
+    fn with_compact_duration_minutes() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Run tomorrow 7:00 90min", now).unwrap();
+        assert_eq!(
+            event.duration.map(Span::fieldwise),
+            Some(90.minutes().fieldwise())
+        );
+        assert_eq!(event.location, None);
+    }
+
+    #[test]
+    fn with_compact_duration_after_bare_hour() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Focus block tomorrow 9 2h", now).unwrap();
+        assert_eq!(event.summary, "Focus block");
+        assert_eq!(event.datetime(), date(2024, 6, 2).at(9, 0, 0, 0));
+        assert_eq!(
+            event.duration.map(Span::fieldwise),
+            Some(2.hours().fieldwise())
+        );
+    }
+
+    #[test]
+    fn with_compact_duration_decimal_hours() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Call today 15:00 1.5h, Zoom", now).unwrap();
+        assert_eq!(event.summary, "Call");
+        assert_eq!(
+            event.duration.map(Span::fieldwise),
+            Some(1.hour().minutes(30).fieldwise())
+        );
+        assert_eq!(event.location, Some("Zoom".to_owned()));
+    }
+
+    #[test]
+    fn meters_are_not_a_duration() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Swim 2m deep tomorrow 7:00", now).unwrap();
+        assert_eq!(event.summary, "Swim 2m deep");
+        assert!(event.duration.is_none());
+    }
+
+    #[test]
+    fn with_time_zone_abbreviation() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Sync tomorrow 15:00 UTC @ Meet", now).unwrap();
+        assert_eq!(event.summary, "Sync");
+        assert_eq!(event.datetime(), date(2024, 6, 2).at(15, 0, 0, 0));
+        assert_eq!(event.offset(), Some(Offset::UTC));
+        assert_eq!(event.location, Some("Meet".to_owned()));
+    }
+
+    #[test]
+    fn with_time_zone_offset_and_duration() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Standup tomorrow 9:00 +03:00 (15min)", now).unwrap();
+        assert_eq!(event.offset(), Some(Offset::constant(3)));
+        assert_eq!(
+            event.duration.map(Span::fieldwise),
+            Some(15.minutes().fieldwise())
+        );
+        assert_eq!(event.location, None);
+    }
+
+    #[test]
+    fn with_time_zone_after_range() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Release tomorrow 15:00-16:00 EET, Zoom", now).unwrap();
+        assert_eq!(event.offset(), Some(Offset::constant(2)));
+        assert_eq!(
+            event.duration.map(Span::fieldwise),
+            Some(1.hour().fieldwise())
+        );
+        assert_eq!(event.location, Some("Zoom".to_owned()));
+    }
+
+    #[test]
+    fn with_time_zone_name() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Webinar 18.11. 09:00 EST", now).unwrap();
+        assert_eq!(event.summary, "Webinar");
+        assert_eq!(
+            event.timezone,
+            Some(EventTimeZone::Fixed {
+                name: "EST".to_owned(),
+                offset: Offset::constant(-5),
+            })
+        );
+        assert_eq!(event.offset(), Some(Offset::constant(-5)));
+        assert_eq!(event.location, None);
+    }
+
+    #[test]
+    fn with_time_zone_iana_summer() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event =
+            NewEvent::parse_at_time("Call tomorrow 15:00 Europe/Helsinki @ Teams", now).unwrap();
+        assert_eq!(
+            event.timezone,
+            Some(EventTimeZone::Iana("Europe/Helsinki".to_owned()))
+        );
+        assert_eq!(event.offset(), Some(Offset::constant(3)));
+        assert_eq!(event.location, Some("Teams".to_owned()));
+    }
+
+    #[test]
+    fn with_time_zone_iana_winter() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Call 18.11. 15:00 Europe/Helsinki", now).unwrap();
+        assert_eq!(event.offset(), Some(Offset::constant(2)));
+        let start = event.zoned_datetime(TimeZone::UTC).unwrap();
+        assert_eq!(start.time_zone().iana_name(), Some("Europe/Helsinki"));
+        let expected = date(2024, 11, 18)
+            .at(13, 0, 0, 0)
+            .to_zoned(TimeZone::UTC)
+            .unwrap();
+        assert_eq!(start.timestamp(), expected.timestamp());
+    }
+
+    #[test]
+    fn to_zoned_and_end_zoned() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Review tomorrow 11:00-12:30 EET", now).unwrap();
+        let helsinki = TimeZone::get("Europe/Helsinki").unwrap();
+        let start = event.to_zoned(&helsinki).unwrap();
+        assert_eq!(
+            start.to_string(),
+            "2024-06-02T11:00:00+03:00[Europe/Helsinki]"
+        );
+        let end = event.end_zoned(&helsinki).unwrap().unwrap();
+        assert_eq!(
+            end.to_string(),
+            "2024-06-02T12:30:00+03:00[Europe/Helsinki]"
+        );
+    }
+
+    #[test]
+    fn end_zoned_without_duration() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Review tomorrow 11:00", now).unwrap();
+        assert_eq!(event.end_zoned(&TimeZone::UTC).unwrap(), None);
+    }
+
+    #[test]
+    fn to_zoned_dst_gap() {
+        let now = date(2024, 3, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Night shift 31.3. 3:30 (2h)", now).unwrap();
+        let helsinki = TimeZone::get("Europe/Helsinki").unwrap();
+        let start = event.to_zoned(&helsinki).unwrap();
+        assert_eq!(
+            start.to_string(),
+            "2024-03-31T04:30:00+03:00[Europe/Helsinki]"
+        );
+        let end = event.end_zoned(&helsinki).unwrap().unwrap();
+        assert_eq!(
+            end.to_string(),
+            "2024-03-31T06:30:00+03:00[Europe/Helsinki]"
+        );
+    }
+
+    #[test]
+    fn to_zoned_dst_fold() {
+        let now = date(2024, 10, 1).in_tz("UTC").unwrap();
+        // Clocks in Finland go back from 04:00 to 03:00 on 27.10.2024
+        let event = NewEvent::parse_at_time("Night shift 27.10. 2:30 (2h)", now).unwrap();
+        let helsinki = TimeZone::get("Europe/Helsinki").unwrap();
+        let start = event.to_zoned(&helsinki).unwrap();
+        assert_eq!(
+            start.to_string(),
+            "2024-10-27T02:30:00+03:00[Europe/Helsinki]"
+        );
+        // Two hours later the clocks show only an hour later
+        let end = event.end_zoned(&helsinki).unwrap().unwrap();
+        assert_eq!(
+            end.to_string(),
+            "2024-10-27T03:30:00+02:00[Europe/Helsinki]"
+        );
+    }
+
+    #[test]
+    fn to_zoned_dst_fold_ambiguous_time() {
+        let now = date(2024, 10, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Night shift 27.10. 3:30", now).unwrap();
+        let helsinki = TimeZone::get("Europe/Helsinki").unwrap();
+        let start = event.to_zoned(&helsinki).unwrap();
+        assert_eq!(
+            start.to_string(),
+            "2024-10-27T03:30:00+03:00[Europe/Helsinki]"
+        );
+    }
+
+    #[test]
+    fn zoned_datetime_out_of_range() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let mut event = NewEvent::parse_at_time("Party 31.12.9999 23:00", now).unwrap();
+        assert_eq!(
+            event.zoned_datetime(TimeZone::UTC),
+            Err(EventParseError::InvalidTimeZone {
+                name: "UTC".to_owned()
+            })
+        );
+        event.timezone = Some(EventTimeZone::Fixed {
+            name: "EST".to_owned(),
+            offset: Offset::constant(-5),
+        });
+        assert_eq!(
+            event.zoned_datetime(TimeZone::UTC),
+            Err(EventParseError::InvalidTimeZone {
+                name: "EST".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn zoned_datetime_default_tz() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Sync tomorrow 15:00 @ Meet", now).unwrap();
+        assert_eq!(event.offset(), None);
+        let start = event
+            .zoned_datetime(TimeZone::fixed(Offset::constant(-5)))
+            .unwrap();
+        assert_eq!(start.offset(), Offset::constant(-5));
+        assert_eq!(start.datetime(), date(2024, 6, 2).at(15, 0, 0, 0));
+    }
+
+    #[test]
+    fn time_zone_serde_roundtrip() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Sync tomorrow 15:00 UTC+5:30", now).unwrap();
+        let json = serde_json::to_string(&event).unwrap();
+        assert!(json.contains(r#""timezone":{"Fixed":{"name":"UTC+5:30","offset":19800}}"#));
+        let parsed: NewEvent = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, event);
+    }
+
+    #[test]
+    fn time_zone_abbreviation_serde_roundtrip() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Sync tomorrow 15:00 CET", now).unwrap();
+        let json = serde_json::to_string(&event).unwrap();
+        let parsed: NewEvent = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, event);
+        assert_eq!(parsed.offset(), Some(Offset::constant(1)));
+        assert_eq!(
+            parsed.zoned_datetime(TimeZone::UTC).unwrap().to_string(),
+            "2024-06-02T15:00:00+01:00[+01:00]"
+        );
+    }
+
+    #[test]
+    fn time_zone_iana_serde_roundtrip() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Sync 18.11. 15:00 Europe/Helsinki", now).unwrap();
+        let json = serde_json::to_string(&event).unwrap();
+        assert!(json.contains(r#""timezone":{"Iana":"Europe/Helsinki"}"#));
+        let parsed: NewEvent = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, event);
+        assert_eq!(parsed.offset(), Some(Offset::constant(2)));
+    }
+
+    #[test]
+    fn with_time_oclock() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event =
+            NewEvent::parse_at_time("Dinner tomorrow at seven o'clock @ Home", now).unwrap();
+        assert_eq!(event.summary, "Dinner");
+        assert_eq!(event.datetime(), date(2024, 6, 2).at(7, 0, 0, 0));
+        assert_eq!(event.location, Some("Home".to_owned()));
+    }
+
+    #[test]
+    fn with_time_oclock_digits() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        for input in ["Meeting tomorrow 5 o'clock", "Meeting tomorrow 5o'clock"] {
+            let event = NewEvent::parse_at_time(input, now.clone()).unwrap();
+            assert_eq!(event.summary, "Meeting");
+            assert_eq!(event.datetime(), date(2024, 6, 2).at(5, 0, 0, 0));
+            let (_, remainder) = NewEvent::parse_with_remainder(input, now.clone()).unwrap();
+            assert_eq!(remainder, "");
+        }
+    }
+
+    #[test]
+    fn with_time_oclock_daytime() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let parse = |s, options| NewEvent::parse_at_time_with_options(s, now.clone(), options);
+        // Read as written by default, like bare hours
+        let as_written = parse("Dinner tomorrow seven o'clock", ParserOptions::default()).unwrap();
+        assert_eq!(as_written.summary, "Dinner");
+        assert_eq!(as_written.datetime(), date(2024, 6, 2).at(7, 0, 0, 0));
+        let options = ParserOptions {
+            bare_hour_meridiem: MeridiemInference::Daytime {
+                first_morning_hour: 8,
+            },
+            ..Default::default()
+        };
+        let event = parse("Dinner tomorrow 7 o'clock", options.clone()).unwrap();
+        assert_eq!(event.datetime(), date(2024, 6, 2).at(19, 0, 0, 0));
+        assert_eq!(event.location, None);
+        let spelled = parse("Dinner tomorrow seven o'clock", options).unwrap();
+        assert_eq!(spelled.datetime(), date(2024, 6, 2).at(19, 0, 0, 0));
+    }
+
+    #[test]
+    fn bare_hour_times_enabled() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Order 18.11. 3 pizzas", now).unwrap();
+        assert_eq!(event.time, Some(Time::constant(3, 0, 0, 0)));
+    }
+
+    #[test]
+    fn bare_hour_times_disabled() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let options = ParserOptions {
+            bare_hour_times: false,
+            ..Default::default()
+        };
+        let event =
+            NewEvent::parse_at_time_with_options("Order 18.11. 3 pizzas", now, options).unwrap();
+        assert_eq!(event.summary, "Order 3 pizzas");
+        assert_eq!(event.date, date(2024, 11, 18));
+        assert_eq!(event.time, None);
+    }
+
+    #[test]
+    fn bare_hour_times_disabled_at() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let options = ParserOptions {
+            bare_hour_times: false,
+            ..Default::default()
+        };
+        let event =
+            NewEvent::parse_at_time_with_options("Pizza party 18.11. at 3 @ Office", now, options)
+                .unwrap();
+        assert_eq!(event.datetime(), date(2024, 11, 18).at(3, 0, 0, 0));
+        assert_eq!(event.location, Some("Office".to_owned()));
+    }
+
+    #[test]
+    fn bare_hour_meridiem() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let options = ParserOptions {
+            bare_hour_meridiem: MeridiemInference::Daytime {
+                first_morning_hour: 8,
+            },
+            ..Default::default()
+        };
+        let parse =
+            |s| NewEvent::parse_at_time_with_options(s, now.clone(), options.clone()).unwrap();
+        assert_eq!(
+            parse("Dinner next friday at 7").time,
+            Some(Time::constant(19, 0, 0, 0))
+        );
+        assert_eq!(
+            parse("Standup next friday at 9").time,
+            Some(Time::constant(9, 0, 0, 0))
+        );
+        assert_eq!(
+            parse("Gym next friday 7:00").time,
+            Some(Time::constant(7, 0, 0, 0))
+        );
+        assert_eq!(
+            parse("Gym next friday 7am").time,
+            Some(Time::constant(7, 0, 0, 0))
+        );
+        assert_eq!(
+            parse("Match next friday 19").time,
+            Some(Time::constant(19, 0, 0, 0))
+        );
+    }
+
+    #[test]
+    fn bare_hour_meridiem_range() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let options = ParserOptions {
+            bare_hour_meridiem: MeridiemInference::Daytime {
+                first_morning_hour: 8,
+            },
+            ..Default::default()
+        };
+        let event =
+            NewEvent::parse_at_time_with_options("Work tomorrow 9-5", now, options).unwrap();
+        assert_eq!(event.datetime(), date(2024, 6, 2).at(9, 0, 0, 0));
+        assert_eq!(
+            event.duration.map(Span::fieldwise),
+            Some(8.hours().fieldwise())
+        );
+    }
+
+    #[test]
+    fn bare_hour_meridiem_default() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Dinner next friday at 7", now).unwrap();
+        assert_eq!(event.time, Some(Time::constant(7, 0, 0, 0)));
+    }
+
+    #[test]
+    fn bare_hour_meridiem_threshold() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let options = ParserOptions {
+            bare_hour_meridiem: MeridiemInference::Daytime {
+                first_morning_hour: 7,
+            },
+            ..Default::default()
+        };
+        let parse =
+            |s| NewEvent::parse_at_time_with_options(s, now.clone(), options.clone()).unwrap();
+        assert_eq!(
+            parse("Drinks tomorrow at 1").time,
+            Some(Time::constant(13, 0, 0, 0))
+        );
+        assert_eq!(
+            parse("Dinner tomorrow at 6").time,
+            Some(Time::constant(18, 0, 0, 0))
+        );
+        assert_eq!(
+            parse("Gym tomorrow at 7").time,
+            Some(Time::constant(7, 0, 0, 0))
+        );
+        assert_eq!(
+            parse("Lunch tomorrow at 12").time,
+            Some(Time::constant(12, 0, 0, 0))
+        );
+        assert_eq!(
+            parse("Dinner tomorrow 06:00").time,
+            Some(Time::constant(6, 0, 0, 0))
+        );
+        assert_eq!(
+            parse("Dinner tomorrow 6am").time,
+            Some(Time::constant(6, 0, 0, 0))
+        );
+    }
+
+    #[test]
+    fn with_time_half_hour() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let options = ParserOptions {
+            meridiem: MeridiemInference::Daytime {
+                first_morning_hour: 8,
+            },
+            ..Default::default()
+        };
+        let english = NewEvent::parse_at_time_with_options(
+            "Meeting tomorrow half five",
+            now.clone(),
+            options.clone(),
+        )
+        .unwrap();
+        assert_eq!(english.summary, "Meeting");
+        assert_eq!(english.datetime(), date(2024, 6, 2).at(17, 30, 0, 0));
+        let finnish =
+            NewEvent::parse_at_time_with_options("Palaveri huomenna puoli kuusi", now, options)
+                .unwrap();
+        assert_eq!(finnish.summary, "Palaveri");
+        assert_eq!(finnish.datetime(), date(2024, 6, 2).at(17, 30, 0, 0));
+    }
+
+    #[test]
+    fn with_time_in_the_evening() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Dinner tomorrow 7 in the evening", now).unwrap();
+        assert_eq!(event.summary, "Dinner");
+        assert_eq!(event.datetime(), date(2024, 6, 2).at(19, 0, 0, 0));
+        assert_eq!(event.location, None);
+    }
+
+    #[test]
+    fn with_time_after_at_in_part_of_day() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let daytime = ParserOptions {
+            bare_hour_meridiem: MeridiemInference::Daytime {
+                first_morning_hour: 8,
+            },
+            ..Default::default()
+        };
+        for options in [ParserOptions::default(), daytime] {
+            let parse =
+                |s| NewEvent::parse_at_time_with_options(s, now.clone(), options.clone()).unwrap();
+            let call = parse("Call tomorrow at 7 in the evening");
+            assert_eq!(call.summary, "Call");
+            assert_eq!(call.datetime(), date(2024, 6, 2).at(19, 0, 0, 0));
+            let run = parse("Run tomorrow at 7 in the morning");
+            assert_eq!(run.summary, "Run");
+            assert_eq!(run.datetime(), date(2024, 6, 2).at(7, 0, 0, 0));
+            let nap = parse("Nap tomorrow at 3 in the afternoon");
+            assert_eq!(nap.datetime(), date(2024, 6, 2).at(15, 0, 0, 0));
+        }
+    }
+
+    #[test]
+    fn with_time_approximate_around() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Drinks tomorrow around 5 @ Pub", now).unwrap();
+        assert_eq!(event.summary, "Drinks");
+        assert_eq!(event.time, Some(Time::constant(5, 0, 0, 0)));
+        assert_eq!(event.location, Some("Pub".to_owned()));
+        assert!(event.time_approximate);
+    }
+
+    #[test]
+    fn with_time_approximate_tilde() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Drinks tomorrow ~17:00", now).unwrap();
+        assert_eq!(event.time, Some(Time::constant(17, 0, 0, 0)));
+        assert!(event.time_approximate);
+    }
+
+    #[test]
+    fn with_time_approximate_ish() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Drinks tomorrow 5-ish @ Bar", now).unwrap();
+        assert_eq!(event.time, Some(Time::constant(5, 0, 0, 0)));
+        assert_eq!(event.location, Some("Bar".to_owned()));
+        assert!(event.time_approximate);
+    }
+
+    #[test]
+    fn with_time_approximate_rounded() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let options = ParserOptions {
+            round_to: Some(15.minutes()),
+            ..Default::default()
+        };
+        let event =
+            NewEvent::parse_at_time_with_options("Drinks tomorrow around 16:53", now, options)
+                .unwrap();
+        assert_eq!(event.datetime(), date(2024, 6, 2).at(17, 0, 0, 0));
+    }
+
+    #[test]
+    fn with_time_exact() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Drinks tomorrow 5ish", now.clone()).unwrap();
+        assert!(event.time_approximate);
+        let exact = NewEvent::parse_at_time("Drinks tomorrow 5", now).unwrap();
+        assert!(!exact.time_approximate);
+        assert_ne!(event, exact);
+    }
+
+    #[test]
+    fn with_time_end_of_day() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Send the draft tomorrow EOD", now).unwrap();
+        assert_eq!(event.summary, "Send the draft");
+        assert_eq!(event.datetime(), date(2024, 6, 2).at(17, 0, 0, 0));
+    }
+
+    #[test]
+    fn with_time_end_of_day_in_summary() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("EOD review tomorrow 10:00", now).unwrap();
+        assert_eq!(event.summary, "EOD review");
+        assert_eq!(event.datetime(), date(2024, 6, 2).at(10, 0, 0, 0));
+    }
+
+    #[test]
+    fn with_time_in_the_morning_and_location() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event =
+            NewEvent::parse_at_time("Run tomorrow at 8 in the morning @ Park", now).unwrap();
+        assert_eq!(event.datetime(), date(2024, 6, 2).at(8, 0, 0, 0));
+        assert_eq!(event.location, Some("Park".to_owned()));
+    }
+
+    #[test]
+    fn with_time_four_digit() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Pickup tomorrow 0930 @ Dock 3", now).unwrap();
+        assert_eq!(event.summary, "Pickup");
+        assert_eq!(event.datetime(), date(2024, 6, 2).at(9, 30, 0, 0));
+        assert_eq!(event.location, Some("Dock 3".to_owned()));
+    }
+
+    #[test]
+    fn with_time_four_digit_invalid() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Pickup tomorrow 2460", now).unwrap();
+        assert_eq!(event.time, None);
+    }
+
+    #[test]
+    fn candidates_ambiguous_slash_date() {
+        let now = date(2024, 1, 1).in_tz("UTC").unwrap();
+        let candidates = NewEvent::parse_candidates("Dentist 3/4 10:00 @ Clinic", now);
+        assert_eq!(candidates.len(), 2);
+        assert_eq!(candidates[0].datetime(), date(2024, 4, 3).at(10, 0, 0, 0));
+        assert_eq!(candidates[1].datetime(), date(2024, 3, 4).at(10, 0, 0, 0));
+        assert_eq!(candidates[1].location, Some("Clinic".to_owned()));
+    }
+
+    #[test]
+    fn candidates_ambiguous_slash_date_month_first() {
+        let now = date(2024, 1, 1).in_tz("UTC").unwrap();
+        let options = ParserOptions {
+            date_order: DateOrder::MonthFirst,
+            ..Default::default()
+        };
+        let candidates = NewEvent::parse_candidates_with_options("Dentist 3/4", now, options);
+        assert_eq!(candidates.len(), 2);
+        assert_eq!(candidates[0].date, date(2024, 3, 4));
+        assert_eq!(candidates[1].date, date(2024, 4, 3));
+    }
+
+    #[test]
+    fn candidates_unambiguous_slash_date() {
+        let now = date(2024, 1, 1).in_tz("UTC").unwrap();
+        let candidates = NewEvent::parse_candidates("Family dinner 12/25 18:00", now);
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(candidates[0].datetime(), date(2024, 12, 25).at(18, 0, 0, 0));
+    }
+
+    #[test]
+    fn candidates_unambiguous_dotted_date() {
+        let now = date(2024, 1, 1).in_tz("UTC").unwrap();
+        let candidates = NewEvent::parse_candidates("Dentist 3.4. 10:00", now);
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(candidates[0].date, date(2024, 4, 3));
+    }
+
+    #[test]
+    fn candidates_none() {
+        let now = date(2024, 1, 1).in_tz("UTC").unwrap();
+        assert!(NewEvent::parse_candidates("Dentist @ Clinic", now).is_empty());
+    }
+
+    #[test]
+    fn street_number_is_not_a_time_a() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event =
+            NewEvent::parse_at_time("Pick up keys tomorrow 99 Main St at 18:00", now).unwrap();
+        assert_eq!(event.summary, "Pick up keys 99 Main St");
+        assert_eq!(event.datetime(), date(2024, 6, 2).at(18, 0, 0, 0));
+    }
+
+    #[test]
+    fn street_number_is_not_a_time_b() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Pick up keys tomorrow, 120 Main St", now).unwrap();
+        assert_eq!(event.time, None);
+        assert_eq!(event.location, Some("120 Main St".to_owned()));
+    }
+
+    #[test]
+    fn fail_invalid_time() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("John's birthday 18.11. 25:00 room", now.clone());
+        let text = "25:00".to_owned();
+        assert_eq!(event, Err(EventParseError::InvalidTime { text, at: 23 }));
+        let error = NewEvent::parse_at_time("Deploy 2.6. klo 24.30", now).unwrap_err();
+        assert_eq!(error.to_string(), "Invalid time \"klo 24.30\" at byte 12");
+    }
+
+    #[test]
+    fn fail_invalid_date() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Palaveri 16.30", now);
+        let text = "16.30".to_owned();
+        assert_eq!(event, Err(EventParseError::InvalidTime { text, at: 9 }));
+    }
+
+    #[test]
+    fn with_time_fractional_seconds() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event =
+            NewEvent::parse_at_time("Backup 18.11. 03:00:00.250, Server room", now).unwrap();
+        assert_eq!(event.summary, "Backup");
+        assert_eq!(
+            event.datetime(),
+            date(2024, 11, 18).at(3, 0, 0, 250_000_000)
+        );
+        assert_eq!(event.location, Some("Server room".to_owned()));
+    }
+
+    #[test]
+    fn trivial_with_location_a() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("John's birthday 18.11. @ Memory Plaza", now).unwrap();
+        assert_eq!(event.summary, "John's birthday");
+        assert_eq!(event.date.year(), 2024);
+        assert_eq!(event.date.day(), 18);
+        assert_eq!(event.date.month(), 11);
+        assert_eq!(event.location, Some("Memory Plaza".to_owned()));
+    }
+
+    #[test]
+    fn trivial_with_location_b() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("John's birthday 18.11., Memory Plaza", now).unwrap();
+        assert_eq!(event.summary, "John's birthday");
+        assert_eq!(event.location, Some("Memory Plaza".to_owned()));
+    }
+
+    #[test]
+    fn pipe_is_not_a_location_separator() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Ops | Standup tomorrow | notes", now).unwrap();
+        assert_eq!(event.summary, "Ops | Standup | notes");
+        assert_eq!(event.location, None);
+    }
+
+    #[test]
+    fn pipe_inside_location() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Lecture tomorrow @ A|B Wing", now).unwrap();
+        assert_eq!(event.summary, "Lecture");
+        assert_eq!(event.location, Some("A|B Wing".to_owned()));
+    }
+
+    #[test]
+    fn double_space_is_not_a_location_separator() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Lecture tomorrow 10:15  Hall 2  ", now).unwrap();
+        assert_eq!(event.summary, "Lecture Hall 2");
+        assert_eq!(event.location, None);
+    }
+
+    #[test]
+    fn location_after_comma() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let parse = |s| NewEvent::parse_at_time(s, now.clone()).unwrap();
+        for input in ["Retro tomorrow 11:00,A769", "Retro tomorrow 11:00, A769"] {
+            let retro = parse(input);
+            assert_eq!(retro.summary, "Retro");
+            assert_eq!(retro.time, Some(Time::constant(11, 0, 0, 0)));
+            assert_eq!(retro.location, Some("A769".to_owned()));
+        }
+        let ninety = Some(1.hour().minutes(30).fieldwise());
+        for input in ["Retro tomorrow 11:00, 1,5h", "Retro tomorrow 11:00,1,5h"] {
+            let retro = parse(input);
+            assert_eq!(retro.duration.map(|d| d.fieldwise()), ninety);
+            assert_eq!(retro.location, None);
+        }
+        let review = parse("Review tomorrow 11:00 1,5h, A769");
+        assert_eq!(review.duration.map(|d| d.fieldwise()), ninety);
+        assert_eq!(review.location, Some("A769".to_owned()));
+    }
+
+    #[test]
+    fn location_delimiter_without_spaces() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let parse = |s| NewEvent::parse_at_time(s, now.clone()).unwrap();
+        let dinner = parse("Dinner tomorrow 11:00,Kitchen");
+        assert_eq!(dinner.summary, "Dinner");
+        assert_eq!(dinner.time, Some(Time::constant(11, 0, 0, 0)));
+        assert_eq!(dinner.location, Some("Kitchen".to_owned()));
+        assert_eq!(
+            parse("Dinner tomorrow 11:00@Kitchen").location,
+            Some("Kitchen".to_owned())
+        );
+        assert_eq!(
+            parse("Dinner tomorrow 11:00 ,Kitchen").location,
+            Some("Kitchen".to_owned())
+        );
+        assert_eq!(parse("Dinner tomorrow 11:00 | random text").location, None);
+        assert_eq!(
+            parse("Dinner tomorrow 11:00  double space text").location,
+            None
+        );
+    }
+
+    #[test]
+    fn single_space_is_not_a_location_separator() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Lecture tomorrow 10:15 maybe", now).unwrap();
+        assert_eq!(event.location, None);
+    }
+
+    #[test]
+    fn trailing_whitespace_is_not_a_location() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let event = NewEvent::parse_at_time("Lecture tomorrow   ", now).unwrap();
+        assert_eq!(event.location, None);
     }
 
     #[test]
@@ -0,0 +1,372 @@
+//! Options for tuning how ambiguous inputs are interpreted
+
+use std::borrow::Cow;
+
+use jiff::{civil::Time, Span};
+
+use crate::DayPart;
+
+/// Controls how the parser resolves inputs that have more than one reasonable interpretation.
+///
+/// The [`Default`] implementation matches the behaviour of [`crate::NewEvent::parse_at_time`].
+#[derive(Debug, Clone)]
+pub struct ParserOptions {
+    /// Which day "midnight" refers to when it follows a date, see [`MidnightConvention`]
+    pub midnight: MidnightConvention,
+    /// Clock times that vague times of day such as "morning" resolve to
+    pub day_parts: DayPartTimes,
+    /// Whether 3 or 4 digit numbers following a date, such as "0930", are read as times.
+    /// Enabled by default.
+    pub four_digit_times: bool,
+    /// How slash separated dates such as "3/4" are read, see [`DateOrder`]
+    pub date_order: DateOrder,
+    /// Whether slash separated dates that could be read in either order, such as "3/4", fail the
+    /// parse with [`crate::EventParseError::AmbiguousTime`] instead of being read in
+    /// [`ParserOptions::date_order`]. Dates where only one order is valid, such as "18/3", are
+    /// read as usual.
+    /// Disabled by default.
+    pub strict_dates: bool,
+    /// Two digit years such as the "24" in "18.11.24" below this are placed in the 2000s, the
+    /// rest in the 1900s. Years written with 4 digits are used as is.
+    /// Defaults to 70, so that "18.11.24" is in 2024 and "18.11.99" in 1999.
+    pub two_digit_year_pivot: i8,
+    /// Whether a bare hour following a date, such as the "3" in "Order 18.11. 3 pizzas", is read
+    /// as a time. When disabled, only times with minutes ("3:00", "3.00") or bare hours directly
+    /// after "at", "klo", "kello", "from" or "between" are accepted.
+    /// Enabled by default.
+    pub bare_hour_times: bool,
+    /// Whether times with a dot as the minute separator, such as "16.30", are accepted after any
+    /// date. When disabled, they are only accepted in a Finnish context: directly after "klo" or
+    /// "kello", or after a dotted date such as "18.11." or a Finnish word such as "huomenna".
+    /// Disabled by default, so that "tomorrow 16.30" has no time.
+    pub dot_times: bool,
+    /// How 12-hour clock phrases such as "quarter to six" are placed on the 24-hour clock, see
+    /// [`MeridiemInference`]
+    pub meridiem: MeridiemInference,
+    /// How bare hours such as the "7" in "dinner friday at 7" or "seven o'clock" are placed on the
+    /// 24-hour clock, see [`MeridiemInference`]. Times with minutes or an am/pm marker, such as
+    /// "7:00" or "7pm", are never adjusted.
+    /// Defaults to [`MeridiemInference::AsWritten`], so that "7" is 07:00.
+    ///
+    /// To read casual times in the afternoon, pick the first hour that is still a morning hour:
+    /// with a `first_morning_hour` of 7, bare hours 1 to 6 are moved to 13:00–18:00.
+    /// ```rust
+    /// use jiff::civil::{date, time};
+    /// use nlcep::{MeridiemInference, NewEvent, ParserOptions};
+    /// let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+    /// let options = ParserOptions {
+    ///     bare_hour_meridiem: MeridiemInference::Daytime { first_morning_hour: 8 },
+    ///     ..Default::default()
+    /// };
+    /// let input = "Dinner tomorrow at 7";
+    /// let event = NewEvent::parse_at_time_with_options(input, now, options).unwrap();
+    /// assert_eq!(event.time, Some(time(19, 0, 0, 0)));
+    /// ```
+    pub bare_hour_meridiem: MeridiemInference,
+    /// Clock time that "EOD" (end of day) resolves to. Defaults to 17:00
+    pub end_of_day: Time,
+    /// Clock time that "COB" (close of business) resolves to. Defaults to 17:00
+    pub close_of_business: Time,
+    /// Whether a second start time following the first, as in "Gym tomorrow 10:00 and 18:00",
+    /// fails the parse with [`crate::EventParseError::AmbiguousTime`] instead of being left for
+    /// the location or ignored.
+    /// Disabled by default.
+    pub strict_times: bool,
+    /// Whether inputs without a summary, such as "tomorrow 11:00", are accepted, in which case the
+    /// summary is [`ParserOptions::untitled_summary`]. When disabled, they fail with
+    /// [`crate::EventParseError::MissingSummary`].
+    /// Disabled by default.
+    pub allow_missing_summary: bool,
+    /// Summary used for inputs without one when [`ParserOptions::allow_missing_summary`] is set.
+    /// Defaults to "(untitled)"
+    pub untitled_summary: Cow<'static, str>,
+    /// Whether events starting before the time of parsing fail with
+    /// [`crate::EventParseError::PastEvent`], see [`crate::NewEvent::is_future`].
+    /// Disabled by default.
+    pub future_only: bool,
+    /// When set, inexact times are rounded to the nearest multiple of this span counted from
+    /// midnight, moving to the next day if needed. This applies to relative times such as
+    /// "in 7 minutes", parts of the day such as "morning" and approximate times such as
+    /// "around 5:07". Exact clock times are used as written.
+    /// Disabled by default.
+    /// ```rust
+    /// use jiff::{civil::date, ToSpan};
+    /// use nlcep::{NewEvent, ParserOptions};
+    /// let now = date(2024, 6, 1).at(9, 2, 0, 0).in_tz("UTC").unwrap();
+    /// let options = ParserOptions { round_to: Some(5.minutes()), ..Default::default() };
+    /// let event = NewEvent::parse_at_time_with_options("Call in 7 minutes", now, options).unwrap();
+    /// assert_eq!(event.datetime(), date(2024, 6, 1).at(9, 10, 0, 0));
+    /// ```
+    pub round_to: Option<Span>,
+    /// Whether misspelled relative words and weekdays, such as "tomorow" or "wedesday", are
+    /// accepted. Only the words of dates are matched this way, never the rest of the input.
+    /// Words of 4 to 7 letters may be one edit away from the intended word, longer words two.
+    /// Disabled by default.
+    pub fuzzy: bool,
+    /// Characters that introduce a location following the datetime, as in "11:00 @ A769" or
+    /// "11:00,A769", with or without whitespace around the delimiter. Only a delimiter directly
+    /// following the datetime counts, so that the `@` of an email address isn't one. Words such as
+    /// "at" or "Paikka:" introduce a location regardless.
+    /// Defaults to `@` and `,`.
+    /// ```rust
+    /// use jiff::civil::date;
+    /// use nlcep::{NewEvent, ParserOptions};
+    /// let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+    /// let options = ParserOptions {
+    ///     location_delimiters: vec!['@', ',', ';'],
+    ///     ..Default::default()
+    /// };
+    /// let input = "Meeting tomorrow 11:00 ; Room 2";
+    /// let event = NewEvent::parse_at_time_with_options(input, now, options).unwrap();
+    /// assert_eq!(event.location, Some("Room 2".to_owned()));
+    /// ```
+    pub location_delimiters: Vec<char>,
+    /// Whether text following the datetime that looks like a room, such as "room 204",
+    /// "huone 204" or a code like "A769" or "B-wing", is read as a location without a delimiter.
+    /// This is a guess, so it is disabled by default.
+    pub room_locations: bool,
+    /// Whether text that isn't part of any component, such as the "with the design team" in
+    /// "Standup tomorrow 9:15 with the design team", is appended to the summary after a space, so
+    /// that nothing written is lost. See [`crate::NewEvent::parse_with_remainder`] for what the
+    /// text consists of.
+    /// Enabled by default.
+    pub append_remainder: bool,
+    /// Words removed from the end of the summary, where they are left dangling once the datetime
+    /// following them is cut out, as the "on" in "Dentist appointment on tomorrow". Matched
+    /// case-insensitively, and the first word of the summary is always kept.
+    /// Defaults to "on", "at", "the", "klo", "-" and "–". Set to an empty list to keep the
+    /// summary as written.
+    pub trailing_connectors: Vec<Cow<'static, str>>,
+}
+impl PartialEq for ParserOptions {
+    fn eq(&self, other: &Self) -> bool {
+        let round_to_same = match (self.round_to, other.round_to) {
+            (None, None) => true,
+            (Some(_), None) | (None, Some(_)) => false,
+            (Some(a), Some(b)) => a
+                .compare(b)
+                .is_ok_and(|ord| matches!(ord, core::cmp::Ordering::Equal)),
+        };
+        self.midnight == other.midnight
+            && self.day_parts == other.day_parts
+            && self.four_digit_times == other.four_digit_times
+            && self.date_order == other.date_order
+            && self.strict_dates == other.strict_dates
+            && self.two_digit_year_pivot == other.two_digit_year_pivot
+            && self.bare_hour_times == other.bare_hour_times
+            && self.dot_times == other.dot_times
+            && self.meridiem == other.meridiem
+            && self.bare_hour_meridiem == other.bare_hour_meridiem
+            && self.end_of_day == other.end_of_day
+            && self.close_of_business == other.close_of_business
+            && self.strict_times == other.strict_times
+            && self.allow_missing_summary == other.allow_missing_summary
+            && self.untitled_summary == other.untitled_summary
+            && self.future_only == other.future_only
+            && self.fuzzy == other.fuzzy
+            && self.location_delimiters == other.location_delimiters
+            && self.room_locations == other.room_locations
+            && self.append_remainder == other.append_remainder
+            && self.trailing_connectors == other.trailing_connectors
+            && round_to_same
+    }
+}
+impl Default for ParserOptions {
+    fn default() -> Self {
+        Self {
+            midnight: MidnightConvention::default(),
+            day_parts: DayPartTimes::default(),
+            four_digit_times: true,
+            bare_hour_times: true,
+            dot_times: false,
+            date_order: DateOrder::default(),
+            strict_dates: false,
+            two_digit_year_pivot: 70,
+            meridiem: MeridiemInference::default(),
+            bare_hour_meridiem: MeridiemInference::default(),
+            end_of_day: Time::constant(17, 0, 0, 0),
+            close_of_business: Time::constant(17, 0, 0, 0),
+            strict_times: false,
+            allow_missing_summary: false,
+            untitled_summary: Cow::Borrowed("(untitled)"),
+            future_only: false,
+            round_to: None,
+            fuzzy: false,
+            location_delimiters: vec!['@', ','],
+            room_locations: false,
+            append_remainder: true,
+            trailing_connectors: ["on", "at", "the", "klo", "-", "–"]
+                .into_iter()
+                .map(Cow::Borrowed)
+                .collect(),
+        }
+    }
+}
+
+/// Determines which day a "midnight" following a date is placed on.
+///
+/// For example, with "maintenance window tomorrow midnight" parsed on 1.6.2024:
+/// ```rust
+/// use jiff::civil::date;
+/// use nlcep::{MidnightConvention, NewEvent, ParserOptions};
+/// let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+/// let input = "maintenance window tomorrow midnight";
+///
+/// let options = ParserOptions { midnight: MidnightConvention::StartOfDay, ..Default::default() };
+/// let event = NewEvent::parse_at_time_with_options(input, now.clone(), options).unwrap();
+/// assert_eq!(event.date, date(2024, 6, 2));
+///
+/// let options = ParserOptions { midnight: MidnightConvention::EndOfDay, ..Default::default() };
+/// let event = NewEvent::parse_at_time_with_options(input, now, options).unwrap();
+/// assert_eq!(event.date, date(2024, 6, 3));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MidnightConvention {
+    /// "midnight" is 00:00 at the start of the named date
+    #[default]
+    StartOfDay,
+    /// "midnight" is 00:00 at the end of the named date, that is, at the start of the following
+    /// day
+    EndOfDay,
+}
+
+/// The order of the day and the month in slash separated dates such as "3/4".
+///
+/// Dates where only one order is valid, such as "12/25", are read in that order regardless.
+/// Dotted dates such as "3.4." are always day first.
+/// ```rust
+/// use jiff::civil::date;
+/// use nlcep::{DateOrder, NewEvent, ParserOptions};
+/// let now = date(2024, 1, 1).in_tz("UTC").unwrap();
+///
+/// let options = ParserOptions { date_order: DateOrder::DayFirst, ..Default::default() };
+/// let event = NewEvent::parse_at_time_with_options("Dentist 3/4", now.clone(), options).unwrap();
+/// assert_eq!(event.date, date(2024, 4, 3));
+///
+/// let options = ParserOptions { date_order: DateOrder::MonthFirst, ..Default::default() };
+/// let event = NewEvent::parse_at_time_with_options("Dentist 3/4", now, options).unwrap();
+/// assert_eq!(event.date, date(2024, 3, 4));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DateOrder {
+    /// d/m, as in most of Europe
+    #[default]
+    DayFirst,
+    /// m/d, as in the United States
+    MonthFirst,
+}
+impl DateOrder {
+    /// Returns the other order
+    pub const fn swapped(self) -> Self {
+        match self {
+            Self::DayFirst => Self::MonthFirst,
+            Self::MonthFirst => Self::DayFirst,
+        }
+    }
+}
+
+/// Determines whether 12-hour clock times without an am/pm marker are in the morning or in the
+/// afternoon.
+///
+/// [`ParserOptions::meridiem`] applies to spoken times such as "quarter to six" and
+/// "half past five", [`ParserOptions::bare_hour_meridiem`] to bare hours such as "at 7" and
+/// "seven o'clock".
+/// ```rust
+/// use jiff::civil::{date, time};
+/// use nlcep::{MeridiemInference, NewEvent, ParserOptions};
+/// let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+/// let input = "Dinner tomorrow at quarter to seven";
+///
+/// let event = NewEvent::parse_at_time(input, now.clone()).unwrap();
+/// assert_eq!(event.time, Some(time(6, 45, 0, 0)));
+///
+/// let options = ParserOptions {
+///     meridiem: MeridiemInference::Daytime { first_morning_hour: 8 },
+///     ..Default::default()
+/// };
+/// let event = NewEvent::parse_at_time_with_options(input, now, options).unwrap();
+/// assert_eq!(event.time, Some(time(18, 45, 0, 0)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MeridiemInference {
+    /// The hour is used as written, "seven o'clock" is 07:00
+    #[default]
+    AsWritten,
+    /// Hours before `first_morning_hour` are assumed to be in the afternoon or evening, as people
+    /// rarely schedule things in the small hours. With a `first_morning_hour` of 8, "seven o'clock"
+    /// is 19:00 and "eight o'clock" is 08:00.
+    Daytime {
+        /// The earliest hour that is still read as a morning hour, from 1 to 12
+        first_morning_hour: i8,
+    },
+}
+impl MeridiemInference {
+    /// Moves a 12-hour clock `hour` to the afternoon if this inference says so
+    pub const fn adjust(self, hour: i8) -> i8 {
+        match self {
+            Self::Daytime { first_morning_hour } if 1 <= hour && hour < first_morning_hour => {
+                hour + 12
+            }
+            _ => hour,
+        }
+    }
+}
+
+/// Clock times used for each [`DayPart`].
+///
+/// ```rust
+/// use jiff::civil::{date, time};
+/// use nlcep::{DayPart, DayPartTimes, NewEvent, ParserOptions};
+/// let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+/// let options = ParserOptions {
+///     day_parts: DayPartTimes { morning: time(8, 0, 0, 0), ..Default::default() },
+///     ..Default::default()
+/// };
+/// let event =
+///     NewEvent::parse_at_time_with_options("Dentist tomorrow morning", now, options).unwrap();
+/// assert_eq!(event.time, Some(time(8, 0, 0, 0)));
+/// assert_eq!(event.day_part, Some(DayPart::Morning));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DayPartTimes {
+    /// Defaults to 09:00
+    pub morning: Time,
+    /// Defaults to 15:00
+    pub afternoon: Time,
+    /// Defaults to 18:00
+    pub evening: Time,
+    /// Defaults to 22:00
+    pub night: Time,
+    /// Defaults to 08:00
+    pub breakfast: Time,
+    /// Defaults to 12:00
+    pub lunch: Time,
+    /// Defaults to 18:00
+    pub dinner: Time,
+}
+impl DayPartTimes {
+    pub const fn time_of(&self, day_part: DayPart) -> Time {
+        match day_part {
+            DayPart::Morning => self.morning,
+            DayPart::Afternoon => self.afternoon,
+            DayPart::Evening => self.evening,
+            DayPart::Night => self.night,
+            DayPart::Breakfast => self.breakfast,
+            DayPart::Lunch => self.lunch,
+            DayPart::Dinner => self.dinner,
+        }
+    }
+}
+impl Default for DayPartTimes {
+    fn default() -> Self {
+        Self {
+            morning: Time::constant(9, 0, 0, 0),
+            afternoon: Time::constant(15, 0, 0, 0),
+            evening: Time::constant(18, 0, 0, 0),
+            night: Time::constant(22, 0, 0, 0),
+            breakfast: Time::constant(8, 0, 0, 0),
+            lunch: Time::constant(12, 0, 0, 0),
+            dinner: Time::constant(18, 0, 0, 0),
+        }
+    }
+}
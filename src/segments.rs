@@ -0,0 +1,34 @@
+//! The parts of an input that each component of a parse was read from
+
+/// The text each component of a [`crate::NewEvent`] was read from, sliced from the original
+/// input, see [`crate::NewEvent::parse_with_segments`].
+/// ```rust
+/// use jiff::civil::date;
+/// use nlcep::{NewEvent, ParserOptions};
+/// let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+/// let input = "Meetup tomorrow 16:00 @ Memory Plaza";
+/// let options = ParserOptions::default();
+/// let (_, segments) = NewEvent::parse_with_segments(input, now, options).unwrap();
+/// assert_eq!(segments.summary_text, "Meetup");
+/// assert_eq!(segments.date_text, "tomorrow");
+/// assert_eq!(segments.time_text, Some("16:00"));
+/// assert_eq!(segments.location_text, Some("@ Memory Plaza"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MatchedSegments<'a> {
+    /// The summary, empty if the input had none and
+    /// [`crate::ParserOptions::allow_missing_summary`] is set.
+    /// Hashtags inside the summary are kept, as in "Lunch #food with Anna".
+    pub summary_text: &'a str,
+    /// The date, including the end of a range of dates such as "until friday".
+    /// Empty if the event was placed on the current day by a relative time such as
+    /// "in 5 minutes", in which case the phrase is the `time_text`.
+    pub date_text: &'a str,
+    /// The time, including the end of a range such as "11:00-12:30" and a time zone
+    pub time_text: Option<&'a str>,
+    /// The location along with its delimiter, as in "@ Memory Plaza"
+    pub location_text: Option<&'a str>,
+    /// The duration, as in "for 45 min" or "(15min)". The end of a range is a part of the
+    /// `time_text` or the `date_text` instead.
+    pub duration_text: Option<&'a str>,
+}
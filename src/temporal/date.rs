@@ -1,12 +1,18 @@
-use std::str::FromStr;
+use core::{mem, str::FromStr};
 
 use jiff::{
-    civil::{date, Date},
+    civil::{Date, ISOWeekDate, Weekday},
     ToSpan, Zoned,
 };
+use lazy_regex::{lazy_regex, Lazy, Regex};
 use strum::IntoEnumIterator;
 
-use crate::EventParseError;
+#[cfg(feature = "holidays")]
+use crate::temporal::holidays::{continues_as_eve, parse_holiday, Holiday};
+use crate::{
+    temporal::{normalize_word, time::is_clock_prefix},
+    DateOrder, EventParseError, ParserOptions,
+};
 
 pub trait AsDate {
     fn as_date(&self, now: Zoned) -> Result<Date, EventParseError>;
@@ -14,27 +20,336 @@ pub trait AsDate {
 
 trait FromMultiword {
     /// usize is the number of words matched
-    fn parse_multiword(words: &[String]) -> Option<(Self, usize)>
+    fn parse_multiword(words: &[&str]) -> Option<(Self, usize)>
     where
         Self: Sized;
 }
 
+/// Number of words spanned by the longest multiword pattern ("first monday of december")
+const MAX_MULTIWORD_LEN: usize = 4;
+
+/// Fixed-size window over the most recently seen words and their starting byte offsets, used to
+/// match multiword patterns without keeping every past word around
+#[derive(Debug, Default)]
+struct RecentWords<'a> {
+    words: [&'a str; MAX_MULTIWORD_LEN],
+    starts: [usize; MAX_MULTIWORD_LEN],
+    len: usize,
+}
+impl<'a> RecentWords<'a> {
+    const fn push(&mut self, word: &'a str, start: usize) {
+        if self.len == MAX_MULTIWORD_LEN {
+            self.words.rotate_left(1);
+            self.starts.rotate_left(1);
+            self.len -= 1;
+        }
+        self.words[self.len] = word;
+        self.starts[self.len] = start;
+        self.len += 1;
+    }
+
+    fn words(&self) -> &[&'a str] {
+        &self.words[..self.len]
+    }
+
+    /// Returns the starting byte offset of the nth most recent word, 1 being the latest
+    const fn nth_last_start(&self, n: usize) -> usize {
+        self.starts[self.len - n]
+    }
+}
+
+/// Whether a [`WeekdayModifier`] is written before or after the weekday
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModifierPosition {
+    /// "next monday"
+    Prefix,
+    /// "lunes próximo"
+    Postfix,
+}
+
+/// A "next" or "last" modifier of a weekday, as a sequence of lowercase words and where it is
+/// written relative to the weekday
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WeekdayModifier {
+    /// Lowercase words of the modifier, such as `["que", "viene"]`
+    pub words: &'static [&'static str],
+    pub position: ModifierPosition,
+}
+impl WeekdayModifier {
+    /// Returns the words of this modifier applied to `weekday`, in the order they are written
+    fn tokens(self, weekday: &'static str) -> Vec<&'static str> {
+        let weekday = core::iter::once(weekday);
+        match self.position {
+            ModifierPosition::Prefix => self.words.iter().copied().chain(weekday).collect(),
+            ModifierPosition::Postfix => weekday.chain(self.words.iter().copied()).collect(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, strum_macros::Display, strum_macros::EnumIter)]
 pub enum DateRelativeLanguage {
     English,
     Finnish,
+    /// Shares most of its words with Danish, shared words are reported as Norwegian
+    Norwegian,
+    Danish,
+    Spanish,
+    French,
+    Italian,
 }
 impl DateRelativeLanguage {
-    pub const fn get_noun_prev(&self) -> &'static str {
+    /// Ways to write "last" for a weekday, as in "last monday" or the Spanish "lunes pasado"
+    pub const fn get_modifiers_prev(&self) -> &'static [WeekdayModifier] {
+        use ModifierPosition::{Postfix, Prefix};
+        match self {
+            DateRelativeLanguage::English => &[WeekdayModifier {
+                words: &["last"],
+                position: Prefix,
+            }],
+            DateRelativeLanguage::Finnish => &[WeekdayModifier {
+                words: &["viime"],
+                position: Prefix,
+            }],
+            DateRelativeLanguage::Norwegian => &[WeekdayModifier {
+                words: &["forrige"],
+                position: Prefix,
+            }],
+            DateRelativeLanguage::Danish => &[WeekdayModifier {
+                words: &["sidste"],
+                position: Prefix,
+            }],
+            DateRelativeLanguage::Spanish => &[WeekdayModifier {
+                words: &["pasado"],
+                position: Postfix,
+            }],
+            DateRelativeLanguage::French => &[WeekdayModifier {
+                words: &["dernier"],
+                position: Postfix,
+            }],
+            DateRelativeLanguage::Italian => &[
+                WeekdayModifier {
+                    words: &["scorso"],
+                    position: Postfix,
+                },
+                // "domenica scorsa"
+                WeekdayModifier {
+                    words: &["scorsa"],
+                    position: Postfix,
+                },
+            ],
+        }
+    }
+    /// Ways to write "next" for a weekday, as in "next monday" or the Spanish "lunes que viene"
+    pub const fn get_modifiers_next(&self) -> &'static [WeekdayModifier] {
+        use ModifierPosition::{Postfix, Prefix};
+        match self {
+            DateRelativeLanguage::English => &[
+                WeekdayModifier {
+                    words: &["next"],
+                    position: Prefix,
+                },
+                // "wednesday next", mostly British
+                WeekdayModifier {
+                    words: &["next"],
+                    position: Postfix,
+                },
+                // "from monday until friday"
+                WeekdayModifier {
+                    words: &["from"],
+                    position: Prefix,
+                },
+            ],
+            DateRelativeLanguage::Finnish => &[WeekdayModifier {
+                words: &["ensi"],
+                position: Prefix,
+            }],
+            DateRelativeLanguage::Norwegian => &[WeekdayModifier {
+                words: &["neste"],
+                position: Prefix,
+            }],
+            DateRelativeLanguage::Danish => &[WeekdayModifier {
+                words: &["næste"],
+                position: Prefix,
+            }],
+            DateRelativeLanguage::Spanish => &[
+                WeekdayModifier {
+                    words: &["próximo"],
+                    position: Prefix,
+                },
+                WeekdayModifier {
+                    words: &["próximo"],
+                    position: Postfix,
+                },
+                WeekdayModifier {
+                    words: &["que", "viene"],
+                    position: Postfix,
+                },
+            ],
+            DateRelativeLanguage::French => &[WeekdayModifier {
+                words: &["prochain"],
+                position: Postfix,
+            }],
+            DateRelativeLanguage::Italian => &[
+                WeekdayModifier {
+                    words: &["prossimo"],
+                    position: Postfix,
+                },
+                // "domenica prossima"
+                WeekdayModifier {
+                    words: &["prossima"],
+                    position: Postfix,
+                },
+                WeekdayModifier {
+                    words: &["prossimo"],
+                    position: Prefix,
+                },
+            ],
+        }
+    }
+    pub const fn get_nouns_week(&self) -> &'static [&'static str] {
+        match self {
+            DateRelativeLanguage::English => &["week"],
+            DateRelativeLanguage::Finnish => &["viikko", "vk"],
+            DateRelativeLanguage::Norwegian => &["uke"],
+            DateRelativeLanguage::Danish => &["uge"],
+            DateRelativeLanguage::Spanish => &["semana"],
+            DateRelativeLanguage::French => &["semaine"],
+            DateRelativeLanguage::Italian => &["settimana"],
+        }
+    }
+    /// Words introducing a location after the datetime, as in "at the office" or
+    /// "Paikka: A769". The marker is not part of the location.
+    pub const fn get_location_markers(&self) -> &'static [&'static str] {
+        match self {
+            DateRelativeLanguage::English => &["at", "in", "location:"],
+            DateRelativeLanguage::Finnish => &["paikassa", "osoitteessa", "paikka:"],
+            _ => &[],
+        }
+    }
+    /// Words for a room that start a location after the datetime, as in "room 204" or
+    /// "huone 204". Unlike location markers, the word is part of the location.
+    pub const fn get_room_words(&self) -> &'static [&'static str] {
+        match self {
+            DateRelativeLanguage::English => &["room"],
+            DateRelativeLanguage::Finnish => &["huone"],
+            _ => &[],
+        }
+    }
+    /// Words for a month in phrases such as "end of month" or "kuun lopussa"
+    pub const fn get_nouns_month(&self) -> &'static [&'static str] {
+        match self {
+            DateRelativeLanguage::English => &["month"],
+            DateRelativeLanguage::Finnish => &["kuun"],
+            _ => &[],
+        }
+    }
+    /// Words for the previous, the current and the next month, in that order, written directly
+    /// before the noun as in "end of next month" or "ensi kuun lopussa"
+    pub const fn get_month_modifiers(&self) -> [&'static [&'static str]; 3] {
+        match self {
+            DateRelativeLanguage::English => [&["last"], &["this", "the"], &["next"]],
+            DateRelativeLanguage::Finnish => [&["viime"], &["tämän"], &["ensi"]],
+            _ => [&[], &[], &[]],
+        }
+    }
+    /// Words before and after the (modified) noun for the beginning, the middle and the end of a
+    /// month, in that order: "end of (next) month", "(ensi) kuun lopussa"
+    pub const fn get_month_parts(&self) -> [&'static [MonthPartWords]; 3] {
+        match self {
+            DateRelativeLanguage::English => [
+                &[(&["beginning", "of"], &[]), (&["start", "of"], &[])],
+                &[(&["middle", "of"], &[]), (&["mid"], &[])],
+                &[(&["end", "of"], &[])],
+            ],
+            DateRelativeLanguage::Finnish => [
+                &[(&[], &["alussa"])],
+                &[(&[], &["puolivälissä"])],
+                &[(&[], &["lopussa"])],
+            ],
+            _ => [&[], &[], &[]],
+        }
+    }
+    /// Words for hours, minutes, days, weeks and seconds in durations such as "2 hours", in that
+    /// order
+    pub const fn get_duration_units(&self) -> [&'static [&'static str]; 5] {
+        match self {
+            DateRelativeLanguage::English => [
+                &["hour", "hours", "hr", "hrs"],
+                &["minute", "minutes", "min", "mins"],
+                &["day", "days"],
+                &["week", "weeks"],
+                &["second", "seconds", "sec", "secs"],
+            ],
+            DateRelativeLanguage::Finnish => [
+                &["tunti", "tuntia", "tunnin"],
+                &["minuutti", "minuuttia", "minuutin"],
+                &["päivä", "päivää", "päivän"],
+                &["viikko", "viikkoa", "viikon"],
+                &["sekunti", "sekuntia", "sekunnin"],
+            ],
+            _ => [&[], &[], &[], &[], &[]],
+        }
+    }
+    /// Words for a single hour, minute, day, week and second that are used without a number, as
+    /// in the Finnish "tunnin ajan"
+    pub const fn get_duration_units_single(&self) -> [&'static [&'static str]; 5] {
         match self {
-            DateRelativeLanguage::English => "last",
-            DateRelativeLanguage::Finnish => "viime",
+            DateRelativeLanguage::Finnish => [
+                &["tunnin"],
+                &["minuutin"],
+                &["päivän"],
+                &["viikon"],
+                &["sekunnin"],
+            ],
+            _ => [&[], &[], &[], &[], &[]],
         }
     }
-    pub const fn get_noun_next(&self) -> &'static str {
+    /// Words for ereyesterday, yesterday, today, tomorrow and overmorrow, in that order.
+    /// Each day can be written in multiple ways, each alternative is a sequence of lowercase words.
+    pub const fn get_relative_days(&self) -> [&'static [&'static [&'static str]]; 5] {
         match self {
-            DateRelativeLanguage::English => "next",
-            DateRelativeLanguage::Finnish => "ensi",
+            DateRelativeLanguage::English => [
+                &[&["ereyesterday"], &["day", "before", "yesterday"]],
+                &[&["yesterday"]],
+                &[&["today"]],
+                &[&["tomorrow"]],
+                &[&["overmorrow"], &["day", "after", "tomorrow"]],
+            ],
+            DateRelativeLanguage::Finnish => [
+                &[&["toissapäivänä"]],
+                &[&["eilen"]],
+                &[&["tänään"]],
+                &[&["huomenna"]],
+                &[&["ylihuomenna"]],
+            ],
+            DateRelativeLanguage::Norwegian | DateRelativeLanguage::Danish => [
+                &[&["i", "forgårs"]],
+                &[&["i", "går"]],
+                &[&["i", "dag"]],
+                &[&["i", "morgen"]],
+                &[&["i", "overmorgen"]],
+            ],
+            DateRelativeLanguage::Spanish => [
+                &[&["anteayer"]],
+                &[&["ayer"]],
+                &[&["hoy"]],
+                &[&["mañana"]],
+                &[&["pasado", "mañana"]],
+            ],
+            DateRelativeLanguage::French => [
+                &[&["avant-hier"]],
+                &[&["hier"]],
+                &[&["aujourd'hui"]],
+                &[&["demain"]],
+                &[&["après-demain"]],
+            ],
+            DateRelativeLanguage::Italian => [
+                &[&["altroieri"], &["l'altro", "ieri"]],
+                &[&["ieri"]],
+                &[&["oggi"]],
+                &[&["domani"]],
+                &[&["dopodomani"]],
+            ],
         }
     }
 }
@@ -67,71 +382,230 @@ impl DateRelativeWeekday {
         match (self, lang) {
             (DateRelativeWeekday::Monday, DateRelativeLanguage::English) => "monday",
             (DateRelativeWeekday::Monday, DateRelativeLanguage::Finnish) => "maanantaina",
+            (DateRelativeWeekday::Monday, DateRelativeLanguage::Norwegian) => "mandag",
+            (DateRelativeWeekday::Monday, DateRelativeLanguage::Danish) => "mandag",
+            (DateRelativeWeekday::Monday, DateRelativeLanguage::Spanish) => "lunes",
+            (DateRelativeWeekday::Monday, DateRelativeLanguage::French) => "lundi",
+            (DateRelativeWeekday::Monday, DateRelativeLanguage::Italian) => "lunedì",
 
             (DateRelativeWeekday::Tuesday, DateRelativeLanguage::English) => "tuesday",
             (DateRelativeWeekday::Tuesday, DateRelativeLanguage::Finnish) => "tiistaina",
+            (DateRelativeWeekday::Tuesday, DateRelativeLanguage::Norwegian) => "tirsdag",
+            (DateRelativeWeekday::Tuesday, DateRelativeLanguage::Danish) => "tirsdag",
+            (DateRelativeWeekday::Tuesday, DateRelativeLanguage::Spanish) => "martes",
+            (DateRelativeWeekday::Tuesday, DateRelativeLanguage::French) => "mardi",
+            (DateRelativeWeekday::Tuesday, DateRelativeLanguage::Italian) => "martedì",
 
             (DateRelativeWeekday::Wednesday, DateRelativeLanguage::English) => "wednesday",
             (DateRelativeWeekday::Wednesday, DateRelativeLanguage::Finnish) => "keskiviikkona",
+            (DateRelativeWeekday::Wednesday, DateRelativeLanguage::Norwegian) => "onsdag",
+            (DateRelativeWeekday::Wednesday, DateRelativeLanguage::Danish) => "onsdag",
+            (DateRelativeWeekday::Wednesday, DateRelativeLanguage::Spanish) => "miércoles",
+            (DateRelativeWeekday::Wednesday, DateRelativeLanguage::French) => "mercredi",
+            (DateRelativeWeekday::Wednesday, DateRelativeLanguage::Italian) => "mercoledì",
 
             (DateRelativeWeekday::Thurdsday, DateRelativeLanguage::English) => "thursday",
             (DateRelativeWeekday::Thurdsday, DateRelativeLanguage::Finnish) => "torstaina",
+            (DateRelativeWeekday::Thurdsday, DateRelativeLanguage::Norwegian) => "torsdag",
+            (DateRelativeWeekday::Thurdsday, DateRelativeLanguage::Danish) => "torsdag",
+            (DateRelativeWeekday::Thurdsday, DateRelativeLanguage::Spanish) => "jueves",
+            (DateRelativeWeekday::Thurdsday, DateRelativeLanguage::French) => "jeudi",
+            (DateRelativeWeekday::Thurdsday, DateRelativeLanguage::Italian) => "giovedì",
 
             (DateRelativeWeekday::Friday, DateRelativeLanguage::English) => "friday",
             (DateRelativeWeekday::Friday, DateRelativeLanguage::Finnish) => "perjantaina",
+            (DateRelativeWeekday::Friday, DateRelativeLanguage::Norwegian) => "fredag",
+            (DateRelativeWeekday::Friday, DateRelativeLanguage::Danish) => "fredag",
+            (DateRelativeWeekday::Friday, DateRelativeLanguage::Spanish) => "viernes",
+            (DateRelativeWeekday::Friday, DateRelativeLanguage::French) => "vendredi",
+            (DateRelativeWeekday::Friday, DateRelativeLanguage::Italian) => "venerdì",
 
             (DateRelativeWeekday::Saturday, DateRelativeLanguage::English) => "saturday",
             (DateRelativeWeekday::Saturday, DateRelativeLanguage::Finnish) => "lauantaina",
+            (DateRelativeWeekday::Saturday, DateRelativeLanguage::Norwegian) => "lørdag",
+            (DateRelativeWeekday::Saturday, DateRelativeLanguage::Danish) => "lørdag",
+            (DateRelativeWeekday::Saturday, DateRelativeLanguage::Spanish) => "sábado",
+            (DateRelativeWeekday::Saturday, DateRelativeLanguage::French) => "samedi",
+            (DateRelativeWeekday::Saturday, DateRelativeLanguage::Italian) => "sabato",
 
             (DateRelativeWeekday::Sunday, DateRelativeLanguage::English) => "sunday",
             (DateRelativeWeekday::Sunday, DateRelativeLanguage::Finnish) => "sunnuntaina",
+            (DateRelativeWeekday::Sunday, DateRelativeLanguage::Norwegian) => "søndag",
+            (DateRelativeWeekday::Sunday, DateRelativeLanguage::Danish) => "søndag",
+            (DateRelativeWeekday::Sunday, DateRelativeLanguage::Spanish) => "domingo",
+            (DateRelativeWeekday::Sunday, DateRelativeLanguage::French) => "dimanche",
+            (DateRelativeWeekday::Sunday, DateRelativeLanguage::Italian) => "domenica",
+        }
+    }
+}
+
+/// Tries to match a part of a month in `lang` with `check_sequence`, as in "end of next month".
+/// Returns the part, the offset of the month from the current one and the number of words matched.
+fn parse_month_part(
+    lang: DateRelativeLanguage,
+    check_sequence: impl Fn(&[&'static str]) -> Option<()>,
+) -> Option<(MonthPart, i8, usize)> {
+    let parts = [MonthPart::Beginning, MonthPart::Middle, MonthPart::End];
+    // Modified phrases first, "kuun lopussa" is the end of "ensi kuun lopussa"
+    let modifiers = lang
+        .get_month_modifiers()
+        .into_iter()
+        .zip([-1, 0, 1])
+        .flat_map(|(words, offset)| words.iter().map(move |word| (Some(*word), offset)))
+        .chain([(None, 0)]);
+    for (modifier, offset) in modifiers {
+        for (alternatives, part) in lang.get_month_parts().into_iter().zip(parts) {
+            for (before, after) in alternatives {
+                for noun in lang.get_nouns_month() {
+                    let tokens: Vec<&'static str> = before
+                        .iter()
+                        .copied()
+                        .chain(modifier)
+                        .chain([*noun])
+                        .chain(after.iter().copied())
+                        .collect();
+                    if check_sequence(&tokens).is_some() {
+                        return Some((part, offset, tokens.len()));
+                    }
+                }
+            }
         }
     }
+    None
+}
+
+/// Parses an English ordinal word used in "first monday of december", "last" being -1
+fn parse_ordinal(word: &str) -> Option<i8> {
+    match word.to_lowercase().as_str() {
+        "first" => Some(1),
+        "second" => Some(2),
+        "third" => Some(3),
+        "fourth" => Some(4),
+        "last" => Some(-1),
+        _ => None,
+    }
+}
+
+/// Parses an English month name, returning the number of the month
+fn parse_month_name(word: &str) -> Option<i8> {
+    const MONTHS: [&str; 12] = [
+        "january",
+        "february",
+        "march",
+        "april",
+        "may",
+        "june",
+        "july",
+        "august",
+        "september",
+        "october",
+        "november",
+        "december",
+    ];
+    let word = word.to_lowercase();
+    let index = MONTHS.iter().position(|month| *month == word)?;
+    i8::try_from(index + 1).ok()
+}
+
+/// Whether `s` starts with "of (month)", as in the rest of "last friday of november"
+fn starts_with_of_month(s: &str) -> bool {
+    let mut words = s.split([' ', ',']).filter(|word| !word.is_empty());
+    words
+        .next()
+        .is_some_and(|word| word.eq_ignore_ascii_case("of"))
+        && words.next().and_then(parse_month_name).is_some()
+}
+
+/// Words written before and after the month in a [`MonthPart`] phrase, as the "end of" in
+/// "end of month"
+type MonthPartWords = (&'static [&'static str], &'static [&'static str]);
+
+/// A part of a month that a [`DateRelative::MonthPart`] resolves to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MonthPart {
+    /// The first day
+    Beginning,
+    /// The 15th
+    Middle,
+    /// The last day
+    End,
 }
 
 /// "Natural language" date formats
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum DateRelative {
     LastWeekday(DateRelativeLanguage, DateRelativeWeekday),
+    /// The day before yesterday
+    Ereyesterday(DateRelativeLanguage),
     Yesterday(DateRelativeLanguage),
     Today(DateRelativeLanguage),
     Tomorrow(DateRelativeLanguage),
     Overmorrow(DateRelativeLanguage),
     NextWeekday(DateRelativeLanguage, DateRelativeWeekday),
+    /// A weekday on its own, "saturday", the first such day from today onwards
+    Weekday(DateRelativeLanguage, DateRelativeWeekday),
+    /// The nth weekday of a month, -1 being the last, in the current year if that day hasn't passed
+    /// yet, otherwise in the next year: "first monday of december", "last friday of november"
+    NthWeekdayOfMonth(i8, DateRelativeWeekday, i8),
+    /// The Monday of an ISO 8601 week, in the current year if the week hasn't ended yet,
+    /// otherwise in the next year
+    IsoWeek(DateRelativeLanguage, u8),
+    /// A part of the month this many months from the current one: "end of month",
+    /// "beginning of next month". Without a modifier, a part of the current month that has
+    /// already passed means the next month instead.
+    MonthPart(DateRelativeLanguage, MonthPart, i8),
+    /// The next occurrence of a holiday, see [`Holiday`]
+    #[cfg(feature = "holidays")]
+    Holiday(DateRelativeLanguage, Holiday),
 }
 impl FromStr for DateRelative {
     type Err = ();
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s.to_lowercase().as_str() {
-            "yesterday" => Ok(Self::Yesterday(DateRelativeLanguage::English)),
-            "eilen" => Ok(Self::Yesterday(DateRelativeLanguage::Finnish)),
-
-            "today" => Ok(Self::Today(DateRelativeLanguage::English)),
-            "tänään" => Ok(Self::Today(DateRelativeLanguage::Finnish)),
-
-            "tomorrow" => Ok(Self::Tomorrow(DateRelativeLanguage::English)),
-            "huomenna" => Ok(Self::Tomorrow(DateRelativeLanguage::Finnish)),
-
-            "overmorrow" | "day after tomorrow" => {
-                Ok(Self::Overmorrow(DateRelativeLanguage::English))
-            }
-            "ylihuomenna" => Ok(Self::Overmorrow(DateRelativeLanguage::Finnish)),
-
+        let words: Vec<&str> = s.split(' ').collect();
+        match Self::parse_multiword(&words) {
+            Some((relative, words_matched)) if words_matched == words.len() => Ok(relative),
             _ => Err(()),
         }
     }
 }
 impl FromMultiword for DateRelative {
-    fn parse_multiword(words: &[String]) -> Option<(Self, usize)>
+    fn parse_multiword(words: &[&str]) -> Option<(Self, usize)>
     where
         Self: Sized,
     {
+        Self::parse_multiword_fuzzy(words, false)
+    }
+}
+impl DateRelative {
+    /// The language the date was written in, if it was written with words
+    pub const fn language(&self) -> Option<DateRelativeLanguage> {
+        match self {
+            DateRelative::LastWeekday(lang, _)
+            | DateRelative::Ereyesterday(lang)
+            | DateRelative::Yesterday(lang)
+            | DateRelative::Today(lang)
+            | DateRelative::Tomorrow(lang)
+            | DateRelative::Overmorrow(lang)
+            | DateRelative::NextWeekday(lang, _)
+            | DateRelative::Weekday(lang, _)
+            | DateRelative::IsoWeek(lang, _)
+            | DateRelative::MonthPart(lang, _, _) => Some(*lang),
+            #[cfg(feature = "holidays")]
+            DateRelative::Holiday(lang, _) => Some(*lang),
+            DateRelative::NthWeekdayOfMonth(..) => None,
+        }
+    }
+
+    /// Like [`FromMultiword::parse_multiword`], but with `fuzzy` set, words within a small edit
+    /// distance of a known word match it as well, see [`is_typo_of`]
+    fn parse_multiword_fuzzy(words: &[&str], fuzzy: bool) -> Option<(Self, usize)> {
+        let normalized: Vec<String> = words.iter().map(|word| normalize_word(word)).collect();
         let check_sequence = |tokens: &[&'static str]| -> Option<()> {
-            let mut iterator = words.iter().rev();
+            let mut iterator = normalized.iter().rev();
             let mut assume_next = |token: &'static str| -> Option<()> {
                 let nxt = iterator.next()?;
-                if nxt.as_str() == token.to_lowercase() {
+                if nxt == token || fuzzy && is_typo_of(nxt, token) {
                     return Some(());
                 }
                 None
@@ -142,28 +616,84 @@ impl FromMultiword for DateRelative {
             Some(())
         };
 
-        if check_sequence(&["day", "after", "tomorrow"]).is_some() {
-            return Some((Self::Overmorrow(DateRelativeLanguage::English), 3));
+        // Prefer the longest match, "day after tomorrow" over "tomorrow"
+        let mut relative_day: Option<(Self, usize)> = None;
+        for lang in DateRelativeLanguage::iter() {
+            let [ereyesterday, yesterday, today, tomorrow, overmorrow] = lang.get_relative_days();
+            for (alternatives, relative) in [
+                (ereyesterday, Self::Ereyesterday(lang)),
+                (yesterday, Self::Yesterday(lang)),
+                (today, Self::Today(lang)),
+                (tomorrow, Self::Tomorrow(lang)),
+                (overmorrow, Self::Overmorrow(lang)),
+            ] {
+                for tokens in alternatives {
+                    let longer = relative_day
+                        .as_ref()
+                        .is_none_or(|(_, words_matched)| tokens.len() > *words_matched);
+                    if longer && check_sequence(tokens).is_some() {
+                        relative_day = Some((relative, tokens.len()));
+                    }
+                }
+            }
+        }
+        if relative_day.is_some() {
+            return relative_day;
+        }
+
+        if let [.., ordinal, weekday, of, month] = words {
+            let weekday = DateRelativeWeekday::iter().find(|candidate| {
+                candidate
+                    .to_locale_static_str(DateRelativeLanguage::English)
+                    .eq_ignore_ascii_case(weekday)
+            });
+            if let (Some(nth), Some(weekday), true, Some(month)) = (
+                parse_ordinal(ordinal),
+                weekday,
+                of.eq_ignore_ascii_case("of"),
+                parse_month_name(month),
+            ) {
+                return Some((Self::NthWeekdayOfMonth(nth, weekday, month), 4));
+            }
         }
 
         for lang in DateRelativeLanguage::iter() {
             for weekday in DateRelativeWeekday::iter() {
-                if check_sequence(&[lang.get_noun_next(), weekday.to_locale_static_str(lang)])
-                    .is_some()
-                {
-                    return Some((Self::NextWeekday(lang, weekday), 2));
+                let weekday_word = weekday.to_locale_static_str(lang);
+                for (modifiers, relative) in [
+                    (lang.get_modifiers_next(), Self::NextWeekday(lang, weekday)),
+                    (lang.get_modifiers_prev(), Self::LastWeekday(lang, weekday)),
+                ] {
+                    for modifier in modifiers {
+                        let tokens = modifier.tokens(weekday_word);
+                        if check_sequence(&tokens).is_some() {
+                            return Some((relative, tokens.len()));
+                        }
+                    }
                 }
             }
 
-            for weekday in DateRelativeWeekday::iter() {
-                if check_sequence(&[lang.get_noun_prev(), weekday.to_locale_static_str(lang)])
-                    .is_some()
+            if let Some((part, offset, words_matched)) = parse_month_part(lang, check_sequence) {
+                return Some((Self::MonthPart(lang, part, offset), words_matched));
+            }
+
+            if let [.., noun, number] = words {
+                if lang
+                    .get_nouns_week()
+                    .contains(&normalize_word(noun).as_str())
                 {
-                    return Some((Self::LastWeekday(lang, weekday), 2));
+                    if let Ok(week @ 1..=53) = number.parse::<u8>() {
+                        return Some((Self::IsoWeek(lang, week), 2));
+                    }
                 }
             }
         }
 
+        #[cfg(feature = "holidays")]
+        if let Some((lang, holiday, words_matched)) = parse_holiday(words) {
+            return Some((Self::Holiday(lang, holiday), words_matched));
+        }
+
         None
     }
 }
@@ -173,47 +703,192 @@ impl AsDate for DateRelative {
             DateRelative::LastWeekday(_, weekday) => {
                 let next_such_date = now
                     .nth_weekday(-1, (*weekday).into())
-                    .map_err(|_e| EventParseError::AmbiguousTime)?;
+                    .map_err(|_e| EventParseError::ambiguous_time())?;
                 Ok(next_such_date.into())
             }
+            DateRelative::Ereyesterday(_) => {
+                let ereyesterday = now
+                    .checked_sub(2.days())
+                    .map_err(|_e| EventParseError::ambiguous_time())?;
+                Ok(ereyesterday.into())
+            }
             DateRelative::Yesterday(_) => {
                 let yesterday = now
                     .checked_sub(1.day())
-                    .map_err(|_e| EventParseError::AmbiguousTime)?;
+                    .map_err(|_e| EventParseError::ambiguous_time())?;
                 Ok(yesterday.into())
             }
             DateRelative::Today(_) => Ok(now.into()),
             DateRelative::Tomorrow(_) => {
                 let tomorrow = now
                     .checked_add(1.day())
-                    .map_err(|_e| EventParseError::AmbiguousTime)?;
+                    .map_err(|_e| EventParseError::ambiguous_time())?;
                 Ok(tomorrow.into())
             }
             DateRelative::Overmorrow(_) => {
                 let overmorrow = now
                     .checked_add(2.days())
-                    .map_err(|_e| EventParseError::AmbiguousTime)?;
+                    .map_err(|_e| EventParseError::ambiguous_time())?;
                 Ok(overmorrow.into())
             }
             DateRelative::NextWeekday(_, weekday) => {
                 let next_such_date = now
                     .nth_weekday(1, (*weekday).into())
-                    .map_err(|_e| EventParseError::AmbiguousTime)?;
+                    .map_err(|_e| EventParseError::ambiguous_time())?;
                 Ok(next_such_date.into())
             }
+            DateRelative::Weekday(_, weekday) => {
+                let today = now.date();
+                if today.weekday() == Weekday::from(*weekday) {
+                    return Ok(today);
+                }
+                today
+                    .nth_weekday(1, (*weekday).into())
+                    .map_err(|_e| EventParseError::ambiguous_time())
+            }
+            DateRelative::NthWeekdayOfMonth(nth, weekday, month) => {
+                let today = now.date();
+                let nth_weekday_in = |year: i16| {
+                    Date::new(year, *month, 1)
+                        .and_then(|first| first.nth_weekday_of_month(*nth, (*weekday).into()))
+                        .map_err(|_e| EventParseError::invalid_time())
+                };
+                let this_year = nth_weekday_in(today.year())?;
+                if this_year >= today {
+                    Ok(this_year)
+                } else {
+                    nth_weekday_in(today.year() + 1)
+                }
+            }
+            DateRelative::IsoWeek(_, week) => {
+                let today = now.date();
+                let week_year = today.iso_week_date().year();
+                let monday_of = |year: i16| {
+                    ISOWeekDate::new(year, *week as i8, Weekday::Monday)
+                        .map(ISOWeekDate::date)
+                        .map_err(|_e| EventParseError::invalid_time())
+                };
+                // Week 53 only exists in some years, so the current year might not have it
+                if let Ok(monday) = monday_of(week_year) {
+                    let sunday = monday
+                        .checked_add(6.days())
+                        .map_err(|_e| EventParseError::ambiguous_time())?;
+                    if sunday >= today {
+                        return Ok(monday);
+                    }
+                }
+                monday_of(week_year + 1)
+            }
+            DateRelative::MonthPart(_, part, offset) => {
+                let today = now.date();
+                let in_month = |months: i8| {
+                    let first = today
+                        .first_of_month()
+                        .checked_add(months.months())
+                        .map_err(|_e| EventParseError::ambiguous_time())?;
+                    match part {
+                        MonthPart::Beginning => Ok(first),
+                        MonthPart::Middle => Date::new(first.year(), first.month(), 15)
+                            .map_err(|_e| EventParseError::invalid_time()),
+                        MonthPart::End => Ok(first.last_of_month()),
+                    }
+                };
+                let date = in_month(*offset)?;
+                if *offset == 0 && date < today {
+                    in_month(1)
+                } else {
+                    Ok(date)
+                }
+            }
+            #[cfg(feature = "holidays")]
+            DateRelative::Holiday(_, holiday) => holiday.next_occurrence(now.date()),
         }
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum DateStructured {
     Ymd(i16, i8, i8),
     Ym(i8, i8),
+    /// A date with a two digit year such as "18.11.24", resolved into a [`DateStructured::Ymd`] by
+    /// [`DateStructured::with_year_pivot`]
+    ShortYmd(i8, i8, i8),
+    /// A slash separated date such as "3/4" or "3/4/2025", which may be either day/month or
+    /// month/day. The components are stored in input order, see [`DateStructured::in_order`].
+    /// Years below 100 are two digit years, as in "3/4/25".
+    Slashed(i8, i8, Option<i16>),
+}
+impl DateStructured {
+    /// Resolves a [`DateStructured::Slashed`] date using the given component order.
+    /// If the order would produce a month greater than 12 but the other one wouldn't, such as with
+    /// "12/25" read day first, the other order is used instead.
+    pub const fn in_order(self, order: DateOrder) -> Self {
+        let Self::Slashed(first, second, year) = self else {
+            return self;
+        };
+        let (day, month) = match order {
+            DateOrder::DayFirst => (first, second),
+            DateOrder::MonthFirst => (second, first),
+        };
+        let (day, month) = if month > 12 && day <= 12 {
+            (month, day)
+        } else {
+            (day, month)
+        };
+        match year {
+            #[allow(clippy::cast_possible_truncation)]
+            Some(year @ 0..100) => Self::ShortYmd(year as i8, month, day),
+            Some(year) => Self::Ymd(year, month, day),
+            None => Self::Ym(month, day),
+        }
+    }
+
+    /// Resolves a [`DateStructured::ShortYmd`] date: two digit years below `pivot` are placed in
+    /// the 2000s, the rest in the 1900s. With a pivot of 70, "18.11.24" is in 2024 and "18.11.99"
+    /// in 1999.
+    pub const fn with_year_pivot(self, pivot: i8) -> Self {
+        match self {
+            Self::ShortYmd(year, month, day) => {
+                let century = if year < pivot { 2000 } else { 1900 };
+                Self::Ymd(century + year as i16, month, day)
+            }
+            _ => self,
+        }
+    }
+
+    fn from_slashed(string: &str) -> Result<Self, ()> {
+        let mut split_by_slashes = string.split('/');
+        let first = split_by_slashes
+            .next()
+            .ok_or(())?
+            .parse::<i8>()
+            .map_err(|_e| ())?;
+        let second = split_by_slashes
+            .next()
+            .ok_or(())?
+            .parse::<i8>()
+            .map_err(|_e| ())?;
+        let year = match split_by_slashes.next() {
+            Some(year_segment) => Some(year_segment.parse::<i16>().map_err(|_e| ())?),
+            None => None,
+        };
+        if split_by_slashes.next().is_some() || first < 1 || second < 1 {
+            return Err(());
+        }
+        // Things like "50/50" or "24/24" can't be dates in either order
+        if first.max(second) > 31 || first.min(second) > 12 {
+            return Err(());
+        }
+        Ok(Self::Slashed(first, second, year))
+    }
 }
 impl FromStr for DateStructured {
     type Err = ();
 
     fn from_str(string: &str) -> Result<Self, Self::Err> {
+        if string.contains('/') {
+            return Self::from_slashed(string);
+        }
         let mut split_by_dots = string.split('.');
         let date = split_by_dots
             .next()
@@ -226,6 +901,10 @@ impl FromStr for DateStructured {
             .parse::<i8>()
             .map_err(|_e| ())?;
         if let Some(year_segment) = split_by_dots.next().filter(|s| !s.is_empty()) {
+            if year_segment.len() == 2 {
+                let year = year_segment.parse::<i8>().map_err(|_e| ())?;
+                return Ok(Self::ShortYmd(year, month, date));
+            }
             let year = year_segment.parse::<i16>().map_err(|_e| ())?;
             return Ok(Self::Ymd(year, month, date));
         };
@@ -235,18 +914,26 @@ impl FromStr for DateStructured {
 impl AsDate for DateStructured {
     fn as_date(&self, now: Zoned) -> Result<Date, EventParseError> {
         match self {
-            DateStructured::Ymd(year, month, day) => Ok(date(*year, *month, *day)),
+            DateStructured::Ymd(year, month, day) => {
+                Date::new(*year, *month, *day).map_err(|_e| EventParseError::invalid_time())
+            }
             DateStructured::Ym(month, day) => {
                 let current_year = now.year();
                 let current_month = now.month();
                 let current_day = now.day();
                 if *month < current_month || *month == current_month && *day < current_day {
                     // That date has already passed this year, target next year instead
-                    Ok(date(current_year + 1, *month, *day))
+                    Date::new(current_year + 1, *month, *day)
+                        .map_err(|_e| EventParseError::invalid_time())
                 } else {
-                    Ok(date(current_year, *month, *day))
+                    Date::new(current_year, *month, *day)
+                        .map_err(|_e| EventParseError::invalid_time())
                 }
             }
+            DateStructured::Slashed(..) => self.in_order(DateOrder::default()).as_date(now),
+            DateStructured::ShortYmd(..) => self
+                .with_year_pivot(ParserOptions::default().two_digit_year_pivot)
+                .as_date(now),
         }
     }
 }
@@ -255,12 +942,99 @@ impl AsDate for DateStructured {
 pub enum DateUnit {
     Structured(DateStructured),
     Relative(DateRelative),
+    /// A range of days such as "18.-20.11.", stored as the first day and the last date.
+    /// The first day is in the month before the last date if it is greater than the last day, as
+    /// in "30.-2.12."
+    DayRange(i8, DateStructured),
+}
+impl DateUnit {
+    /// Whether the date is written the Finnish way, either dotted as in "18.11." or with Finnish
+    /// words as in "huomenna", in which case it may be followed by a dotted time such as "16.30"
+    pub const fn is_finnish(&self) -> bool {
+        match self {
+            DateUnit::Structured(DateStructured::Slashed(..)) => false,
+            DateUnit::Structured(_) | DateUnit::DayRange(..) => true,
+            DateUnit::Relative(relative) => {
+                matches!(relative.language(), Some(DateRelativeLanguage::Finnish))
+            }
+        }
+    }
+
+    /// Whether the date has a two digit year that is yet to be resolved with
+    /// [`DateUnit::with_year_pivot`], as in "18.11.24"
+    pub const fn has_short_year(&self) -> bool {
+        matches!(
+            self,
+            DateUnit::Structured(DateStructured::ShortYmd(..))
+                | DateUnit::DayRange(_, DateStructured::ShortYmd(..))
+        )
+    }
+
+    /// Whether the date reads differently depending on the [`DateOrder`], such as "3/4"
+    pub const fn is_ambiguous(&self) -> bool {
+        matches!(
+            self,
+            DateUnit::Structured(DateStructured::Slashed(first, second, _))
+                if *first != *second && *first <= 12 && *second <= 12
+        )
+    }
+
+    /// Parses a dotted range of days sharing a month, such as "18.-20.11." or "30.–2.12.2024"
+    fn from_day_range(string: &str) -> Option<Self> {
+        let (first, last) = string.split_once(['-', '–'])?;
+        let first_day = first.strip_suffix('.')?.parse::<i8>().ok()?;
+        if !(1..=31).contains(&first_day) || last.contains('/') {
+            return None;
+        }
+        Some(Self::DayRange(first_day, last.parse().ok()?))
+    }
+
+    /// The last date of a [`DateUnit::DayRange`], or `None` for single dates
+    pub fn last_date(&self, now: Zoned) -> Result<Option<Date>, EventParseError> {
+        match self {
+            DateUnit::DayRange(_, last) => last.as_date(now).map(Some),
+            DateUnit::Structured(_) | DateUnit::Relative(_) => Ok(None),
+        }
+    }
+
+    /// Resolves ambiguous slash separated dates using the given component order,
+    /// see [`DateStructured::in_order`]
+    pub const fn in_order(self, order: DateOrder) -> Self {
+        match self {
+            DateUnit::Structured(structured) => DateUnit::Structured(structured.in_order(order)),
+            DateUnit::Relative(_) | DateUnit::DayRange(..) => self,
+        }
+    }
+
+    /// Resolves two digit years, see [`DateStructured::with_year_pivot`]
+    pub const fn with_year_pivot(self, pivot: i8) -> Self {
+        match self {
+            DateUnit::Structured(structured) => {
+                DateUnit::Structured(structured.with_year_pivot(pivot))
+            }
+            DateUnit::DayRange(first_day, last) => {
+                DateUnit::DayRange(first_day, last.with_year_pivot(pivot))
+            }
+            DateUnit::Relative(_) => self,
+        }
+    }
 }
 impl AsDate for DateUnit {
     fn as_date(&self, now: Zoned) -> Result<Date, EventParseError> {
         match self {
             DateUnit::Structured(structured) => structured.as_date(now),
             DateUnit::Relative(relative) => relative.as_date(now),
+            DateUnit::DayRange(first_day, last) => {
+                let last = last.as_date(now)?;
+                let month = if *first_day > last.day() {
+                    last.checked_sub(1.month())
+                        .map_err(|_e| EventParseError::invalid_time())?
+                } else {
+                    last
+                };
+                Date::new(month.year(), month.month(), *first_day)
+                    .map_err(|_e| EventParseError::invalid_time())
+            }
         }
     }
 }
@@ -268,44 +1042,264 @@ impl AsDate for DateUnit {
 /// Tries to find a date from the supplied string.
 /// The date can be expressed as
 /// - a full gregorian calendar date in (d)d.(m)m.(yyy)y: 8.12.2000, 13.04.2004, 1.1.0
+///   - Exactly two digit years are placed in the 2000s or the 1900s according to
+///     [`ParserOptions::two_digit_year_pivot`]: 18.11.24, 18.11.99
 /// - next matching (d)d.(m)m. gregorian calendar date: 8.12., 13.04., 1.1.
 ///   - If the date is currently 01.06.2019, the strings above will be parsed as: 8.12.2019,
 ///     13.04.2020, 1.1.2020
+/// - a slash separated date, either d/m(/y) or m/d(/y) depending on the [`DateOrder`]: 3/4, 12/25,
+///   3/4/2025
+/// - a range of days sharing a month, in which case the first day is returned: 18.-20.11.,
+///   30.-2.12. (the first day is in the previous month)
 /// - a relative date, such as:
 ///   - tomorrow
 ///   - yesterday
+///   - day before yesterday, toissapäivänä
+///   - Spanish: mañana, pasado mañana, próximo lunes, lunes que viene, lunes pasado
+///   - French: demain, après-demain, avant-hier, lundi prochain, lundi dernier
+///   - Italian: domani, dopodomani, ieri, lunedì prossimo, lunedì scorso
 ///   - ("next"/"last") (weekday)
+///   - ("first"/"second"/"third"/"fourth"/"last") (weekday) "of" (month): first monday of december
+///   - ("week"/"viikko"/"vk") (ISO week number), resolves to the Monday of that week
+///   - (requires the `holidays` feature) the next occurrence of a holiday: Christmas, New Year,
+///     Midsummer, Easter, Good Friday, joulu, juhannus, vappu, pääsiäinen, ...
 ///   - (not implemented yet) ("next"/"last") (context event)
 ///   - (not implemented yet) (weekday/"day") ("after"/"before") (context event)
+#[cfg(test)]
 pub fn find_date(s: &str) -> Option<(DateUnit, usize, usize)> {
-    let mut start = 0;
-    let mut past_words = vec![];
-    let mut past_words_start_positions = vec![];
-    for word in s.split([' ', ',']) {
+    find_date_with_options(s, &ParserOptions::default())
+}
+
+/// Like [`find_date`], but allows tuning how ambiguous inputs are interpreted through
+/// [`ParserOptions`].
+/// With [`ParserOptions::fuzzy`] enabled, misspelled relative words and weekdays such as
+/// "tomorow" or "wedesday" are accepted as well.
+pub fn find_date_with_options(
+    s: &str,
+    options: &ParserOptions,
+) -> Option<(DateUnit, usize, usize)> {
+    let mut recent_words = RecentWords::default();
+    let mut after_clock_prefix = false;
+    for (start, word) in split_date_words(s) {
         let end = start + word.len();
-        past_words.push(word.to_owned());
-        past_words_start_positions.push(start);
+        recent_words.push(word, start);
+        // The "16.30" in "kello 16.30" is a time, not a date
+        let after_prefix = mem::replace(&mut after_clock_prefix, is_clock_prefix(word));
+        if after_prefix && word.starts_with(|c: char| c.is_ascii_digit()) {
+            continue;
+        }
 
-        if let Some((unit, words_matched)) = DateRelative::parse_multiword(&past_words) {
-            start = past_words_start_positions[past_words_start_positions.len() - words_matched];
-            return Some((DateUnit::Relative(unit), start, end));
+        if let Some((unit, words_matched)) =
+            DateRelative::parse_multiword_fuzzy(recent_words.words(), options.fuzzy)
+        {
+            // "last friday" might be the start of "last friday of november"
+            let continues =
+                matches!(unit, DateRelative::LastWeekday(..)) && starts_with_of_month(&s[end..]);
+            // "New Year" might be the start of "New Year's Eve"
+            #[cfg(feature = "holidays")]
+            let continues = continues
+                || match unit {
+                    DateRelative::Holiday(_, holiday) => continues_as_eve(holiday, &s[end..]),
+                    _ => false,
+                };
+            if !continues {
+                let phrase_start = recent_words.nth_last_start(words_matched);
+                return Some((DateUnit::Relative(unit), phrase_start, end));
+            }
         }
-        if let Ok(unit) = word.parse::<DateRelative>() {
-            return Some((DateUnit::Relative(unit), start, end));
+        if let Some(unit) = DateUnit::from_day_range(word) {
+            return Some((unit, start, end));
         }
         if let Ok(unit) = word.parse::<DateStructured>() {
             return Some((DateUnit::Structured(unit), start, end));
         }
-
-        start = end + 1;
     }
     None
 }
 
+/// Tries to find a weekday written on its own in any language, as the "saturday" in
+/// "Party saturday 19:00". Such words are common in summaries as well, so this is only tried when
+/// [`find_date_with_options`] finds no date.
+pub fn find_weekday(s: &str) -> Option<(DateUnit, usize, usize)> {
+    split_date_words(s).into_iter().find_map(|(start, word)| {
+        let normalized = normalize_word(word);
+        DateRelativeLanguage::iter().find_map(|lang| {
+            let weekday = DateRelativeWeekday::iter()
+                .find(|weekday| weekday.to_locale_static_str(lang) == normalized)?;
+            let unit = DateUnit::Relative(DateRelative::Weekday(lang, weekday));
+            Some((unit, start, start + word.len()))
+        })
+    })
+}
+
+/// Matches a phrase marking a date as lasting all day, such as "all day" or "koko päivän"
+static ALL_DAY_MARKER: Lazy<Regex> =
+    lazy_regex!(r"^\s+(?P<phrase>(?i:all[\s-]day|koko\s+päivän?))(?:$|[\s,@(])");
+
+/// Tries to find a phrase marking the preceding date as lasting all day, such as "all day",
+/// "all-day" or "koko päivän", directly following the date.
+/// Returns the end offset of the phrase in `s_after_date`.
+pub fn find_all_day_marker(s_after_date: &str) -> Option<usize> {
+    let captures = ALL_DAY_MARKER.captures(s_after_date)?;
+    Some(captures.name("phrase")?.end())
+}
+
+/// The end of a range of dates, as in "from monday until friday" or "3.6.-7.6."
+#[derive(Debug, PartialEq)]
+pub enum DateRangeEnd {
+    /// A date on its own, "until 7.6.", "until next friday"
+    Date(DateUnit),
+    /// A bare weekday, "until friday", resolved to the first such day after the start
+    Weekday(DateRelativeWeekday),
+}
+impl DateRangeEnd {
+    /// Resolves the last date of the range starting on `start`
+    pub fn resolve(
+        self,
+        start: Date,
+        now: Zoned,
+        options: &ParserOptions,
+    ) -> Result<Date, EventParseError> {
+        match self {
+            DateRangeEnd::Date(unit) => unit
+                .in_order(options.date_order)
+                .with_year_pivot(options.two_digit_year_pivot)
+                .as_date(now),
+            DateRangeEnd::Weekday(weekday) => start
+                .nth_weekday(1, weekday.into())
+                .map_err(|_e| EventParseError::invalid_time()),
+        }
+    }
+}
+
+/// Words separating the start of a range of dates from its end, as in "monday until friday"
+const DATE_RANGE_SEPARATORS: [&str; 3] = ["until", "till", "to"];
+
+/// Tries to find the end of a range of dates directly following a date:
+/// - ("until"/"till"/"to") (date): until 7.6., to next friday
+/// - ("until"/"till"/"to") (weekday): until friday, the first such day after the start
+/// - "-" (date): 3.6.-7.6., 3.6. – 7.6.
+/// - (date) "asti": 7.6. asti
+///
+/// Returns the end and its end offset in `s_after_date`.
+pub fn find_date_range_end(
+    s_after_date: &str,
+    options: &ParserOptions,
+) -> Option<(DateRangeEnd, usize)> {
+    let trimmed = s_after_date.trim_start();
+    let offset = s_after_date.len() - trimmed.len();
+    if let Some(rest) = trimmed.strip_prefix(['-', '–']) {
+        let rest_start = s_after_date.len() - rest.len();
+        let (unit, end) = date_at_start(rest, options)?;
+        return Some((DateRangeEnd::Date(unit), rest_start + end));
+    }
+    if offset == 0 {
+        return None;
+    }
+    if let Some((word, rest)) = trimmed.split_once(' ') {
+        if DATE_RANGE_SEPARATORS.contains(&normalize_word(word).as_str()) {
+            let rest_start = s_after_date.len() - rest.len();
+            if let Some((unit, end)) = date_at_start(rest, options) {
+                return Some((DateRangeEnd::Date(unit), rest_start + end));
+            }
+            let weekday_word = rest.split([' ', ',', '@']).next()?;
+            let weekday = parse_weekday(weekday_word)?;
+            return Some((
+                DateRangeEnd::Weekday(weekday),
+                rest_start + weekday_word.len(),
+            ));
+        }
+    }
+    // Finnish, "7.6. asti"
+    let (unit, date_end) = date_at_start(trimmed, options)?;
+    let after_date = &trimmed[date_end..];
+    let asti = after_date.trim_start();
+    let asti_word = asti.split([' ', ',', '@']).next()?;
+    let asti_start = offset + trimmed.len() - asti.len();
+    (asti.len() < after_date.len() && normalize_word(asti_word) == "asti")
+        .then(|| (DateRangeEnd::Date(unit), asti_start + asti_word.len()))
+}
+
+/// Finds a date at the very start of `s`, returning it along with its end offset
+fn date_at_start(s: &str, options: &ParserOptions) -> Option<(DateUnit, usize)> {
+    let (unit, start, end) = find_date_with_options(s, options)?;
+    (start == 0).then_some((unit, end))
+}
+
+/// Parses a weekday written on its own in any language, as the "friday" in "until friday"
+fn parse_weekday(word: &str) -> Option<DateRelativeWeekday> {
+    let word = normalize_word(word);
+    DateRelativeWeekday::iter().find(|weekday| {
+        DateRelativeLanguage::iter().any(|lang| weekday.to_locale_static_str(lang) == word)
+    })
+}
+
+/// Whether `word` is a likely typo of the known word `target`: at most one edit away for words of
+/// 4 to 7 characters and at most two for longer ones. Words shorter than 4 characters must match
+/// exactly, as too many ordinary words are a single edit away from them.
+fn is_typo_of(word: &str, target: &str) -> bool {
+    let max_distance = match target.chars().count() {
+        ..=3 => return false,
+        4..=7 => 1,
+        _ => 2,
+    };
+    edit_distance(word, target) <= max_distance
+}
+
+/// Levenshtein distance between `a` and `b` in characters
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+/// Splits `s` into words and their starting byte offsets for [`find_date`].
+/// Words are separated by spaces and commas, and by hyphens unless the hyphen is a part of the
+/// word, as in "après-demain" or the day range "18.-20.11.".
+fn split_date_words(s: &str) -> Vec<(usize, &str)> {
+    let mut words = Vec::new();
+    let mut start = 0;
+    for token in s.split([' ', ',']) {
+        let keep_whole = !token.contains('-')
+            || is_hyphenated_term(token)
+            || DateUnit::from_day_range(token).is_some();
+        let parts: Vec<&str> = if keep_whole {
+            vec![token]
+        } else {
+            token.split('-').collect()
+        };
+        for part in parts {
+            words.push((start, part));
+            start += part.len() + 1;
+        }
+    }
+    words
+}
+
+/// Whether `word` is a relative date term written with a hyphen, such as "après-demain"
+fn is_hyphenated_term(word: &str) -> bool {
+    let word = normalize_word(word);
+    DateRelativeLanguage::iter()
+        .flat_map(|lang| lang.get_relative_days())
+        .flatten()
+        .flat_map(|tokens| tokens.iter())
+        .any(|token| token.contains('-') && *token == word)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    use jiff::civil::date;
+
     #[test]
     fn find_date_trivial_month_date_a() {
         let (unit, start, end) = find_date("John's birthday 18.11.").expect("parse failed");
@@ -337,6 +1331,108 @@ mod tests {
         assert_eq!(start, 16);
         assert_eq!(end, 26);
     }
+    #[test]
+    fn find_date_two_digit_year() {
+        let (unit, start, end) = find_date("John's birthday 18.11.24").expect("parse failed");
+        assert_eq!(
+            unit,
+            DateUnit::Structured(DateStructured::ShortYmd(24, 11, 18))
+        );
+        assert_eq!(start, 16);
+        assert_eq!(end, 24);
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        assert_eq!(unit.as_date(now), Ok(date(2024, 11, 18)));
+    }
+    #[test]
+    fn two_digit_year_pivot() {
+        let late = DateStructured::ShortYmd(99, 11, 18);
+        assert_eq!(late.with_year_pivot(70), DateStructured::Ymd(1999, 11, 18));
+        let early = DateStructured::ShortYmd(69, 11, 18);
+        assert_eq!(early.with_year_pivot(70), DateStructured::Ymd(2069, 11, 18));
+        assert_eq!(early.with_year_pivot(50), DateStructured::Ymd(1969, 11, 18));
+    }
+    #[test]
+    fn slashed_two_digit_year() {
+        let slashed = DateStructured::Slashed(3, 4, Some(25));
+        assert_eq!(
+            slashed.in_order(DateOrder::MonthFirst),
+            DateStructured::ShortYmd(25, 3, 4)
+        );
+    }
+    #[test]
+    fn find_date_slashed() {
+        let (unit, start, end) = find_date("Dentist 3/4 10:00").expect("parse failed");
+        assert_eq!(
+            unit,
+            DateUnit::Structured(DateStructured::Slashed(3, 4, None))
+        );
+        assert_eq!(start, 8);
+        assert_eq!(end, 11);
+    }
+    #[test]
+    fn find_date_day_range() {
+        let (unit, start, end) = find_date("Offsite 18.-20.11.").expect("parse failed");
+        assert_eq!(unit, DateUnit::DayRange(18, DateStructured::Ym(11, 20)));
+        assert_eq!(start, 8);
+        assert_eq!(end, 18);
+    }
+    #[test]
+    fn day_range_same_month() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let unit = DateUnit::DayRange(18, DateStructured::Ym(11, 20));
+        assert_eq!(unit.as_date(now.clone()), Ok(date(2024, 11, 18)));
+        assert_eq!(unit.last_date(now), Ok(Some(date(2024, 11, 20))));
+    }
+    #[test]
+    fn day_range_crossing_month() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let unit = DateUnit::DayRange(30, DateStructured::Ym(12, 2));
+        assert_eq!(unit.as_date(now.clone()), Ok(date(2024, 11, 30)));
+        assert_eq!(unit.last_date(now), Ok(Some(date(2024, 12, 2))));
+    }
+    #[test]
+    fn day_range_crossing_year() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let unit = DateUnit::DayRange(30, DateStructured::Ymd(2025, 1, 2));
+        assert_eq!(unit.as_date(now), Ok(date(2024, 12, 30)));
+    }
+    #[test]
+    fn find_date_day_range_not_a_range() {
+        assert_eq!(DateUnit::from_day_range("18-20"), None);
+        assert_eq!(DateUnit::from_day_range("40.-20.11."), None);
+        assert_eq!(DateUnit::from_day_range("18.-3/4"), None);
+    }
+    #[test]
+    fn find_date_slashed_not_a_date() {
+        assert_eq!(find_date("Split it 50/50"), None);
+        assert_eq!(find_date("Open 24/24"), None);
+        assert_eq!(find_date("Read and/or write"), None);
+    }
+    #[test]
+    fn slashed_in_order() {
+        let slashed = DateStructured::Slashed(3, 4, None);
+        assert_eq!(
+            slashed.in_order(DateOrder::DayFirst),
+            DateStructured::Ym(4, 3)
+        );
+        assert_eq!(
+            slashed.in_order(DateOrder::MonthFirst),
+            DateStructured::Ym(3, 4)
+        );
+    }
+    #[test]
+    fn slashed_in_order_unambiguous() {
+        let slashed = DateStructured::Slashed(12, 25, Some(2024));
+        assert_eq!(
+            slashed.in_order(DateOrder::DayFirst),
+            DateStructured::Ymd(2024, 12, 25)
+        );
+        assert_eq!(
+            slashed.in_order(DateOrder::MonthFirst),
+            DateStructured::Ymd(2024, 12, 25)
+        );
+    }
+
     #[test]
     fn find_date_relative_a() {
         let (unit, start, end) = find_date("John's birthday tomorrow").expect("parse failed");
@@ -380,25 +1476,162 @@ mod tests {
     }
 
     #[test]
-    fn find_date_relative_weekday_a() {
-        let (unit, start, end) = find_date("John's birthday next monday").expect("parse failed");
+    fn find_date_relative_ereyesterday_a() {
+        let (unit, start, end) =
+            find_date("John's birthday day before yesterday").expect("parse failed");
         assert_eq!(
             unit,
-            DateUnit::Relative(DateRelative::NextWeekday(
-                DateRelativeLanguage::English,
-                DateRelativeWeekday::Monday
-            ))
+            DateUnit::Relative(DateRelative::Ereyesterday(DateRelativeLanguage::English))
         );
         assert_eq!(start, 16);
-        assert_eq!(end, 27);
+        assert_eq!(end, 36);
     }
     #[test]
-    fn find_date_relative_weekday_b() {
-        let (unit, start, end) = find_date("John's birthday next wednesday").expect("parse failed");
+    fn find_date_relative_ereyesterday_b() {
+        let (unit, start, end) = find_date("Johnin synttärit toissapäivänä").expect("parse failed");
         assert_eq!(
             unit,
-            DateUnit::Relative(DateRelative::NextWeekday(
-                DateRelativeLanguage::English,
+            DateUnit::Relative(DateRelative::Ereyesterday(DateRelativeLanguage::Finnish))
+        );
+        assert_eq!(start, 18);
+        assert_eq!(end, 34);
+    }
+    #[test]
+    fn ereyesterday_as_date() {
+        let now = date(2024, 3, 1).in_tz("UTC").unwrap();
+        let (unit, _, _) = find_date("Standup day before yesterday").expect("parse failed");
+        assert_eq!(unit.as_date(now), Ok(date(2024, 2, 28)));
+    }
+    #[test]
+    fn find_date_norwegian_ereyesterday() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let (unit, _, _) = find_date("Møte i forgårs").expect("parse failed");
+        assert_eq!(unit.as_date(now), Ok(date(2024, 5, 30)));
+    }
+
+    #[test]
+    fn find_date_nth_weekday_of_month() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let (unit, start, end) =
+            find_date("Board meeting first monday of December").expect("parse failed");
+        assert_eq!(
+            unit,
+            DateUnit::Relative(DateRelative::NthWeekdayOfMonth(
+                1,
+                DateRelativeWeekday::Monday,
+                12
+            ))
+        );
+        assert_eq!(start, 14);
+        assert_eq!(end, 38);
+        assert_eq!(unit.as_date(now), Ok(date(2024, 12, 2)));
+    }
+    #[test]
+    fn find_date_last_weekday_of_month() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let (unit, start, end) =
+            find_date("Retro last friday of November 14:00").expect("parse failed");
+        assert_eq!(
+            unit,
+            DateUnit::Relative(DateRelative::NthWeekdayOfMonth(
+                -1,
+                DateRelativeWeekday::Friday,
+                11
+            ))
+        );
+        assert_eq!(start, 6);
+        assert_eq!(end, 29);
+        assert_eq!(unit.as_date(now), Ok(date(2024, 11, 29)));
+    }
+    #[test]
+    fn nth_weekday_of_month_passed() {
+        let now = date(2024, 12, 10).in_tz("UTC").unwrap();
+        let unit = DateRelative::NthWeekdayOfMonth(1, DateRelativeWeekday::Monday, 12);
+        assert_eq!(unit.as_date(now), Ok(date(2025, 12, 1)));
+    }
+    #[test]
+    fn find_date_end_of_month() {
+        let now = date(2024, 2, 10).in_tz("UTC").unwrap();
+        let (unit, start, end) = find_date("Invoice end of month").expect("parse failed");
+        assert_eq!(
+            unit,
+            DateUnit::Relative(DateRelative::MonthPart(
+                DateRelativeLanguage::English,
+                MonthPart::End,
+                0
+            ))
+        );
+        assert_eq!(start, 8);
+        assert_eq!(end, 20);
+        assert_eq!(unit.as_date(now), Ok(date(2024, 2, 29)));
+    }
+    #[test]
+    fn find_date_beginning_of_next_month() {
+        let now = date(2024, 12, 10).in_tz("UTC").unwrap();
+        let (unit, start, end) =
+            find_date("Kickoff beginning of next month").expect("parse failed");
+        assert_eq!(
+            unit,
+            DateUnit::Relative(DateRelative::MonthPart(
+                DateRelativeLanguage::English,
+                MonthPart::Beginning,
+                1
+            ))
+        );
+        assert_eq!(start, 8);
+        assert_eq!(end, 31);
+        assert_eq!(unit.as_date(now), Ok(date(2025, 1, 1)));
+    }
+    #[test]
+    fn find_date_month_part_variants() {
+        let now = date(2024, 6, 20).in_tz("UTC").unwrap();
+        let resolve = |s| {
+            let (unit, _, _) = find_date(s).expect("parse failed");
+            unit.as_date(now.clone()).unwrap()
+        };
+        assert_eq!(resolve("Review mid-month"), date(2024, 7, 15));
+        assert_eq!(resolve("Review middle of this month"), date(2024, 7, 15));
+        assert_eq!(resolve("Review start of the month"), date(2024, 7, 1));
+        assert_eq!(resolve("Review end of last month"), date(2024, 5, 31));
+        assert_eq!(resolve("Laskutus kuun lopussa"), date(2024, 6, 30));
+        assert_eq!(resolve("Aloitus ensi kuun alussa"), date(2024, 7, 1));
+        assert_eq!(
+            resolve("Katsaus viime kuun puolivälissä"),
+            date(2024, 5, 15)
+        );
+    }
+    #[test]
+    fn find_date_last_weekday_not_of_month() {
+        let (unit, _, _) = find_date("Retro last friday of the sprint").expect("parse failed");
+        assert_eq!(
+            unit,
+            DateUnit::Relative(DateRelative::LastWeekday(
+                DateRelativeLanguage::English,
+                DateRelativeWeekday::Friday
+            ))
+        );
+    }
+
+    #[test]
+    fn find_date_relative_weekday_a() {
+        let (unit, start, end) = find_date("John's birthday next monday").expect("parse failed");
+        assert_eq!(
+            unit,
+            DateUnit::Relative(DateRelative::NextWeekday(
+                DateRelativeLanguage::English,
+                DateRelativeWeekday::Monday
+            ))
+        );
+        assert_eq!(start, 16);
+        assert_eq!(end, 27);
+    }
+    #[test]
+    fn find_date_relative_weekday_b() {
+        let (unit, start, end) = find_date("John's birthday next wednesday").expect("parse failed");
+        assert_eq!(
+            unit,
+            DateUnit::Relative(DateRelative::NextWeekday(
+                DateRelativeLanguage::English,
                 DateRelativeWeekday::Wednesday
             ))
         );
@@ -420,6 +1653,510 @@ mod tests {
         assert_eq!(end, 32);
     }
 
+    #[test]
+    fn find_date_iso_week_a() {
+        let (unit, start, end) = find_date("Sprint review week 45").expect("parse failed");
+        assert_eq!(
+            unit,
+            DateUnit::Relative(DateRelative::IsoWeek(DateRelativeLanguage::English, 45))
+        );
+        assert_eq!(start, 14);
+        assert_eq!(end, 21);
+    }
+    #[test]
+    fn find_date_iso_week_b() {
+        let (unit, start, end) = find_date("Sprintti vk 2").expect("parse failed");
+        assert_eq!(
+            unit,
+            DateUnit::Relative(DateRelative::IsoWeek(DateRelativeLanguage::Finnish, 2))
+        );
+        assert_eq!(start, 9);
+        assert_eq!(end, 13);
+    }
+    #[test]
+    fn find_date_iso_week_out_of_range() {
+        assert!(find_date("Sprint review week 54").is_none());
+    }
+    #[test]
+    fn iso_week_as_date_current_year() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let week = DateRelative::IsoWeek(DateRelativeLanguage::English, 45);
+        let monday = week.as_date(now).unwrap();
+        assert_eq!(monday, date(2024, 11, 4));
+        assert_eq!(monday.weekday(), Weekday::Monday);
+    }
+    #[test]
+    fn iso_week_as_date_ongoing_week() {
+        // Sunday of week 22, the week hasn't ended yet
+        let now = date(2024, 6, 2).in_tz("UTC").unwrap();
+        let week = DateRelative::IsoWeek(DateRelativeLanguage::Finnish, 22);
+        assert_eq!(week.as_date(now).unwrap(), date(2024, 5, 27));
+    }
+    #[test]
+    fn iso_week_as_date_next_year() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let week = DateRelative::IsoWeek(DateRelativeLanguage::Finnish, 2);
+        assert_eq!(week.as_date(now).unwrap(), date(2025, 1, 6));
+    }
+    #[test]
+    fn iso_week_as_date_week_53_a() {
+        // 2025 has no week 53, but 2026 does
+        let now = date(2025, 12, 1).in_tz("UTC").unwrap();
+        let week = DateRelative::IsoWeek(DateRelativeLanguage::English, 53);
+        assert_eq!(week.as_date(now).unwrap(), date(2026, 12, 28));
+    }
+    #[test]
+    fn iso_week_as_date_week_53_b() {
+        // Neither 2024 nor 2025 have a week 53
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let week = DateRelative::IsoWeek(DateRelativeLanguage::English, 53);
+        assert_eq!(week.as_date(now), Err(EventParseError::invalid_time()));
+    }
+
+    #[cfg(feature = "holidays")]
+    #[test]
+    fn find_date_holiday_a() {
+        let (unit, start, end) = find_date("Dinner on Christmas").expect("parse failed");
+        assert_eq!(
+            unit,
+            DateUnit::Relative(DateRelative::Holiday(
+                DateRelativeLanguage::English,
+                Holiday::Christmas
+            ))
+        );
+        assert_eq!(start, 10);
+        assert_eq!(end, 19);
+    }
+    #[cfg(feature = "holidays")]
+    #[test]
+    fn find_date_holiday_b() {
+        let (unit, start, end) = find_date("Party on New Year's 20:00").expect("parse failed");
+        assert_eq!(
+            unit,
+            DateUnit::Relative(DateRelative::Holiday(
+                DateRelativeLanguage::English,
+                Holiday::NewYear
+            ))
+        );
+        assert_eq!(start, 9);
+        assert_eq!(end, 19);
+    }
+    #[cfg(feature = "holidays")]
+    #[test]
+    fn find_date_holiday_new_years_eve() {
+        let (unit, start, end) = find_date("Party New Year's Eve 20:00").expect("parse failed");
+        assert_eq!(
+            unit,
+            DateUnit::Relative(DateRelative::Holiday(
+                DateRelativeLanguage::English,
+                Holiday::NewYearsEve
+            ))
+        );
+        assert_eq!(start, 6);
+        assert_eq!(end, 20);
+    }
+    #[cfg(feature = "holidays")]
+    #[test]
+    fn holiday_as_date() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let midsummer = DateRelative::Holiday(DateRelativeLanguage::Finnish, Holiday::Midsummer);
+        assert_eq!(midsummer.as_date(now.clone()), Ok(date(2024, 6, 22)));
+        let vappu = DateRelative::Holiday(DateRelativeLanguage::Finnish, Holiday::MayDay);
+        assert_eq!(vappu.as_date(now), Ok(date(2025, 5, 1)));
+    }
+
+    #[test]
+    fn find_date_long_summary() {
+        let summary = "Yet another very long meeting about duckling quotas ".repeat(10_000);
+        let input = format!("{summary}day after tomorrow");
+        let (unit, start, end) = find_date(&input).expect("parse failed");
+        assert_eq!(
+            unit,
+            DateUnit::Relative(DateRelative::Overmorrow(DateRelativeLanguage::English))
+        );
+        assert_eq!(start, summary.len());
+        assert_eq!(end, input.len());
+    }
+
+    #[test]
+    fn find_date_norwegian_tomorrow() {
+        let (unit, start, end) = find_date("Tannlege i morgen 9:00").expect("parse failed");
+        assert_eq!(
+            unit,
+            DateUnit::Relative(DateRelative::Tomorrow(DateRelativeLanguage::Norwegian))
+        );
+        assert_eq!(start, 9);
+        assert_eq!(end, 17);
+    }
+    #[test]
+    fn find_date_norwegian_yesterday() {
+        let (unit, start, end) = find_date("Tannlege I går, Oslo").expect("parse failed");
+        assert_eq!(
+            unit,
+            DateUnit::Relative(DateRelative::Yesterday(DateRelativeLanguage::Norwegian))
+        );
+        assert_eq!(start, 9);
+        assert_eq!(end, 15);
+    }
+    #[test]
+    fn parse_relative_multiword() {
+        assert_eq!(
+            "Day after tomorrow".parse::<DateRelative>(),
+            Ok(DateRelative::Overmorrow(DateRelativeLanguage::English))
+        );
+        assert_eq!(
+            "i dag".parse::<DateRelative>(),
+            Ok(DateRelative::Today(DateRelativeLanguage::Norwegian))
+        );
+        assert_eq!("after tomorrow".parse::<DateRelative>(), Err(()));
+    }
+    #[test]
+    fn find_date_danish_tomorrow() {
+        // Danish shares the word with Norwegian
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let (unit, start, end) = find_date("Tandlæge i morgen").expect("parse failed");
+        assert_eq!(unit.as_date(now), Ok(date(2024, 6, 2)));
+        assert_eq!(start, 10);
+        assert_eq!(end, 18);
+    }
+    #[test]
+    fn find_date_norwegian_weekday() {
+        let (unit, start, end) = find_date("Møte neste fredag").expect("parse failed");
+        assert_eq!(
+            unit,
+            DateUnit::Relative(DateRelative::NextWeekday(
+                DateRelativeLanguage::Norwegian,
+                DateRelativeWeekday::Friday
+            ))
+        );
+        assert_eq!(start, 6);
+        assert_eq!(end, 18);
+    }
+    #[test]
+    fn find_date_danish_weekday() {
+        let (unit, _, _) = find_date("Møde næste lørdag").expect("parse failed");
+        assert_eq!(
+            unit,
+            DateUnit::Relative(DateRelative::NextWeekday(
+                DateRelativeLanguage::Danish,
+                DateRelativeWeekday::Saturday
+            ))
+        );
+    }
+    #[test]
+    fn find_date_danish_overmorrow() {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let (unit, _, _) = find_date("Fødselsdag i overmorgen").expect("parse failed");
+        assert_eq!(unit.as_date(now), Ok(date(2024, 6, 3)));
+    }
+    #[test]
+    fn find_date_spanish_tomorrow() {
+        let (unit, start, end) = find_date("Reunión mañana 10:00").expect("parse failed");
+        assert_eq!(
+            unit,
+            DateUnit::Relative(DateRelative::Tomorrow(DateRelativeLanguage::Spanish))
+        );
+        assert_eq!(start, 9);
+        assert_eq!(end, 16);
+    }
+    #[test]
+    fn find_date_spanish_overmorrow() {
+        let (unit, start, _) = find_date("Cena pasado mañana").expect("parse failed");
+        assert_eq!(
+            unit,
+            DateUnit::Relative(DateRelative::Overmorrow(DateRelativeLanguage::Spanish))
+        );
+        assert_eq!(start, 5);
+    }
+    #[test]
+    fn find_date_spanish_next_weekday_prefix() {
+        let (unit, start, end) = find_date("Cita próximo lunes").expect("parse failed");
+        assert_eq!(
+            unit,
+            DateUnit::Relative(DateRelative::NextWeekday(
+                DateRelativeLanguage::Spanish,
+                DateRelativeWeekday::Monday
+            ))
+        );
+        assert_eq!(start, 5);
+        assert_eq!(end, 19);
+    }
+    #[test]
+    fn find_date_spanish_next_weekday_postfix() {
+        let (unit, start, end) = find_date("Cita lunes que viene 9:00").expect("parse failed");
+        assert_eq!(
+            unit,
+            DateUnit::Relative(DateRelative::NextWeekday(
+                DateRelativeLanguage::Spanish,
+                DateRelativeWeekday::Monday
+            ))
+        );
+        assert_eq!(start, 5);
+        assert_eq!(end, 20);
+    }
+    #[test]
+    fn find_date_spanish_last_weekday_postfix() {
+        let (unit, _, _) = find_date("Cita viernes pasado").expect("parse failed");
+        assert_eq!(
+            unit,
+            DateUnit::Relative(DateRelative::LastWeekday(
+                DateRelativeLanguage::Spanish,
+                DateRelativeWeekday::Friday
+            ))
+        );
+    }
+    #[test]
+    fn weekday_modifier_prefix_tokens() {
+        let modifier = DateRelativeLanguage::English.get_modifiers_next()[0];
+        assert_eq!(modifier.tokens("monday"), vec!["next", "monday"]);
+    }
+    #[test]
+    fn weekday_modifier_postfix_tokens() {
+        let modifier = WeekdayModifier {
+            words: &["que", "viene"],
+            position: ModifierPosition::Postfix,
+        };
+        assert_eq!(modifier.tokens("lunes"), vec!["lunes", "que", "viene"]);
+        let synthetic = WeekdayModifier {
+            words: &["prochain"],
+            position: ModifierPosition::Postfix,
+        };
+        assert_eq!(synthetic.tokens("lundi"), vec!["lundi", "prochain"]);
+    }
+    #[test]
+    fn parse_multiword_prefix_modifier() {
+        let parsed = DateRelative::parse_multiword(&["Dentist", "next", "monday"]);
+        assert_eq!(
+            parsed,
+            Some((
+                DateRelative::NextWeekday(
+                    DateRelativeLanguage::English,
+                    DateRelativeWeekday::Monday
+                ),
+                2
+            ))
+        );
+    }
+
+    #[test]
+    fn find_date_french_hyphenated() {
+        let (unit, start, end) = find_date("Dîner après-demain 19:00").expect("parse failed");
+        assert_eq!(
+            unit,
+            DateUnit::Relative(DateRelative::Overmorrow(DateRelativeLanguage::French))
+        );
+        assert_eq!(start, 7);
+        assert_eq!(end, 20);
+    }
+    #[test]
+    fn find_date_french_next_weekday() {
+        let (unit, _, _) = find_date("Réunion lundi prochain").expect("parse failed");
+        assert_eq!(
+            unit,
+            DateUnit::Relative(DateRelative::NextWeekday(
+                DateRelativeLanguage::French,
+                DateRelativeWeekday::Monday
+            ))
+        );
+    }
+    #[test]
+    fn find_date_italian_next_weekday() {
+        let (unit, start, end) = find_date("Riunione Lunedì prossimo 10:00").expect("parse failed");
+        assert_eq!(
+            unit,
+            DateUnit::Relative(DateRelative::NextWeekday(
+                DateRelativeLanguage::Italian,
+                DateRelativeWeekday::Monday
+            ))
+        );
+        assert_eq!(start, 9);
+        assert_eq!(end, 25);
+    }
+    #[test]
+    fn find_date_italian_last_weekday() {
+        let (unit, _, _) = find_date("Cena domenica scorsa").expect("parse failed");
+        assert_eq!(
+            unit,
+            DateUnit::Relative(DateRelative::LastWeekday(
+                DateRelativeLanguage::Italian,
+                DateRelativeWeekday::Sunday
+            ))
+        );
+    }
+    #[test]
+    fn find_date_italian_tomorrow() {
+        let (unit, start, end) = find_date("Cena domani 20:00").expect("parse failed");
+        assert_eq!(
+            unit,
+            DateUnit::Relative(DateRelative::Tomorrow(DateRelativeLanguage::Italian))
+        );
+        assert_eq!(start, 5);
+        assert_eq!(end, 11);
+    }
+    #[test]
+    fn find_date_italian_overmorrow() {
+        let (unit, _, _) = find_date("Cena DOPODOMANI").expect("parse failed");
+        assert_eq!(
+            unit,
+            DateUnit::Relative(DateRelative::Overmorrow(DateRelativeLanguage::Italian))
+        );
+    }
+    #[test]
+    fn find_date_decomposed_accent() {
+        // "lunedì" with a combining grave accent
+        let (unit, start, end) =
+            find_date("Riunione LUNEDI\u{300} prossimo").expect("parse failed");
+        assert_eq!(
+            unit,
+            DateUnit::Relative(DateRelative::NextWeekday(
+                DateRelativeLanguage::Italian,
+                DateRelativeWeekday::Monday
+            ))
+        );
+        assert_eq!(start, 9);
+        assert_eq!(end, 26);
+    }
+    #[test]
+    fn find_date_mixed_case_accented() {
+        let (unit, _, _) = find_date("Cita MiÉrcoles que viene").expect("parse failed");
+        assert_eq!(
+            unit,
+            DateUnit::Relative(DateRelative::NextWeekday(
+                DateRelativeLanguage::Spanish,
+                DateRelativeWeekday::Wednesday
+            ))
+        );
+        let (ascii, _, _) = find_date("Meeting Next MONDAY").expect("parse failed");
+        assert_eq!(
+            ascii,
+            DateUnit::Relative(DateRelative::NextWeekday(
+                DateRelativeLanguage::English,
+                DateRelativeWeekday::Monday
+            ))
+        );
+    }
+    #[test]
+    fn find_date_fuzzy() {
+        let fuzzy = ParserOptions {
+            fuzzy: true,
+            ..Default::default()
+        };
+        let (tomorrow, start, end) =
+            find_date_with_options("Dentist tomorow 10:00", &fuzzy).expect("parse failed");
+        assert_eq!(
+            tomorrow,
+            DateUnit::Relative(DateRelative::Tomorrow(DateRelativeLanguage::English))
+        );
+        assert_eq!(start, 8);
+        assert_eq!(end, 15);
+        let (wednesday, _, _) =
+            find_date_with_options("Dentist wedesday next", &fuzzy).expect("parse failed");
+        assert_eq!(
+            wednesday,
+            DateUnit::Relative(DateRelative::NextWeekday(
+                DateRelativeLanguage::English,
+                DateRelativeWeekday::Wednesday
+            ))
+        );
+    }
+    #[test]
+    fn find_date_fuzzy_off_by_default() {
+        assert!(find_date("Dentist tomorow 10:00").is_none());
+        assert!(find_date("Dentist wedesday next").is_none());
+    }
+    #[test]
+    fn find_date_fuzzy_short_words_exact() {
+        let fuzzy = ParserOptions {
+            fuzzy: true,
+            ..Default::default()
+        };
+        // "hoy" is too short to be matched loosely, "tomato" is too far from "tomorrow"
+        assert!(find_date_with_options("Call hey", &fuzzy).is_none());
+        assert!(find_date_with_options("Eat tomato", &fuzzy).is_none());
+    }
+    #[test]
+    fn edit_distances() {
+        assert_eq!(edit_distance("tomorow", "tomorrow"), 1);
+        assert_eq!(edit_distance("tommorow", "tomorrow"), 2);
+        assert_eq!(edit_distance("wedesday", "wednesday"), 1);
+        assert_eq!(edit_distance("lunedi", "lunedì"), 1);
+        assert_eq!(edit_distance("", "oggi"), 4);
+    }
+    #[test]
+    fn find_date_british_next_weekday() {
+        let (unit, _, _) = find_date("Dentist wednesday next").expect("parse failed");
+        assert_eq!(
+            unit,
+            DateUnit::Relative(DateRelative::NextWeekday(
+                DateRelativeLanguage::English,
+                DateRelativeWeekday::Wednesday
+            ))
+        );
+    }
+    #[test]
+    fn find_date_hyphen_separated() {
+        let (unit, start, end) = find_date("Trip tomorrow-friday").expect("parse failed");
+        assert_eq!(
+            unit,
+            DateUnit::Relative(DateRelative::Tomorrow(DateRelativeLanguage::English))
+        );
+        assert_eq!(start, 5);
+        assert_eq!(end, 13);
+    }
+    #[test]
+    fn find_date_hyphenated_day_range() {
+        let (unit, start, end) = find_date("Offsite 18.-20.11. @ Nuuksio").expect("parse failed");
+        assert_eq!(unit, DateUnit::DayRange(18, DateStructured::Ym(11, 20)));
+        assert_eq!(start, 8);
+        assert_eq!(end, 18);
+    }
+    #[test]
+    fn find_weekday_any_language() {
+        assert_eq!(
+            find_weekday("Party Saturday 19:00"),
+            Some((
+                DateUnit::Relative(DateRelative::Weekday(
+                    DateRelativeLanguage::English,
+                    DateRelativeWeekday::Saturday
+                )),
+                6,
+                14
+            ))
+        );
+        assert_eq!(
+            find_weekday("Juhlat lauantaina"),
+            Some((
+                DateUnit::Relative(DateRelative::Weekday(
+                    DateRelativeLanguage::Finnish,
+                    DateRelativeWeekday::Saturday
+                )),
+                7,
+                17
+            ))
+        );
+        assert_eq!(find_weekday("Saturdays are for the boys"), None);
+    }
+    #[test]
+    fn split_date_words_hyphens() {
+        assert_eq!(
+            split_date_words("a-b après-demain, 1.-2.3."),
+            vec![
+                (0, "a"),
+                (2, "b"),
+                (4, "après-demain"),
+                (18, ""),
+                (19, "1.-2.3.")
+            ]
+        );
+    }
+
+    #[test]
+    fn find_date_skips_clock_time() {
+        assert_eq!(find_date("Palaveri kello 16.30"), None);
+        assert_eq!(find_date("Palaveri klo. 16.30"), None);
+        assert!(find_date("Palaveri klo huomenna").is_some());
+    }
+
     #[test]
     fn find_date_whitespace_a() {
         let (unit, start, end) = find_date(" John's birthday tomorrow").expect("parse failed");
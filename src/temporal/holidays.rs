@@ -0,0 +1,279 @@
+use jiff::{
+    civil::{date, Date, Weekday},
+    ToSpan,
+};
+
+use crate::{
+    temporal::{date::DateRelativeLanguage, normalize_word},
+    EventParseError,
+};
+
+/// Holidays that can be referred to by name
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Holiday {
+    /// Christmas Day, 25.12.
+    Christmas,
+    /// New Year's Day, 1.1.
+    NewYear,
+    /// New Year's Eve, 31.12.
+    NewYearsEve,
+    /// Finnish Midsummer Day (Juhannuspäivä), the Saturday between 20.6. and 26.6.
+    Midsummer,
+    /// May Day (Vappu), 1.5.
+    MayDay,
+    /// Easter Sunday, see [`easter_sunday`]
+    Easter,
+    /// Good Friday, two days before Easter Sunday
+    GoodFriday,
+}
+impl Holiday {
+    /// Returns the date this holiday falls on in the given year
+    pub fn in_year(self, year: i16) -> Result<Date, EventParseError> {
+        match self {
+            Holiday::Christmas => Ok(date(year, 12, 25)),
+            Holiday::NewYear => Ok(date(year, 1, 1)),
+            Holiday::NewYearsEve => Ok(date(year, 12, 31)),
+            Holiday::Midsummer => date(year, 6, 19)
+                .nth_weekday(1, Weekday::Saturday)
+                .map_err(|_e| EventParseError::ambiguous_time()),
+            Holiday::MayDay => Ok(date(year, 5, 1)),
+            Holiday::Easter => easter_sunday(year),
+            Holiday::GoodFriday => easter_sunday(year)?
+                .checked_sub(2.days())
+                .map_err(|_e| EventParseError::ambiguous_time()),
+        }
+    }
+
+    /// Returns the next time this holiday occurs, `today` included
+    pub fn next_occurrence(self, today: Date) -> Result<Date, EventParseError> {
+        let this_year = self.in_year(today.year())?;
+        if this_year >= today {
+            Ok(this_year)
+        } else {
+            self.in_year(today.year() + 1)
+        }
+    }
+}
+
+/// Computes the date of Easter Sunday in the given year using the anonymous Gregorian algorithm
+/// (Computus)
+pub fn easter_sunday(year: i16) -> Result<Date, EventParseError> {
+    let y = i32::from(year);
+    let a = y % 19;
+    let b = y / 100;
+    let c = y % 100;
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+    // Month is always 3 or 4 and day at most 31, so these conversions can't truncate
+    Date::new(year, month as i8, day as i8).map_err(|_e| EventParseError::invalid_time())
+}
+
+/// Tries to match a holiday name at the end of `words`.
+/// Returns the matched holiday and the number of words it spans.
+pub fn parse_holiday(words: &[&str]) -> Option<(DateRelativeLanguage, Holiday, usize)> {
+    let nth_last = |n: usize| words.len().checked_sub(n).map(|i| normalize_word(words[i]));
+    let last = nth_last(1)?;
+
+    if let (Some(third_to_last), Some(second_to_last)) = (nth_last(3), nth_last(2)) {
+        if third_to_last == "new" && is_year_word(&second_to_last) && last == "eve" {
+            return Some((DateRelativeLanguage::English, Holiday::NewYearsEve, 3));
+        }
+    }
+    if let Some(second_to_last) = nth_last(2) {
+        match (second_to_last.as_str(), last.as_str()) {
+            ("new", year) if is_year_word(year) => {
+                return Some((DateRelativeLanguage::English, Holiday::NewYear, 2));
+            }
+            ("may", "day") => return Some((DateRelativeLanguage::English, Holiday::MayDay, 2)),
+            ("good", "friday") => {
+                return Some((DateRelativeLanguage::English, Holiday::GoodFriday, 2));
+            }
+            _ => {}
+        }
+    }
+
+    let (lang, holiday) = match last.as_str() {
+        "christmas" => (DateRelativeLanguage::English, Holiday::Christmas),
+        "joulu" | "jouluna" => (DateRelativeLanguage::Finnish, Holiday::Christmas),
+
+        "uusivuosi" | "uutenavuotena" => (DateRelativeLanguage::Finnish, Holiday::NewYear),
+        "uudenvuodenaatto" | "uudenvuodenaattona" => {
+            (DateRelativeLanguage::Finnish, Holiday::NewYearsEve)
+        }
+
+        "midsummer" => (DateRelativeLanguage::English, Holiday::Midsummer),
+        "juhannus" | "juhannuksena" => (DateRelativeLanguage::Finnish, Holiday::Midsummer),
+
+        "vappu" | "vappuna" => (DateRelativeLanguage::Finnish, Holiday::MayDay),
+
+        "easter" => (DateRelativeLanguage::English, Holiday::Easter),
+        "pääsiäinen" | "pääsiäisenä" => (DateRelativeLanguage::Finnish, Holiday::Easter),
+
+        "pitkäperjantai" | "pitkäperjantaina" => {
+            (DateRelativeLanguage::Finnish, Holiday::GoodFriday)
+        }
+
+        _ => return None,
+    };
+    Some((lang, holiday, 1))
+}
+
+/// Whether `word` is the "year" of "New Year", in any of the ways it is written
+fn is_year_word(word: &str) -> bool {
+    matches!(word, "year" | "year's" | "year’s" | "years")
+}
+
+/// Whether the "New Year" that was just matched continues as "New Year's Eve", in which case the
+/// match should wait for the "eve" in `s_after`
+pub fn continues_as_eve(holiday: Holiday, s_after: &str) -> bool {
+    holiday == Holiday::NewYear
+        && s_after
+            .split_whitespace()
+            .next()
+            .is_some_and(|word| normalize_word(word) == "eve")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(s: &str) -> Vec<&str> {
+        s.split(' ').collect()
+    }
+
+    #[test]
+    fn parse_holiday_a() {
+        assert_eq!(
+            parse_holiday(&words("Dinner on Christmas")),
+            Some((DateRelativeLanguage::English, Holiday::Christmas, 1))
+        );
+    }
+    #[test]
+    fn parse_holiday_b() {
+        assert_eq!(
+            parse_holiday(&words("Party on New Year")),
+            Some((DateRelativeLanguage::English, Holiday::NewYear, 2))
+        );
+    }
+    #[test]
+    fn parse_holiday_new_years_eve() {
+        assert_eq!(
+            parse_holiday(&words("Party New Year's Eve")),
+            Some((DateRelativeLanguage::English, Holiday::NewYearsEve, 3))
+        );
+        assert!(continues_as_eve(Holiday::NewYear, " Eve 20:00"));
+        assert!(!continues_as_eve(Holiday::NewYear, " evening"));
+        assert_eq!(Holiday::NewYearsEve.in_year(2024), Ok(date(2024, 12, 31)));
+    }
+    #[test]
+    fn parse_holiday_c() {
+        assert_eq!(
+            parse_holiday(&words("Mökille juhannuksena")),
+            Some((DateRelativeLanguage::Finnish, Holiday::Midsummer, 1))
+        );
+    }
+    #[test]
+    fn parse_holiday_d() {
+        assert_eq!(parse_holiday(&words("Dinner on Tuesday")), None);
+    }
+
+    #[test]
+    fn midsummer_in_year() {
+        assert_eq!(Holiday::Midsummer.in_year(2024), Ok(date(2024, 6, 22)));
+        assert_eq!(Holiday::Midsummer.in_year(2025), Ok(date(2025, 6, 21)));
+        // 20.6.2026 is a Saturday itself
+        assert_eq!(Holiday::Midsummer.in_year(2026), Ok(date(2026, 6, 20)));
+        // 26.6.2027 is a Saturday itself
+        assert_eq!(Holiday::Midsummer.in_year(2027), Ok(date(2027, 6, 26)));
+    }
+
+    #[test]
+    fn parse_holiday_easter() {
+        assert_eq!(
+            parse_holiday(&words("Brunch on Easter")),
+            Some((DateRelativeLanguage::English, Holiday::Easter, 1))
+        );
+        assert_eq!(
+            parse_holiday(&words("Mämmiä Pääsiäisenä")),
+            Some((DateRelativeLanguage::Finnish, Holiday::Easter, 1))
+        );
+        assert_eq!(
+            parse_holiday(&words("Off work Good Friday")),
+            Some((DateRelativeLanguage::English, Holiday::GoodFriday, 2))
+        );
+    }
+
+    #[test]
+    fn easter_sunday_known_dates() {
+        assert_eq!(easter_sunday(2000), Ok(date(2000, 4, 23)));
+        assert_eq!(easter_sunday(2008), Ok(date(2008, 3, 23)));
+        assert_eq!(easter_sunday(2011), Ok(date(2011, 4, 24)));
+        assert_eq!(easter_sunday(2024), Ok(date(2024, 3, 31)));
+        assert_eq!(easter_sunday(2025), Ok(date(2025, 4, 20)));
+        assert_eq!(easter_sunday(2038), Ok(date(2038, 4, 25)));
+    }
+    #[test]
+    fn good_friday_in_year() {
+        assert_eq!(Holiday::GoodFriday.in_year(2024), Ok(date(2024, 3, 29)));
+        assert_eq!(Holiday::GoodFriday.in_year(2025), Ok(date(2025, 4, 18)));
+    }
+
+    #[test]
+    fn next_occurrence_easter_rollover() {
+        assert_eq!(
+            Holiday::Easter.next_occurrence(date(2024, 3, 30)),
+            Ok(date(2024, 3, 31))
+        );
+        assert_eq!(
+            Holiday::Easter.next_occurrence(date(2024, 3, 31)),
+            Ok(date(2024, 3, 31))
+        );
+        assert_eq!(
+            Holiday::Easter.next_occurrence(date(2024, 4, 1)),
+            Ok(date(2025, 4, 20))
+        );
+        assert_eq!(
+            Holiday::GoodFriday.next_occurrence(date(2024, 3, 30)),
+            Ok(date(2025, 4, 18))
+        );
+    }
+
+    #[test]
+    fn next_occurrence_same_year() {
+        let today = date(2024, 6, 1);
+        assert_eq!(
+            Holiday::Christmas.next_occurrence(today),
+            Ok(date(2024, 12, 25))
+        );
+        assert_eq!(
+            Holiday::Midsummer.next_occurrence(today),
+            Ok(date(2024, 6, 22))
+        );
+    }
+    #[test]
+    fn next_occurrence_next_year() {
+        let today = date(2024, 6, 1);
+        assert_eq!(
+            Holiday::NewYear.next_occurrence(today),
+            Ok(date(2025, 1, 1))
+        );
+        assert_eq!(Holiday::MayDay.next_occurrence(today), Ok(date(2025, 5, 1)));
+    }
+    #[test]
+    fn next_occurrence_today() {
+        let today = date(2024, 12, 25);
+        assert_eq!(
+            Holiday::Christmas.next_occurrence(today),
+            Ok(date(2024, 12, 25))
+        );
+    }
+}
@@ -1,67 +1,434 @@
 //! Used internally by library for parsing date and time information from strings
 #![allow(clippy::missing_docs_in_private_items)]
 
-use date::find_date;
+use date::{find_all_day_marker, find_date_range_end, find_date_with_options, find_weekday};
 use jiff::{
-    civil::{Date, Time},
-    Zoned,
+    civil::{Date, DateTime, Time},
+    SignedDuration, Span, ToSpan, Zoned,
 };
+use unicode_normalization::UnicodeNormalization;
 
 pub mod date;
+#[cfg(feature = "holidays")]
+pub mod holidays;
+pub mod recurrence;
 pub mod time;
 
 use date::AsDate;
-use time::{find_time, AsTime};
+use recurrence::{find_recurrence, Recurrence};
+use time::{
+    count_skipped_words, find_approximate_marker, find_clock_time_start, find_compact_duration,
+    find_day_part_today, find_duration, find_range_end, find_relative_time, find_time_with_options,
+    find_time_zone, share_meridiem, AsTime, DayPart, TimeNamed, TimeStructured, TimeUnit,
+    TimeZoneAnnotation,
+};
 
 use crate::{
     temporal::date::{DateRelative, DateUnit},
-    EventParseError,
+    EventParseError, MidnightConvention, ParserOptions,
 };
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct DateTimeMatch {
     pub date: Date,
     pub time: Option<Time>,
+    pub day_part: Option<DayPart>,
+    /// Set when the time is the start of a range such as "11:00-12:30"
+    pub duration: Option<Span>,
+    /// Set when the time is followed by a time zone annotation such as "UTC", "+02:00" or
+    /// "Europe/Helsinki"
+    pub time_zone: Option<TimeZoneAnnotation>,
+    /// Set when the time was marked as approximate, as in "around 5" or "5ish"
+    pub approximate: bool,
+    /// Set when the date could be read in another [`crate::DateOrder`], as with "3/4"
+    pub ambiguous_date: bool,
+    /// Set when the date had a two digit year such as "18.11.24", resolved with
+    /// [`ParserOptions::two_digit_year_pivot`]
+    pub short_year: bool,
+    /// Number of words between the date and the time that were ignored, as in
+    /// "tomorrow with Anna 17:00"
+    pub skipped_words: usize,
+    /// Set when the date was marked as lasting all day, as in "18.11. all day", or when it is a
+    /// range of days without a time. Any date without a time lasts all day, but one without a
+    /// marker could just as well be missing its time.
+    pub explicit_all_day: bool,
+    /// Set when the date was given as a repeating one, as in "every other monday", in which case
+    /// the date is the first occurrence
+    pub recurrence: Option<Recurrence>,
     pub start_char: usize,
     pub end_char: usize,
+    /// Where the date ends, including the end of a range of dates or an "all day" marker.
+    /// A relative time such as "in 5 minutes" has no date, so this is the start of the phrase.
+    pub date_end_char: usize,
+    /// Where the time starts and ends, including the end of a range and a time zone, but not a
+    /// duration
+    pub time_chars: Option<(usize, usize)>,
 }
 
 /// Tries to find a datetime from the supplied string.
 /// The date must be before the time.
-/// See [`find_date`] and [`find_time`] for more information on accepted formatting of the date or
-/// time.
+/// See `date::find_date_with_options` and `find_time_with_options` for more information on
+/// accepted formatting of the date or time.
+/// If no date can be found, a time relative to `now` is looked for instead, see
+/// `time::find_relative_time`, followed by a part of the current day such as "tonight", see
+/// `time::find_day_part_today`.
+/// The date may be followed by the end of a range of dates ("until friday"), see
+/// `date::find_date_range_end`.
+/// The time may be followed by either the end of a range ("-12:30") or a duration ("2h30",
+/// "for 2 hours"), see `time::find_range_end`, `time::find_compact_duration` and
+/// `time::find_duration`, and a time zone ("UTC") in between, see `time::find_time_zone`.
+/// A date without a time may be followed by a duration of whole days ("for 2 weeks").
 pub fn find_datetime(
     s: &str,
     now: Zoned,
     default_date: bool,
 ) -> Result<Option<DateTimeMatch>, EventParseError> {
-    if let Some((date, date_start, date_end)) = find_date(s).or_else(|| {
+    find_datetime_with_options(s, now, default_date, ParserOptions::default())
+}
+
+/// Like [`find_datetime`], but allows tuning how ambiguous inputs are interpreted through
+/// [`ParserOptions`].
+pub fn find_datetime_with_options(
+    s: &str,
+    now: Zoned,
+    default_date: bool,
+    options: ParserOptions,
+) -> Result<Option<DateTimeMatch>, EventParseError> {
+    let found_date = find_date_with_options(s, &options);
+    if found_date.is_none() {
+        if let Some((relative, start, end)) = find_relative_time(s) {
+            let located = |e: EventParseError| e.located(&s[start..end], start);
+            let datetime = now
+                .checked_add(relative.as_span().map_err(located)?)
+                .map_err(|_e| located(EventParseError::ambiguous_time()))?
+                .datetime();
+            let datetime = options
+                .round_to
+                .map_or(Ok(datetime), |round_to| round_datetime(datetime, round_to))
+                .map_err(located)?;
+            return Ok(Some(DateTimeMatch {
+                date: datetime.date(),
+                time: Some(datetime.time()),
+                day_part: None,
+                duration: None,
+                time_zone: None,
+                approximate: false,
+                ambiguous_date: false,
+                short_year: false,
+                skipped_words: 0,
+                explicit_all_day: false,
+                recurrence: None,
+                start_char: start,
+                end_char: end,
+                date_end_char: start,
+                time_chars: Some((start, end)),
+            }));
+        }
+        if let Some((part, start, end)) = find_day_part_today(s) {
+            // "tonight" is today even when the night has already begun
+            let datetime = now.date().to_datetime(options.day_parts.time_of(part));
+            let datetime = options
+                .round_to
+                .map_or(Ok(datetime), |round_to| round_datetime(datetime, round_to))
+                .map_err(|e| e.located(&s[start..end], start))?;
+            return Ok(Some(DateTimeMatch {
+                date: datetime.date(),
+                time: Some(datetime.time()),
+                day_part: Some(part),
+                duration: None,
+                time_zone: None,
+                approximate: false,
+                ambiguous_date: false,
+                short_year: false,
+                skipped_words: 0,
+                explicit_all_day: false,
+                recurrence: None,
+                start_char: start,
+                end_char: end,
+                date_end_char: start,
+                time_chars: Some((start, end)),
+            }));
+        }
+    }
+    // "Gym every other monday 18:00" starts on the next monday, "Standup daily 9:00" today
+    let found_recurrence = found_date.is_none().then(|| find_recurrence(s)).flatten();
+    let recurrence = found_recurrence.map(|(recurrence, ..)| recurrence);
+    let found_date = found_date.or_else(|| {
+        let (_, weekday, start, end) = found_recurrence?;
+        let lang = date::DateRelativeLanguage::English;
+        let day = weekday.map_or(DateRelative::Today(lang), |weekday| {
+            DateRelative::Weekday(lang, weekday)
+        });
+        Some((DateUnit::Relative(day), start, end))
+    });
+    // "Party saturday 19:00", only when there is nothing more specific
+    let found_date = found_date.or_else(|| find_weekday(s));
+    let date_was_matched = found_date.is_some();
+    // "Palaveri kello 16.30" is today
+    let clock_time_today = || {
+        find_clock_time_start(s, &options).map(|start| {
+            let today = DateRelative::Today(date::DateRelativeLanguage::Finnish);
+            (DateUnit::Relative(today), start, start)
+        })
+    };
+    if let Some((date, date_start, date_end)) = found_date.or_else(clock_time_today).or_else(|| {
         default_date.then_some((
             DateUnit::Relative(DateRelative::Today(date::DateRelativeLanguage::English)),
             0,
             0,
         ))
     }) {
-        let (_, s_after_date) = s.split_at(date_end);
-
-        let date = date.as_date(now)?;
+        let ambiguous_date = date.is_ambiguous();
+        if ambiguous_date && options.strict_dates {
+            return Err(EventParseError::AmbiguousTime {
+                text: s[date_start..date_end].to_owned(),
+                at: date_start,
+            });
+        }
+        // Finnish dates may be followed by Finnish times, as in "18.11. 16.30"
+        let options = ParserOptions {
+            dot_times: options.dot_times || date.is_finnish(),
+            ..options
+        };
+        let date = date.in_order(options.date_order);
+        let short_year = date.has_short_year();
+        let date = date.with_year_pivot(options.two_digit_year_pivot);
+        let located_date = |e: EventParseError| e.located(&s[date_start..date_end], date_start);
+        let last_date = date.last_date(now.clone()).map_err(located_date)?;
+        let mut date = date.as_date(now.clone()).map_err(located_date)?;
         let mut end = date_end;
-        let time = if let Some((time, _time_start, time_end)) = find_time(s_after_date) {
+        // "from monday until friday", unless the date is a range of days already
+        let date_range_end = last_date
+            .is_none()
+            .then(|| find_date_range_end(&s[date_end..], &options))
+            .flatten();
+        let last_date = match date_range_end {
+            Some((last_unit, last_offset)) => {
+                end += last_offset;
+                let range_text = s[date_start..end].trim_end();
+                let last = last_unit
+                    .resolve(date, now, &options)
+                    .map_err(|e| e.located(range_text, date_start))?;
+                if last < date {
+                    return Err(EventParseError::InvalidDateRange {
+                        text: range_text.to_owned(),
+                        at: date_start,
+                    });
+                }
+                Some(last)
+            }
+            None => last_date,
+        };
+        let (_, s_after_date) = s.split_at(end);
+
+        let mut day_part = None;
+        // Number of days after the first one in a range of days
+        let extra_days = last_date
+            .map(|last_date| date.until(last_date).map(|span| span.get_days()))
+            .transpose()
+            .map_err(|_e| {
+                EventParseError::ambiguous_duration().located(&s[date_start..end], date_start)
+            })?;
+        // A range of days lasts until the end of its last day
+        let mut duration = extra_days.map(|days| (days + 1).days());
+        let mut time_zone = None;
+        let mut approximate = false;
+        let mut skipped_words = 0;
+        // "18.11. all day" has no time by definition
+        let all_day_marker = find_all_day_marker(s_after_date);
+        if let Some(marker_end) = all_day_marker {
+            end += marker_end;
+        }
+        // Only the text after the date is searched for a time, so ambiguous tokens such as "16.30"
+        // that follow a date are always tried as a time first
+        let found_time = all_day_marker
+            .is_none()
+            .then(|| find_time_with_options(s_after_date, &options))
+            .flatten();
+        let date_end_char = end;
+        let mut time_chars = None;
+        let time = if let Some((time, time_start, time_end)) = found_time {
             end += time_end;
-            Some(time.as_time()?)
+            let time_at = date_end_char + time_start;
+            let located_time = |e: EventParseError, located_end: usize| {
+                e.located(&s[time_at..located_end], time_at)
+            };
+            skipped_words = count_skipped_words(&s_after_date[..time_start]);
+            // "between 2 and 3" is a range as well
+            let after_between = s_after_date[time_start..time_end]
+                .split(' ')
+                .next()
+                .is_some_and(|word| word.eq_ignore_ascii_case("between"));
+            if let Some(marker_end) = find_approximate_marker(
+                &s_after_date[..time_start],
+                &s_after_date[time_start..time_end],
+                &s_after_date[time_end..],
+            ) {
+                end += marker_end;
+                approximate = true;
+            }
+            if matches!(time, TimeUnit::Named(TimeNamed::Midnight(_)))
+                && options.midnight == MidnightConvention::EndOfDay
+            {
+                date = date
+                    .checked_add(1.day())
+                    .map_err(|_e| located_time(EventParseError::ambiguous_time(), end))?;
+            }
+            if let TimeUnit::DayPart(part) = time {
+                day_part = Some(part);
+            }
+            let mut start_time = time
+                .as_time(&options)
+                .map_err(|e| located_time(e, date_end_char + time_end))?;
+
+            // Bare hour ranges such as "11-13" could just as well be something else, such as a
+            // phone number, unless they follow a date
+            if date_was_matched || !matches!(time, TimeUnit::Structured(TimeStructured::H(_))) {
+                let (_, s_after_time) = s.split_at(end);
+                if let Some((range_end, range_end_offset)) =
+                    find_range_end(s_after_time, after_between)
+                {
+                    end += range_end_offset;
+                    let located_range = |e| located_time(e, end);
+                    start_time = share_meridiem(time, &range_end, &options)
+                        .as_time(&options)
+                        .map_err(located_range)?;
+                    let range_end = range_end.as_time(&options).map_err(located_range)?;
+                    let range = range_duration(start_time, range_end).map_err(located_range)?;
+                    // A time range on a range of days ends on the last day, as in
+                    // "18.-20.11. 9-17"
+                    duration = Some(extra_days.map_or(range, |days| range.days(days)));
+                }
+            }
+            let (_, s_after_range) = s.split_at(end);
+            if let Some((annotation, time_zone_end)) = find_time_zone(s_after_range) {
+                end += time_zone_end;
+                time_zone = Some(annotation);
+            }
+            time_chars = Some((date_end_char + time_start, end));
+            if duration.is_none() {
+                let (_, s_after_zone) = s.split_at(end);
+                if let Some((found_duration, duration_end)) =
+                    find_compact_duration(s_after_zone).or_else(|| find_duration(s_after_zone))
+                {
+                    end += duration_end;
+                    duration = Some(found_duration);
+                }
+            }
+            if let Some((second_start, second_end)) = options
+                .strict_times
+                .then(|| find_second_time(&s[end..], &options))
+                .flatten()
+            {
+                return Err(EventParseError::AmbiguousTime {
+                    text: s[end + second_start..end + second_end].to_owned(),
+                    at: end + second_start,
+                });
+            }
+            if let (Some(round_to), true) = (options.round_to, day_part.is_some() || approximate) {
+                let rounded = round_datetime(date.to_datetime(start_time), round_to)
+                    .map_err(|e| located_time(e, end))?;
+                date = rounded.date();
+                start_time = rounded.time();
+            }
+            Some(start_time)
         } else {
             None
         };
+        // "Vacation tomorrow for 2 weeks", only whole days make sense without a time
+        if time.is_none() && duration.is_none() && all_day_marker.is_none() {
+            if let Some((found_duration, duration_end)) =
+                find_duration(s_after_date).filter(|(found, _)| {
+                    found.get_hours() == 0 && found.get_minutes() == 0 && found.get_seconds() == 0
+                })
+            {
+                end += duration_end;
+                duration = Some(found_duration);
+            }
+        }
         return Ok(Some(DateTimeMatch {
             date,
             time,
+            day_part,
+            duration,
+            time_zone,
+            approximate,
+            ambiguous_date,
+            short_year,
+            skipped_words,
+            explicit_all_day: all_day_marker.is_some() || (extra_days.is_some() && time.is_none()),
+            recurrence,
             start_char: date_start,
             end_char: end,
+            date_end_char,
+            time_chars,
         }));
     }
     Ok(None)
 }
 
+/// Words that may join a second start time to the first one, as in "10:00 and 18:00"
+const TIME_CONNECTORS: [&str; 4] = ["and", "ja", "&", ","];
+
+/// Finds another time at the start of `s_after_time`, possibly after a connector such as "and".
+/// Returns the byte offsets of the second time in `s_after_time`.
+fn find_second_time(s_after_time: &str, options: &ParserOptions) -> Option<(usize, usize)> {
+    let mut rest = s_after_time.trim_start();
+    if let Some(connector) = TIME_CONNECTORS.iter().find(|connector| {
+        rest.get(..connector.len())
+            .is_some_and(|word| word.eq_ignore_ascii_case(connector))
+    }) {
+        rest = rest[connector.len()..].trim_start();
+    }
+    let offset = s_after_time.len() - rest.len();
+    find_time_with_options(rest, options)
+        .filter(|(_, start, _)| *start == 0)
+        .map(|(_, _, end)| (offset, offset + end))
+}
+
+/// Computes the length of a time range, assuming that the end is on the following day if it isn't
+/// after the start
+fn range_duration(start: Time, end: Time) -> Result<Span, EventParseError> {
+    let duration = start
+        .until(end)
+        .map_err(|_e| EventParseError::ambiguous_duration())?;
+    if end > start {
+        Ok(duration)
+    } else {
+        duration
+            .checked_add(24.hours())
+            .map_err(|_e| EventParseError::ambiguous_duration())
+    }
+}
+
+/// Normalizes a word for comparison against the lowercase words of each language: accents are
+/// composed (NFC), so that a "lunedì" typed with a combining accent matches, and case is folded
+pub fn normalize_word(word: &str) -> String {
+    if word.is_ascii() {
+        return word.to_ascii_lowercase();
+    }
+    word.nfc().collect::<String>().to_lowercase()
+}
+
+/// Rounds `datetime` to the nearest multiple of `round_to` counted from midnight, rolling over to
+/// the next day if needed. Spans that aren't positive leave `datetime` as is.
+fn round_datetime(datetime: DateTime, round_to: Span) -> Result<DateTime, EventParseError> {
+    let increment = SignedDuration::try_from(round_to)
+        .map_err(|_e| EventParseError::ambiguous_duration())?
+        .as_nanos();
+    if increment <= 0 {
+        return Ok(datetime);
+    }
+    let since_midnight = Time::midnight().duration_until(datetime.time()).as_nanos();
+    let rounded = (since_midnight + increment / 2) / increment * increment;
+    let rounded = i64::try_from(rounded).map_err(|_e| EventParseError::ambiguous_duration())?;
+    datetime
+        .date()
+        .to_datetime(Time::midnight())
+        .checked_add(SignedDuration::from_nanos(rounded))
+        .map_err(|_e| EventParseError::ambiguous_time())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -74,6 +441,7 @@ mod tests {
             time,
             start_char,
             end_char,
+            ..
         } = find_datetime("21.11.2004", now, false)
             .expect("parse failed")
             .expect("no parse result");
@@ -92,6 +460,7 @@ mod tests {
             time,
             start_char,
             end_char,
+            ..
         } = find_datetime("22.9.1999 11:00", now, false)
             .expect("parse failed")
             .expect("no parse result");
@@ -112,6 +481,7 @@ mod tests {
             time,
             start_char,
             end_char,
+            ..
         } = find_datetime("22.9.1999 11", now, false)
             .expect("parse failed")
             .expect("no parse result");
@@ -132,6 +502,7 @@ mod tests {
             time,
             start_char,
             end_char,
+            ..
         } = find_datetime("22.9. 11", now, false)
             .expect("parse failed")
             .expect("no parse result");
@@ -152,6 +523,7 @@ mod tests {
             time,
             start_char,
             end_char,
+            ..
         } = find_datetime("22.1. 11", now, false)
             .expect("parse failed")
             .expect("no parse result");
@@ -173,6 +545,7 @@ mod tests {
             time,
             start_char,
             end_char,
+            ..
         } = find_datetime("tomorrow 0:30:12", now, false)
             .expect("parse failed")
             .expect("no parse result");
@@ -195,6 +568,7 @@ mod tests {
             time,
             start_char,
             end_char,
+            ..
         } = find_datetime("next monday 0:30:12", now, false)
             .expect("parse failed")
             .expect("no parse result");
@@ -216,6 +590,7 @@ mod tests {
             time,
             start_char,
             end_char,
+            ..
         } = find_datetime("last sunday 0:30:12", now, false)
             .expect("parse failed")
             .expect("no parse result");
@@ -237,6 +612,7 @@ mod tests {
             time,
             start_char,
             end_char,
+            ..
         } = find_datetime("last wednesday 0:30:12", now, false)
             .expect("parse failed")
             .expect("no parse result");
@@ -250,4 +626,330 @@ mod tests {
         assert_eq!(time.minute(), 30);
         assert_eq!(time.second(), 12);
     }
+
+    #[test]
+    fn datetime_dot_time_a() {
+        let now = jiff::civil::date(2024, 6, 1).in_tz("UTC").unwrap();
+        let DateTimeMatch {
+            date,
+            time,
+            start_char,
+            end_char,
+            ..
+        } = find_datetime("Palaveri huomenna 16.30", now, false)
+            .expect("parse failed")
+            .expect("no parse result");
+        assert_eq!(start_char, 9);
+        assert_eq!(end_char, 23);
+        assert_eq!(date, jiff::civil::date(2024, 6, 2));
+        assert_eq!(time, Some(jiff::civil::time(16, 30, 0, 0)));
+    }
+    #[test]
+    fn datetime_dot_time_b() {
+        let now = jiff::civil::date(2024, 6, 1).in_tz("UTC").unwrap();
+        let DateTimeMatch {
+            date,
+            time,
+            start_char,
+            end_char,
+            ..
+        } = find_datetime("18.11. 16.30", now, false)
+            .expect("parse failed")
+            .expect("no parse result");
+        assert_eq!(start_char, 0);
+        assert_eq!(end_char, 12);
+        assert_eq!(date, jiff::civil::date(2024, 11, 18));
+        assert_eq!(time, Some(jiff::civil::time(16, 30, 0, 0)));
+    }
+
+    #[test]
+    fn datetime_dot_time_english() {
+        let now = jiff::civil::date(2024, 6, 1).in_tz("UTC").unwrap();
+        let DateTimeMatch { date, time, .. } =
+            find_datetime("Meeting tomorrow 16.30", now.clone(), false)
+                .expect("parse failed")
+                .expect("no parse result");
+        assert_eq!(date, jiff::civil::date(2024, 6, 2));
+        assert_eq!(time, None);
+        let DateTimeMatch { time: klo_time, .. } =
+            find_datetime("Meeting tomorrow klo 11.30", now.clone(), false)
+                .expect("parse failed")
+                .expect("no parse result");
+        assert_eq!(klo_time, Some(jiff::civil::time(11, 30, 0, 0)));
+        let options = ParserOptions {
+            dot_times: true,
+            ..Default::default()
+        };
+        let DateTimeMatch { time: dot_time, .. } =
+            find_datetime_with_options("Meeting tomorrow 16.30", now, false, options)
+                .expect("parse failed")
+                .expect("no parse result");
+        assert_eq!(dot_time, Some(jiff::civil::time(16, 30, 0, 0)));
+    }
+
+    #[test]
+    fn datetime_spoken() {
+        let now = jiff::civil::date(2024, 6, 1).in_tz("UTC").unwrap();
+        let DateTimeMatch {
+            date,
+            time,
+            start_char,
+            end_char,
+            ..
+        } = find_datetime("Call mom tomorrow quarter to six, home", now, false)
+            .expect("parse failed")
+            .expect("no parse result");
+        assert_eq!(start_char, 9);
+        assert_eq!(end_char, 32);
+        assert_eq!(date, jiff::civil::date(2024, 6, 2));
+        assert_eq!(time, Some(jiff::civil::time(5, 45, 0, 0)));
+    }
+
+    #[test]
+    fn datetime_range_a() {
+        let now = jiff::civil::date(2024, 6, 1).in_tz("UTC").unwrap();
+        let DateTimeMatch {
+            date,
+            time,
+            duration,
+            start_char,
+            end_char,
+            ..
+        } = find_datetime("Design review tomorrow 11:00-12:30 @ A769", now, false)
+            .expect("parse failed")
+            .expect("no parse result");
+        assert_eq!(start_char, 14);
+        assert_eq!(end_char, 34);
+        assert_eq!(date, jiff::civil::date(2024, 6, 2));
+        assert_eq!(time, Some(jiff::civil::time(11, 0, 0, 0)));
+        assert_eq!(
+            duration.map(Span::fieldwise),
+            Some(1.hour().minutes(30).fieldwise())
+        );
+    }
+    #[test]
+    fn datetime_range_b() {
+        let now = jiff::civil::date(2024, 6, 1).in_tz("UTC").unwrap();
+        let DateTimeMatch {
+            time,
+            duration,
+            end_char,
+            ..
+        } = find_datetime("Deploy 2.6. 23:00 – 1:00", now, false)
+            .expect("parse failed")
+            .expect("no parse result");
+        assert_eq!(end_char, 26);
+        assert_eq!(time, Some(jiff::civil::time(23, 0, 0, 0)));
+        assert_eq!(duration.map(Span::fieldwise), Some(2.hours().fieldwise()));
+    }
+    #[test]
+    fn datetime_range_c() {
+        let now = jiff::civil::date(2024, 6, 1).in_tz("UTC").unwrap();
+        let DateTimeMatch {
+            time,
+            duration,
+            end_char,
+            ..
+        } = find_datetime("Lunch tomorrow 11:30 to 12:15, Cafeteria", now, false)
+            .expect("parse failed")
+            .expect("no parse result");
+        assert_eq!(end_char, 29);
+        assert_eq!(time, Some(jiff::civil::time(11, 30, 0, 0)));
+        assert_eq!(
+            duration.map(Span::fieldwise),
+            Some(45.minutes().fieldwise())
+        );
+    }
+    #[test]
+    fn datetime_range_bare_hours() {
+        let now = jiff::civil::date(2024, 6, 1).in_tz("UTC").unwrap();
+        let DateTimeMatch {
+            date,
+            time,
+            duration,
+            start_char,
+            end_char,
+            ..
+        } = find_datetime("Workshop next friday 11-13 @ Aula", now, false)
+            .expect("parse failed")
+            .expect("no parse result");
+        assert_eq!(start_char, 9);
+        assert_eq!(end_char, 26);
+        assert_eq!(date, jiff::civil::date(2024, 6, 7));
+        assert_eq!(time, Some(jiff::civil::time(11, 0, 0, 0)));
+        assert_eq!(duration.map(Span::fieldwise), Some(2.hours().fieldwise()));
+    }
+    #[test]
+    fn datetime_range_bare_hours_without_date() {
+        let now = jiff::civil::date(2024, 6, 1).in_tz("UTC").unwrap();
+        let DateTimeMatch {
+            time,
+            duration,
+            end_char,
+            ..
+        } = find_datetime("Workshop 11-13", now, true)
+            .expect("parse failed")
+            .expect("no parse result");
+        assert_eq!(end_char, 11);
+        assert_eq!(time, Some(jiff::civil::time(11, 0, 0, 0)));
+        assert!(duration.is_none());
+    }
+    #[test]
+    fn datetime_no_range() {
+        let now = jiff::civil::date(2024, 6, 1).in_tz("UTC").unwrap();
+        let DateTimeMatch {
+            duration, end_char, ..
+        } = find_datetime("Lunch tomorrow 11:30 with Tom", now, false)
+            .expect("parse failed")
+            .expect("no parse result");
+        assert_eq!(end_char, 20);
+        assert!(duration.is_none());
+    }
+
+    #[test]
+    fn datetime_relative_time_a() {
+        let now = jiff::civil::date(2024, 6, 1)
+            .at(23, 50, 0, 0)
+            .in_tz("UTC")
+            .unwrap();
+        let DateTimeMatch {
+            date,
+            time,
+            start_char,
+            end_char,
+            ..
+        } = find_datetime("Take the pizza out in 25 minutes", now, false)
+            .expect("parse failed")
+            .expect("no parse result");
+        assert_eq!(start_char, 19);
+        assert_eq!(end_char, 32);
+        assert_eq!(date, jiff::civil::date(2024, 6, 2));
+        assert_eq!(time, Some(jiff::civil::time(0, 15, 0, 0)));
+    }
+    #[test]
+    fn datetime_relative_time_b() {
+        let now = jiff::civil::date(2024, 6, 1)
+            .at(13, 14, 0, 0)
+            .in_tz("UTC")
+            .unwrap();
+        let DateTimeMatch { date, time, .. } =
+            find_datetime("Soita takaisin 2 tunnin päästä", now, false)
+                .expect("parse failed")
+                .expect("no parse result");
+        assert_eq!(date, jiff::civil::date(2024, 6, 1));
+        assert_eq!(time, Some(jiff::civil::time(15, 14, 0, 0)));
+    }
+
+    #[test]
+    fn datetime_named_noon() {
+        let now = jiff::civil::date(2024, 6, 1).in_tz("UTC").unwrap();
+        let DateTimeMatch {
+            date,
+            time,
+            start_char,
+            end_char,
+            ..
+        } = find_datetime("Lunch tomorrow at noon", now, false)
+            .expect("parse failed")
+            .expect("no parse result");
+        assert_eq!(start_char, 6);
+        assert_eq!(end_char, 22);
+        assert_eq!(date, jiff::civil::date(2024, 6, 2));
+        assert_eq!(time, Some(jiff::civil::time(12, 0, 0, 0)));
+    }
+    #[test]
+    fn datetime_named_midnight_start_of_day() {
+        let now = jiff::civil::date(2024, 6, 1).in_tz("UTC").unwrap();
+        let DateTimeMatch {
+            date,
+            time,
+            start_char,
+            end_char,
+            ..
+        } = find_datetime("maintenance window tomorrow midnight", now, false)
+            .expect("parse failed")
+            .expect("no parse result");
+        assert_eq!(start_char, 19);
+        assert_eq!(end_char, 36);
+        assert_eq!(date, jiff::civil::date(2024, 6, 2));
+        assert_eq!(time, Some(jiff::civil::time(0, 0, 0, 0)));
+    }
+    #[test]
+    fn datetime_named_midnight_end_of_day() {
+        let now = jiff::civil::date(2024, 6, 30).in_tz("UTC").unwrap();
+        let options = ParserOptions {
+            midnight: MidnightConvention::EndOfDay,
+            ..Default::default()
+        };
+        let DateTimeMatch { date, time, .. } =
+            find_datetime_with_options("huolto huomenna keskiyöllä", now, false, options)
+                .expect("parse failed")
+                .expect("no parse result");
+        assert_eq!(date, jiff::civil::date(2024, 7, 2));
+        assert_eq!(time, Some(jiff::civil::time(0, 0, 0, 0)));
+    }
+    #[test]
+    fn normalize_word_composes_and_lowercases() {
+        assert_eq!(normalize_word("LUNEDI\u{300}"), "lunedì");
+        assert_eq!(normalize_word("Tänään"), "tänään");
+        assert_eq!(normalize_word("Monday"), "monday");
+    }
+    #[test]
+    fn round_datetime_across_hour() {
+        let datetime = jiff::civil::date(2024, 6, 1).at(9, 58, 0, 0);
+        assert_eq!(
+            round_datetime(datetime, 5.minutes()),
+            Ok(jiff::civil::date(2024, 6, 1).at(10, 0, 0, 0))
+        );
+        assert_eq!(
+            round_datetime(datetime, 15.minutes()),
+            Ok(jiff::civil::date(2024, 6, 1).at(10, 0, 0, 0))
+        );
+    }
+    #[test]
+    fn round_datetime_down() {
+        let datetime = jiff::civil::date(2024, 6, 1).at(9, 7, 0, 0);
+        assert_eq!(
+            round_datetime(datetime, 15.minutes()),
+            Ok(jiff::civil::date(2024, 6, 1).at(9, 0, 0, 0))
+        );
+    }
+    #[test]
+    fn round_datetime_across_day() {
+        let datetime = jiff::civil::date(2024, 6, 30).at(23, 53, 0, 0);
+        assert_eq!(
+            round_datetime(datetime, 15.minutes()),
+            Ok(jiff::civil::date(2024, 7, 1).at(0, 0, 0, 0))
+        );
+    }
+    #[test]
+    fn datetime_relative_rounded() {
+        let now = jiff::civil::date(2024, 6, 1)
+            .at(9, 51, 0, 0)
+            .in_tz("UTC")
+            .unwrap();
+        let options = ParserOptions {
+            round_to: Some(5.minutes()),
+            ..Default::default()
+        };
+        let DateTimeMatch { date, time, .. } =
+            find_datetime_with_options("Call in 7 minutes", now, false, options)
+                .expect("parse failed")
+                .expect("no parse result");
+        assert_eq!(date, jiff::civil::date(2024, 6, 1));
+        assert_eq!(time, Some(jiff::civil::time(10, 0, 0, 0)));
+    }
+    #[test]
+    fn datetime_exact_not_rounded() {
+        let now = jiff::civil::date(2024, 6, 1).in_tz("UTC").unwrap();
+        let options = ParserOptions {
+            round_to: Some(15.minutes()),
+            ..Default::default()
+        };
+        let DateTimeMatch { time, .. } =
+            find_datetime_with_options("Call tomorrow 9:07", now, false, options)
+                .expect("parse failed")
+                .expect("no parse result");
+        assert_eq!(time, Some(jiff::civil::time(9, 7, 0, 0)));
+    }
 }
@@ -0,0 +1,155 @@
+//! Repeating events, as in "Gym every other monday 18:00"
+
+use lazy_regex::{lazy_regex, Lazy, Regex};
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+
+use crate::temporal::{
+    date::{DateRelativeLanguage, DateRelativeWeekday},
+    normalize_word,
+};
+
+/// How often an event repeats, see [`Recurrence`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "wasm", derive(tsify::Tsify))]
+pub enum Frequency {
+    Daily,
+    Weekly,
+}
+
+/// A repeating event, starting from the date of the event.
+/// Weekly events repeat on the weekday of their first occurrence.
+/// ```rust
+/// use jiff::civil::date;
+/// use nlcep::{Frequency, NewEvent, Recurrence};
+/// let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+/// let event = NewEvent::parse_at_time("Gym every other monday 18:00", now).unwrap();
+/// assert_eq!(event.summary, "Gym");
+/// assert_eq!(event.datetime(), date(2024, 6, 3).at(18, 0, 0, 0));
+/// let recurrence = event.recurrence.unwrap();
+/// assert_eq!(recurrence, Recurrence { frequency: Frequency::Weekly, interval: 2 });
+/// assert_eq!(recurrence.rrule(), "FREQ=WEEKLY;INTERVAL=2");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "wasm", derive(tsify::Tsify))]
+pub struct Recurrence {
+    pub frequency: Frequency,
+    /// Number of days or weeks between occurrences, 2 for "every other monday"
+    pub interval: u32,
+}
+impl Recurrence {
+    /// The recurrence as an iCalendar (RFC 5545) RRULE value, as in "FREQ=WEEKLY;INTERVAL=2".
+    /// The interval is left out when it is 1, which is its default.
+    pub fn rrule(&self) -> String {
+        let frequency = match self.frequency {
+            Frequency::Daily => "DAILY",
+            Frequency::Weekly => "WEEKLY",
+        };
+        if self.interval == 1 {
+            return format!("FREQ={frequency}");
+        }
+        format!("FREQ={frequency};INTERVAL={}", self.interval)
+    }
+}
+
+/// Matches a recurrence phrase such as "every other monday", "every 3 days" or "weekly".
+/// "daily" and "weekly" starting the input describe the summary, as in "Weekly review monday".
+static RECURRENCE: Lazy<Regex> = lazy_regex!(
+    r"(?:(?:^|\s)(?P<phrase>(?i:every\s+(?:(?P<other>other)\s+|(?P<count>\d{1,3})\s+)?(?P<unit>\p{L}+)))|\s(?P<adverb>(?i:daily|weekly)))(?:$|[\s,@(])"
+);
+
+/// Tries to find a phrase making the event repeat, in English:
+/// - "daily", "every day", "every 3 days"
+/// - "weekly", "every week", "every other week", "every 2 weeks"
+/// - "every monday", "every other monday"
+///
+/// Returns the recurrence, the weekday it is on if one was given, and the start and end offsets
+/// of the phrase.
+pub fn find_recurrence(s: &str) -> Option<(Recurrence, Option<DateRelativeWeekday>, usize, usize)> {
+    RECURRENCE.captures_iter(s).find_map(|captures| {
+        let phrase = captures
+            .name("phrase")
+            .or_else(|| captures.name("adverb"))?;
+        let (recurrence, weekday) = if let Some(adverb) = captures.name("adverb") {
+            let frequency = if adverb.as_str().eq_ignore_ascii_case("daily") {
+                Frequency::Daily
+            } else {
+                Frequency::Weekly
+            };
+            (
+                Recurrence {
+                    frequency,
+                    interval: 1,
+                },
+                None,
+            )
+        } else {
+            let interval = match (captures.name("other"), captures.name("count")) {
+                (Some(_), _) => 2,
+                (None, Some(count)) => count.as_str().parse().ok().filter(|n| *n > 0)?,
+                (None, None) => 1,
+            };
+            let unit = normalize_word(captures.name("unit")?.as_str());
+            let weekday = DateRelativeWeekday::iter().find(|weekday| {
+                weekday.to_locale_static_str(DateRelativeLanguage::English) == unit
+            });
+            let frequency = match unit.as_str() {
+                "day" | "days" => Frequency::Daily,
+                "week" | "weeks" => Frequency::Weekly,
+                // "every 3 mondays" isn't a thing
+                _ if weekday.is_some() && captures.name("count").is_none() => Frequency::Weekly,
+                _ => return None,
+            };
+            (
+                Recurrence {
+                    frequency,
+                    interval,
+                },
+                weekday,
+            )
+        };
+        Some((recurrence, weekday, phrase.start(), phrase.end()))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_recurrence_every_other_weekday() {
+        let weekly = Recurrence {
+            frequency: Frequency::Weekly,
+            interval: 2,
+        };
+        assert_eq!(
+            find_recurrence("Gym every other monday 18:00"),
+            Some((weekly, Some(DateRelativeWeekday::Monday), 4, 22))
+        );
+    }
+
+    #[test]
+    fn find_recurrence_every_n_days() {
+        let daily = Recurrence {
+            frequency: Frequency::Daily,
+            interval: 3,
+        };
+        assert_eq!(
+            find_recurrence("Water plants every 3 days"),
+            Some((daily, None, 13, 25))
+        );
+        assert_eq!(find_recurrence("Water plants every 0 days"), None);
+        assert_eq!(find_recurrence("Gym every 3 mondays"), None);
+    }
+
+    #[test]
+    fn find_recurrence_adverbs() {
+        let (daily, _, start, end) = find_recurrence("Standup Daily 9:00").unwrap();
+        assert_eq!(daily.rrule(), "FREQ=DAILY");
+        assert_eq!((start, end), (8, 13));
+        let (weekly, ..) = find_recurrence("Sync weekly, Zoom").unwrap();
+        assert_eq!(weekly.rrule(), "FREQ=WEEKLY");
+        assert_eq!(find_recurrence("Weekly review monday 10:00"), None);
+        assert_eq!(find_recurrence("Everyday carry"), None);
+    }
+}
@@ -1,15 +1,25 @@
-use std::str::FromStr;
+use core::str::FromStr;
 
-use jiff::civil::Time;
+use jiff::{
+    civil::Time,
+    tz::{Offset, TimeZone},
+    Span, ToSpan,
+};
+use lazy_regex::{lazy_regex, Lazy, Regex};
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
 
-use crate::EventParseError;
+use crate::{
+    temporal::{date::DateRelativeLanguage, normalize_word},
+    EventParseError, MeridiemInference, ParserOptions,
+};
 
 pub trait AsTime {
-    fn as_time(&self) -> Result<Time, EventParseError>;
+    fn as_time(&self, options: &ParserOptions) -> Result<Time, EventParseError>;
 }
 
 /// "Regularly formatted" time formats
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum TimeStructured {
     /// Hours
     H(i8),
@@ -17,18 +27,41 @@ pub enum TimeStructured {
     Hm(i8, i8),
     /// Hours, Minutes, Seconds
     Hms(i8, i8, i8),
+    /// Hours, Minutes, Seconds, Nanoseconds
+    Hmsn(i8, i8, i8, i32),
 }
 impl FromStr for TimeStructured {
     type Err = ();
 
     fn from_str(string: &str) -> Result<Self, Self::Err> {
+        if let Some((hour_segment, minute_segment)) =
+            string.split_once('.').filter(|_| !string.contains(':'))
+        {
+            // Finnish convention, 16.30
+            if minute_segment.len() != 2 {
+                return Err(());
+            }
+            let hours = hour_segment.parse::<i8>().map_err(|_e| ())?;
+            let minutes = minute_segment.parse::<i8>().map_err(|_e| ())?;
+            return Ok(Self::Hm(hours, minutes));
+        }
+
         let mut split_by_colon = string.split(':');
-        let hours = split_by_colon.next().ok_or(())?.parse::<i8>().map_err(|_e| ())?;
+        let hours = split_by_colon
+            .next()
+            .ok_or(())?
+            .parse::<i8>()
+            .map_err(|_e| ())?;
 
         if let Some(minute_segment) = split_by_colon.next().filter(|s| !s.is_empty()) {
             let minutes = minute_segment.parse::<i8>().map_err(|_e| ())?;
 
             if let Some(second_segment) = split_by_colon.next().filter(|s| !s.is_empty()) {
+                if let Some((second_segment, fraction_segment)) = second_segment.split_once('.') {
+                    let seconds = second_segment.parse::<i8>().map_err(|_e| ())?;
+                    let nanoseconds = parse_fraction_as_nanoseconds(fraction_segment).ok_or(())?;
+                    return Ok(Self::Hmsn(hours, minutes, seconds, nanoseconds));
+                }
                 let seconds = second_segment.parse::<i8>().map_err(|_e| ())?;
 
                 return Ok(Self::Hms(hours, minutes, seconds));
@@ -40,53 +73,1199 @@ impl FromStr for TimeStructured {
     }
 }
 impl AsTime for TimeStructured {
-    fn as_time(&self) -> Result<Time, EventParseError> {
+    fn as_time(&self, _options: &ParserOptions) -> Result<Time, EventParseError> {
+        match self {
+            TimeStructured::H(h) => {
+                Time::new(*h, 0, 0, 0).map_err(|_e| EventParseError::invalid_time())
+            }
+            TimeStructured::Hm(h, m) => {
+                Time::new(*h, *m, 0, 0).map_err(|_e| EventParseError::invalid_time())
+            }
+            TimeStructured::Hms(h, m, s) => {
+                Time::new(*h, *m, *s, 0).map_err(|_e| EventParseError::invalid_time())
+            }
+            TimeStructured::Hmsn(h, m, s, n) => {
+                Time::new(*h, *m, *s, *n).map_err(|_e| EventParseError::invalid_time())
+            }
+        }
+    }
+}
+
+/// Parses the digits after a decimal point, such as the "500" in "11:00:00.500", as nanoseconds
+fn parse_fraction_as_nanoseconds(fraction: &str) -> Option<i32> {
+    if fraction.is_empty() || fraction.len() > 9 || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let padded = format!("{fraction:0<9}");
+    padded.parse::<i32>().ok()
+}
+
+/// Times of day that are referred to by name
+#[derive(Debug, PartialEq)]
+pub enum TimeNamed {
+    /// 12:00
+    Noon(DateRelativeLanguage),
+    /// 00:00, which day this refers to is controlled by [`crate::MidnightConvention`]
+    Midnight(DateRelativeLanguage),
+    /// "EOD", resolves to [`ParserOptions::end_of_day`]
+    EndOfDay,
+    /// "COB", resolves to [`ParserOptions::close_of_business`]
+    CloseOfBusiness,
+}
+impl FromStr for TimeNamed {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match normalize_word(s).as_str() {
+            "noon" | "midday" => Ok(Self::Noon(DateRelativeLanguage::English)),
+            "keskipäivällä" => Ok(Self::Noon(DateRelativeLanguage::Finnish)),
+
+            "midnight" => Ok(Self::Midnight(DateRelativeLanguage::English)),
+            "keskiyöllä" => Ok(Self::Midnight(DateRelativeLanguage::Finnish)),
+
+            "eod" => Ok(Self::EndOfDay),
+            "cob" => Ok(Self::CloseOfBusiness),
+
+            _ => Err(()),
+        }
+    }
+}
+impl AsTime for TimeNamed {
+    fn as_time(&self, options: &ParserOptions) -> Result<Time, EventParseError> {
+        match self {
+            TimeNamed::Noon(_) => Ok(Time::constant(12, 0, 0, 0)),
+            TimeNamed::Midnight(_) => Ok(Time::midnight()),
+            TimeNamed::EndOfDay => Ok(options.end_of_day),
+            TimeNamed::CloseOfBusiness => Ok(options.close_of_business),
+        }
+    }
+}
+
+/// Clock phrases relative to a full hour, such as "ten past nine", "quarter to six",
+/// "seven o'clock" or "half five".
+///
+/// These are 12-hour clock times, so the hour is adjusted according to
+/// [`ParserOptions::meridiem`]. By default it's used as written, "half past five" is 5:30.
+/// "seven o'clock" is a bare hour spelled out, so it follows [`ParserOptions::bare_hour_meridiem`]
+/// instead.
+#[derive(Debug, PartialEq)]
+pub enum TimeSpoken {
+    /// Minutes, Hours
+    Past(i8, i8),
+    /// Minutes, Hours
+    To(i8, i8),
+    /// Hours
+    OClock(i8),
+    /// "half (hour)", which means 30 minutes past the hour in British English ("half five" is
+    /// 5:30) but 30 minutes to it in Finnish ("puoli kuusi" is 5:30 as well).
+    /// Hours as written
+    Half(DateRelativeLanguage, i8),
+}
+impl TimeSpoken {
+    /// Tries to match a clock phrase at the start of `words`.
+    /// usize is the number of words matched
+    fn parse_multiword(words: &[(usize, &str)]) -> Option<(Self, usize)> {
+        let lowercase = |i: usize| words.get(i).map(|(_, word)| normalize_word(word));
+
+        let oclock_hours =
+            |word: &str| parse_hour_word(word).filter(|hours| (1..=12).contains(hours));
+        if matches!(
+            lowercase(1).as_deref(),
+            Some("o'clock" | "o’clock" | "oclock")
+        ) {
+            return Some((Self::OClock(oclock_hours(&lowercase(0)?)?), 2));
+        }
+        // "5o'clock"
+        let first = lowercase(0)?;
+        if let Some(hour_word) = ["o'clock", "o’clock", "oclock"]
+            .into_iter()
+            .find_map(|suffix| first.strip_suffix(suffix))
+        {
+            return Some((Self::OClock(oclock_hours(hour_word)?), 1));
+        }
+
+        let half_language = match lowercase(0)?.as_str() {
+            "half" => Some(DateRelativeLanguage::English),
+            "puoli" => Some(DateRelativeLanguage::Finnish),
+            _ => None,
+        };
+        if let Some(lang) = half_language {
+            let hour_word = lowercase(1)?;
+            let hours = match lang {
+                DateRelativeLanguage::Finnish => parse_finnish_number_word(&hour_word),
+                _ => parse_hour_word(&hour_word),
+            };
+            if let Some(hours) = hours.filter(|hours| (1..=12).contains(hours)) {
+                return Some((Self::Half(lang, hours), 2));
+            }
+        }
+
+        let (minutes, minute_words) = match lowercase(0)?.as_str() {
+            "five" => (5, 1),
+            "ten" => (10, 1),
+            "quarter" => (15, 1),
+            "twenty" if lowercase(1).as_deref() == Some("five") => (25, 2),
+            "twenty" => (20, 1),
+            "half" => (30, 1),
+            // Digits are left out on purpose, "10 to 12" is more likely a range
+            _ => return None,
+        };
+        let relation = lowercase(minute_words)?;
+        let hours = parse_hour_word(&lowercase(minute_words + 1)?)?;
+        let words_matched = minute_words + 2;
+
+        match relation.as_str() {
+            "past" | "after" => Some((Self::Past(minutes, hours), words_matched)),
+            "to" | "before" if minutes != 30 => Some((Self::To(minutes, hours), words_matched)),
+            _ => None,
+        }
+    }
+}
+impl AsTime for TimeSpoken {
+    fn as_time(&self, options: &ParserOptions) -> Result<Time, EventParseError> {
+        let hour = |h: i8| options.meridiem.adjust(h);
+        match self {
+            TimeSpoken::Past(m, h) => {
+                Time::new(hour(*h), *m, 0, 0).map_err(|_e| EventParseError::invalid_time())
+            }
+            TimeSpoken::To(m, h) => {
+                let full_hour =
+                    Time::new(hour(*h), 0, 0, 0).map_err(|_e| EventParseError::invalid_time())?;
+                Ok(full_hour.wrapping_sub(i64::from(*m).minutes()))
+            }
+            TimeSpoken::OClock(h) => {
+                let h = options.bare_hour_meridiem.adjust(*h);
+                Time::new(h, 0, 0, 0).map_err(|_e| EventParseError::invalid_time())
+            }
+            TimeSpoken::Half(DateRelativeLanguage::Finnish, h) => {
+                let full_hour =
+                    Time::new(hour(*h), 0, 0, 0).map_err(|_e| EventParseError::invalid_time())?;
+                Ok(full_hour.wrapping_sub(30.minutes()))
+            }
+            TimeSpoken::Half(_, h) => {
+                Time::new(hour(*h), 30, 0, 0).map_err(|_e| EventParseError::invalid_time())
+            }
+        }
+    }
+}
+
+/// Parses a number written either with digits or as an English word, up to "ninety-nine".
+/// Shared by clock times, relative times ("in ten minutes") and durations ("ninety minutes").
+/// Numbers written with digits may be of any size, as in "in 150 minutes".
+fn parse_number_word(word: &str) -> Option<i64> {
+    // "forty-five"
+    if let Some((tens, ones)) = word.split_once('-') {
+        let tens = parse_number_word(tens).filter(|tens| tens % 10 == 0 && *tens >= 20)?;
+        let ones = parse_number_word(ones).filter(|ones| (1..=9).contains(ones))?;
+        return Some(tens + ones);
+    }
+    let number = match word {
+        "one" => 1,
+        "two" => 2,
+        "three" => 3,
+        "four" => 4,
+        "five" => 5,
+        "six" => 6,
+        "seven" => 7,
+        "eight" => 8,
+        "nine" => 9,
+        "ten" => 10,
+        "eleven" => 11,
+        "twelve" => 12,
+        "thirteen" => 13,
+        "fourteen" => 14,
+        "fifteen" => 15,
+        "sixteen" => 16,
+        "seventeen" => 17,
+        "eighteen" => 18,
+        "nineteen" => 19,
+        "twenty" => 20,
+        "thirty" => 30,
+        "forty" => 40,
+        "fifty" => 50,
+        "sixty" => 60,
+        "seventy" => 70,
+        "eighty" => 80,
+        "ninety" => 90,
+        _ => return word.parse::<i64>().ok().filter(|n| *n >= 0),
+    };
+    Some(number)
+}
+
+/// Parses an hour of a clock phrase written either with digits or as an English word, see
+/// [`parse_number_word`]
+fn parse_hour_word(word: &str) -> Option<i8> {
+    i8::try_from(parse_number_word(word)?).ok()
+}
+
+/// Parses an hour written either with digits or as a Finnish word
+fn parse_finnish_number_word(word: &str) -> Option<i8> {
+    let number = match word {
+        "yksi" => 1,
+        "kaksi" => 2,
+        "kolme" => 3,
+        "neljä" => 4,
+        "viisi" => 5,
+        "kuusi" => 6,
+        "seitsemän" => 7,
+        "kahdeksan" => 8,
+        "yhdeksän" => 9,
+        "kymmenen" => 10,
+        "yksitoista" => 11,
+        "kaksitoista" => 12,
+        _ => return word.parse::<i8>().ok().filter(|n| *n >= 0),
+    };
+    Some(number)
+}
+
+/// Vague times of day, including meals, the clock time they resolve to is configured through
+/// [`crate::DayPartTimes`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "wasm", derive(tsify::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+pub enum DayPart {
+    Morning,
+    Afternoon,
+    Evening,
+    Night,
+    Breakfast,
+    Lunch,
+    Dinner,
+}
+impl DayPart {
+    /// Converts a 12-hour clock hour in this part of the day to the 24-hour clock.
+    /// Hours past 12 are returned as is.
+    const fn hour_of(self, hour: i8) -> i8 {
+        match (self, hour) {
+            (_, 13..) => hour,
+            (Self::Morning | Self::Breakfast, 12) => 0,
+            (Self::Morning | Self::Breakfast, _) => hour,
+            (Self::Lunch, 1..=6) => hour + 12,
+            (Self::Lunch, _) => hour,
+            (Self::Afternoon | Self::Evening | Self::Dinner, 1..=11) => hour + 12,
+            (Self::Afternoon | Self::Evening | Self::Dinner, _) => hour,
+            (Self::Night, 12) => 0,
+            (Self::Night, 6..=11) => hour + 12,
+            (Self::Night, _) => hour,
+        }
+    }
+}
+impl FromStr for DayPart {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match normalize_word(s).as_str() {
+            "morning" | "aamulla" => Ok(Self::Morning),
+            "afternoon" | "iltapäivällä" => Ok(Self::Afternoon),
+            "evening" | "illalla" => Ok(Self::Evening),
+            "night" | "yöllä" => Ok(Self::Night),
+            "breakfast" => Ok(Self::Breakfast),
+            "lunch" | "lunchtime" | "lounasaikaan" => Ok(Self::Lunch),
+            "dinner" => Ok(Self::Dinner),
+            _ => Err(()),
+        }
+    }
+}
+
+/// A 12-hour clock marker written after a time, as in "4pm" or "9:30 a.m."
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Meridiem {
+    Am,
+    Pm,
+}
+impl Meridiem {
+    /// Converts a 12-hour clock hour to the 24-hour clock, `None` if the hour isn't from 1 to 12
+    const fn hour_of(self, hour: i8) -> Option<i8> {
+        match (self, hour) {
+            (_, ..=0 | 13..) => None,
+            (Self::Am, 12) => Some(0),
+            (Self::Am, _) | (Self::Pm, 12) => Some(hour),
+            (Self::Pm, _) => Some(hour + 12),
+        }
+    }
+
+    const fn flipped(self) -> Self {
         match self {
-            TimeStructured::H(h) => Time::new(*h, 0, 0, 0).map_err(|_e| EventParseError::InvalidTime),
-            TimeStructured::Hm(h, m) => Time::new(*h, *m, 0, 0).map_err(|_e| EventParseError::InvalidTime),
-            TimeStructured::Hms(h, m, s) => Time::new(*h, *m, *s, 0).map_err(|_e| EventParseError::InvalidTime),
+            Self::Am => Self::Pm,
+            Self::Pm => Self::Am,
+        }
+    }
+}
+
+/// Splits a trailing am/pm marker off a time token: "4pm", "11:30AM", "9a.m."
+fn split_meridiem(word: &str) -> (&str, Option<Meridiem>) {
+    for (suffix, meridiem) in [
+        ("a.m.", Meridiem::Am),
+        ("p.m.", Meridiem::Pm),
+        ("am", Meridiem::Am),
+        ("pm", Meridiem::Pm),
+    ] {
+        let Some(split) = word.len().checked_sub(suffix.len()) else {
+            continue;
+        };
+        if let (Some(number), Some(marker)) = (word.get(..split), word.get(split..)) {
+            if marker.eq_ignore_ascii_case(suffix) && number.ends_with(|c: char| c.is_ascii_digit())
+            {
+                return (number, Some(meridiem));
+            }
         }
     }
+    (word, None)
+}
+
+/// Parses an am/pm marker written as a separate word, as in "3 pm"
+fn parse_meridiem_word(word: &str) -> Option<Meridiem> {
+    match word.to_lowercase().as_str() {
+        "am" | "a.m." => Some(Meridiem::Am),
+        "pm" | "p.m." => Some(Meridiem::Pm),
+        _ => None,
+    }
+}
+
+/// Attaches an am/pm marker written as a separate word after a clock time, as in "3 pm".
+/// `next_word` is the word following the time and its starting offset.
+/// Returns the time and the offset it ends at, which includes the marker if one was attached.
+fn attach_meridiem_word(
+    unit: TimeUnit,
+    end: usize,
+    next_word: Option<(usize, &str)>,
+) -> (TimeUnit, usize) {
+    let TimeUnit::Structured(structured) = unit else {
+        return (unit, end);
+    };
+    match next_word.and_then(|(start, word)| Some((start + word.len(), parse_meridiem_word(word)?)))
+    {
+        Some((marker_end, meridiem)) => (TimeUnit::Meridiem(structured, meridiem), marker_end),
+        None => (unit, end),
+    }
+}
+
+/// Parses a clock time token, with an optional am/pm marker: "16:30", "4pm", "9"
+fn parse_clock_time(word: &str) -> Option<TimeUnit> {
+    let (number, meridiem) = split_meridiem(word);
+    let structured = number.parse::<TimeStructured>().ok()?;
+    Some(
+        meridiem.map_or(TimeUnit::Structured(structured), |meridiem| {
+            TimeUnit::Meridiem(structured, meridiem)
+        }),
+    )
+}
+
+/// Applies the am/pm marker written only after the end of a range to its start as well, so that
+/// "2-4pm" is 14:00-16:00. If that would put the start after the end, the start is taken to be
+/// on the other side of noon, as in "11-1pm".
+/// Starts with their own marker or past 12 are returned as is.
+pub fn share_meridiem(start: TimeUnit, end: &TimeUnit, options: &ParserOptions) -> TimeUnit {
+    let (TimeUnit::Structured(structured), TimeUnit::Meridiem(_, meridiem)) = (&start, end) else {
+        return start;
+    };
+    let Ok(end_time) = end.as_time(options) else {
+        return start;
+    };
+    let shared = TimeUnit::Meridiem(*structured, *meridiem);
+    match shared.as_time(options) {
+        Ok(start_time) if start_time <= end_time => shared,
+        Ok(_) => TimeUnit::Meridiem(*structured, meridiem.flipped()),
+        Err(_) => start,
+    }
 }
 
 #[derive(Debug, PartialEq)]
 pub enum TimeUnit {
     Structured(TimeStructured),
+    /// A time followed by am/pm, such as "4pm"
+    Meridiem(TimeStructured, Meridiem),
+    Named(TimeNamed),
+    Spoken(TimeSpoken),
+    DayPart(DayPart),
+}
+impl TimeUnit {
+    /// Whether the unit resolves to an actual time of day
+    fn is_valid(&self) -> bool {
+        self.as_time(&ParserOptions::default()).is_ok()
+    }
+}
+impl TimeUnit {
+    /// Places a 12-hour clock time on the 24-hour clock according to the part of the day it was
+    /// said to be in, "7" in the evening is 19:00.
+    /// Invalid times and times that are already past 12 are returned as is.
+    fn in_day_part(self, day_part: DayPart) -> Self {
+        let as_written = ParserOptions {
+            meridiem: MeridiemInference::AsWritten,
+            bare_hour_meridiem: MeridiemInference::AsWritten,
+            ..Default::default()
+        };
+        let Ok(time) = self.as_time(&as_written) else {
+            return self;
+        };
+        let hour = day_part.hour_of(time.hour());
+        let structured = match (time.second(), time.subsec_nanosecond()) {
+            (0, 0) => TimeStructured::Hm(hour, time.minute()),
+            (second, 0) => TimeStructured::Hms(hour, time.minute(), second),
+            (second, nanosecond) => TimeStructured::Hmsn(hour, time.minute(), second, nanosecond),
+        };
+        Self::Structured(structured)
+    }
 }
 impl AsTime for TimeUnit {
-    fn as_time(&self) -> Result<Time, EventParseError> {
+    fn as_time(&self, options: &ParserOptions) -> Result<Time, EventParseError> {
         match self {
-            TimeUnit::Structured(structured) => structured.as_time(),
+            TimeUnit::Structured(TimeStructured::H(hour)) => {
+                let hour = options.bare_hour_meridiem.adjust(*hour);
+                Time::new(hour, 0, 0, 0).map_err(|_e| EventParseError::invalid_time())
+            }
+            TimeUnit::Structured(structured) => structured.as_time(options),
+            TimeUnit::Meridiem(structured, meridiem) => {
+                let time = structured.as_time(options)?;
+                let hour = meridiem
+                    .hour_of(time.hour())
+                    .ok_or_else(EventParseError::invalid_time)?;
+                time.with()
+                    .hour(hour)
+                    .build()
+                    .map_err(|_e| EventParseError::invalid_time())
+            }
+            TimeUnit::Named(named) => named.as_time(options),
+            TimeUnit::Spoken(spoken) => spoken.as_time(options),
+            TimeUnit::DayPart(day_part) => Ok(options.day_parts.time_of(*day_part)),
         }
     }
 }
 
+/// Splits the string into words, keeping track of the byte offset each word starts at.
+/// An `@` only separates a word made of digits from what follows, as in "11:00@Kitchen", since
+/// otherwise it only starts a location at the start of a word and may appear inside one in an
+/// email address.
+fn split_words(s: &str) -> Vec<(usize, &str)> {
+    let mut start = 0;
+    s.split([
+        ' ', ',', // Might indicate that the next word is a location
+        '-', // Might indicate that the next word is a duration
+    ])
+    .map(|word| {
+        let word_start = start;
+        start += word.len() + 1;
+        let numeric = |part: &str| {
+            !part.is_empty()
+                && part
+                    .chars()
+                    .all(|c| c.is_ascii_digit() || c == ':' || c == '.')
+        };
+        match word.split_once('@') {
+            Some((time, _)) if numeric(time) => (word_start, time),
+            _ => (word_start, word),
+        }
+    })
+    .collect()
+}
+
 /// Tries to find a time from the supplied string.
 /// The time can be expressed as
 /// - a (H)H time: 12, 01, 8, ...
 /// - a (H)H:(M)M time: 12:00, 01:30, 8:1, ...
+/// - a (H)H.MM time: 12.00, 16.30, 8.05, ...
 /// - a (H)H:(M)M:(S)S time: 12:00:00, 01:30:1, 8:1:23, ...
-pub fn find_time(s_after_date: &str) -> Option<(TimeUnit, usize, usize)> {
-    let mut start: usize = 0;
-    for c in s_after_date.chars() {
-        match c {
-            ' ' => start += 1,
-            _ => break
-        }
-    }
-    start = start.saturating_sub(1);
-    for word in s_after_date.split([
-        ' ',
-        ',', // Might indicate that the next word is a location
-        '@', // Might indicate that the next word is a location
-        '-'  // Might indicate that the next word is a duration
-    ]) {
+/// - a (H)H:(M)M:(S)S.fff time with up to nanosecond precision: 11:00:00.500, 8:1:23.000001, ...
+/// - a named time: noon, midday, midnight, keskipäivällä, keskiyöllä
+/// - a clock phrase: half past five, quarter to six, ten past nine, ...
+/// - a part of the day: morning, afternoon, evening, night, aamulla, illalla, ...
+///
+/// A structured time may be prefixed with "klo", "kello", "from" or "between", in which case the
+/// prefix is included in the match.
+///
+/// A structured time or a clock phrase may be followed by a part of the day that places it on the
+/// 24-hour clock: 7 in the evening, half past eight in the morning, 11 at night, 7 illalla, ...
+/// The phrase is included in the match. Hours that are already past 12 are kept as they are.
+///
+/// Candidates that are out of range, such as the "99" in "99 Main St", are skipped. If no valid time
+/// is found, the first out of range candidate that was clearly meant as a time ("25:00", "klo 25")
+/// is returned so that it can be reported as invalid.
+///
+/// How ambiguous inputs are interpreted can be tuned through [`ParserOptions`].
+/// Times may be followed by am/pm: 4pm, 11:30AM, 9a.m., 3 pm
+/// With [`ParserOptions::four_digit_times`] enabled, 3 or 4 digit numbers are also accepted as
+/// (H)HMM times: 0930, 1630, 800, ...
+/// With [`ParserOptions::bare_hour_times`] disabled, a bare hour such as "3" is only accepted
+/// directly after "at", "klo", "kello", "from" or "between".
+/// With [`ParserOptions::dot_times`] disabled, a dotted time such as "16.30" is only accepted
+/// directly after "klo" or "kello".
+pub fn find_time_with_options(
+    s_after_date: &str,
+    options: &ParserOptions,
+) -> Option<(TimeUnit, usize, usize)> {
+    let (unit, start, end) = find_time_candidate(s_after_date, options)?;
+    if !matches!(unit, TimeUnit::Structured(_) | TimeUnit::Spoken(_)) {
+        return Some((unit, start, end));
+    }
+    if let Some((day_part, phrase_end)) = find_day_part_qualifier(&s_after_date[end..]) {
+        return Some((unit.in_day_part(day_part), start, end + phrase_end));
+    }
+    Some((unit, start, end))
+}
+
+/// Matches a part of the day qualifying the preceding time, such as "in the evening" or "illalla"
+static DAY_PART_QUALIFIER: Lazy<Regex> = lazy_regex!(
+    r"^\s+(?i:in\s+the\s+(?P<en>morning|afternoon|evening)|at\s+(?P<night>night)|(?P<fi>aamulla|iltapäivällä|illalla|yöllä))(?:$|[\s,@(])"
+);
+
+/// Tries to find a part of the day directly following a time.
+/// Returns the part of the day and the end offset of the phrase in `s_after_time`.
+fn find_day_part_qualifier(s_after_time: &str) -> Option<(DayPart, usize)> {
+    let captures = DAY_PART_QUALIFIER.captures(s_after_time)?;
+    let word = ["en", "night", "fi"]
+        .into_iter()
+        .find_map(|name| captures.name(name))?;
+    Some((word.as_str().parse().ok()?, word.end()))
+}
+
+/// Finds the first time in the string, see [`find_time_with_options`]
+fn find_time_candidate(
+    s_after_date: &str,
+    options: &ParserOptions,
+) -> Option<(TimeUnit, usize, usize)> {
+    let words = split_words(s_after_date);
+    let mut invalid_candidate = None;
+    for (i, &(start, word)) in words.iter().enumerate() {
+        if let Some((unit, words_matched)) = TimeSpoken::parse_multiword(&words[i..]) {
+            let (last_start, last_word) = words[i + words_matched - 1];
+            return Some((TimeUnit::Spoken(unit), start, last_start + last_word.len()));
+        }
+
+        if is_clock_prefix(word) || matches!(word.to_lowercase().as_str(), "from" | "between") {
+            // Time prefix, the following number is a time even without a separator
+            if let Some(&(next_start, next_word)) = words.get(i + 1) {
+                if let Some(unit) = parse_clock_time(strip_approximate_marker(next_word)) {
+                    let (unit, unit_end) = attach_meridiem_word(
+                        unit,
+                        next_start + next_word.len(),
+                        words.get(i + 2).copied(),
+                    );
+                    let found = (unit, start, unit_end);
+                    if found.0.is_valid() {
+                        return Some(found);
+                    }
+                    invalid_candidate.get_or_insert(found);
+                }
+            }
+        }
+
         let end = start + word.len();
-        if let Ok(unit) = word.parse::<TimeStructured>() {
-            return Some((TimeUnit::Structured(unit), start, end));
+        let number = strip_approximate_marker(word);
+        let after_at = i
+            .checked_sub(1)
+            .is_some_and(|previous| words[previous].1.eq_ignore_ascii_case("at"));
+        let bare_hour_allowed = options.bare_hour_times || after_at;
+        if let Some((unit, unit_end)) = parse_clock_time(number)
+            .map(|unit| attach_meridiem_word(unit, end, words.get(i + 1).copied()))
+            .filter(|(unit, _)| {
+                let bare_hour = matches!(unit, TimeUnit::Structured(TimeStructured::H(_)));
+                // The "2" in "for 2 hours" is a duration, not a time
+                let duration_amount = words
+                    .get(i + 1)
+                    .is_some_and(|(_, next_word)| is_duration_unit(next_word));
+                let dot_time = number.contains('.') && !number.contains(':');
+                (!bare_hour || (bare_hour_allowed && !duration_amount))
+                    && (!dot_time || options.dot_times)
+            })
+        {
+            let found = (unit, start, unit_end);
+            if found.0.is_valid() {
+                return Some(found);
+            }
+            if word.contains(':') {
+                invalid_candidate.get_or_insert(found);
+            }
+        }
+        // "120 Main St" is more likely an address than 1:20, so a number followed by more
+        // words is not read as a time
+        if options.four_digit_times && ends_time_position(&s_after_date[end..], options) {
+            if let Some(unit) = parse_four_digit_time(number) {
+                let found = (TimeUnit::Structured(unit), start, end);
+                if found.0.is_valid() {
+                    return Some(found);
+                }
+            }
+        }
+        if let Ok(unit) = word.parse::<TimeNamed>() {
+            return Some((TimeUnit::Named(unit), start, end));
+        }
+        if let Ok(day_part) = word.parse::<DayPart>() {
+            return Some((TimeUnit::DayPart(day_part), start, end));
+        }
+    }
+    invalid_candidate
+}
+
+/// Removes markers of an approximate time attached to a number: "~17:00" and "5ish"
+fn strip_approximate_marker(word: &str) -> &str {
+    let word = word.strip_prefix('~').unwrap_or(word);
+    word.strip_suffix("ish").unwrap_or(word)
+}
+
+/// Words that mark the following time as approximate
+const APPROXIMATE_PREFIXES: [&str; 7] = [
+    "~",
+    "around",
+    "about",
+    "approximately",
+    "circa",
+    "ca.",
+    "noin",
+];
+
+/// Checks whether a time found by [`find_time_with_options`] was marked as approximate, as in
+/// "around 5", "~17:00", "5ish" or "5-ish".
+/// `before_time`, `time` and `after_time` are the text before, of and after the time.
+/// Returns the length of the marker following the time, if any, which should be included in the
+/// match.
+pub fn find_approximate_marker(before_time: &str, time: &str, after_time: &str) -> Option<usize> {
+    if let Some(marker) = ["-ish", " ish"]
+        .into_iter()
+        .find(|marker| after_time.starts_with(marker))
+    {
+        if after_time[marker.len()..]
+            .chars()
+            .next()
+            .is_none_or(|c| !c.is_alphanumeric())
+        {
+            return Some(marker.len());
+        }
+    }
+    let previous_word = before_time
+        .trim_end()
+        .rsplit(' ')
+        .next()
+        .unwrap_or_default()
+        .to_lowercase();
+    let marked = time.starts_with('~')
+        || time.ends_with("ish")
+        || APPROXIMATE_PREFIXES.contains(&previous_word.as_str());
+    marked.then_some(0)
+}
+
+/// Counts the words between a date and the time following it that were ignored, see
+/// [`find_skipped_words`]
+pub fn count_skipped_words(between_date_and_time: &str) -> usize {
+    find_skipped_words(between_date_and_time).count()
+}
+
+/// The words between a date and the time following it that were ignored, not including words
+/// that lead to a time such as "at" or "around"
+pub fn find_skipped_words(between_date_and_time: &str) -> impl Iterator<Item = &str> {
+    between_date_and_time
+        .split([' ', ','])
+        .filter(|word| !word.is_empty())
+        .filter(|word| {
+            let word = word.to_lowercase();
+            word != "at" && !APPROXIMATE_PREFIXES.contains(&word.as_str())
+        })
+}
+
+/// Whether the text following a time candidate is something that usually follows a time:
+/// nothing, a location separator, see [`ParserOptions::location_delimiters`], or a range
+fn ends_time_position(rest: &str, options: &ParserOptions) -> bool {
+    let is_separator =
+        |c: char| options.location_delimiters.contains(&c) || ['-', '–'].contains(&c);
+    rest.is_empty() || rest.starts_with("  ") || rest.trim_start().starts_with(is_separator)
+}
+
+/// Parses military style (H)HMM times such as "0930" or "1630"
+fn parse_four_digit_time(word: &str) -> Option<TimeStructured> {
+    if !(3..=4).contains(&word.len()) || !word.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let number = word.parse::<i16>().ok()?;
+    // At most 9999, so both parts fit in an i8
+    Some(TimeStructured::Hm(
+        (number / 100) as i8,
+        (number % 100) as i8,
+    ))
+}
+
+/// Tries to find the end of a time range directly following a time, such as "-12:30", " – 13:00",
+/// " to 13:00", " until 13", " till 13" or "-4pm", see [`share_meridiem`] for the last one.
+/// With `after_between`, set when the time followed "between", " and 13" is accepted as well.
+/// Returns the end time and the byte offset the match ends at.
+pub fn find_range_end(s_after_time: &str, after_between: bool) -> Option<(TimeUnit, usize)> {
+    let trimmed = s_after_time.trim_start();
+    let after_separator = if let Some(rest) = trimmed.strip_prefix(['-', '–']) {
+        rest
+    } else {
+        let (word, rest) = trimmed.split_once(' ')?;
+        let is_separator = word.eq_ignore_ascii_case("to")
+            || word.eq_ignore_ascii_case("until")
+            || word.eq_ignore_ascii_case("till")
+            || after_between && word.eq_ignore_ascii_case("and");
+        if !is_separator {
+            return None;
+        }
+        rest
+    }
+    .trim_start();
+
+    let word_len = after_separator
+        .find([' ', ',', '@'])
+        .unwrap_or(after_separator.len());
+    let unit = parse_clock_time(&after_separator[..word_len])?;
+    let end = s_after_time.len() - after_separator.len() + word_len;
+    let rest = &after_separator[word_len..];
+    let next_start = end + rest.len() - rest.trim_start_matches(' ').len();
+    let next_word = rest.trim_start_matches(' ').split([' ', ',', '@']).next();
+    Some(attach_meridiem_word(
+        unit,
+        end,
+        next_word.map(|word| (next_start, word)),
+    ))
+}
+
+/// Matches a compact duration token: "2h", "2h30", "2h30m", "1.5h", "1,5h", "90min", "45m" or
+/// "30s".
+/// Seconds have at most 3 digits, so that a decade such as "1990s" isn't one.
+static COMPACT_DURATION: Lazy<Regex> = lazy_regex!(
+    r"^(?ix)(?:
+        (?P<hours>\d{1,2})
+            (?:[.,](?P<hour_fraction>\d{1,2})h|h(?:(?P<hour_minutes>\d{1,2})(?:min|m)?)?)
+        |(?P<minutes>\d{1,4})(?:min|m)
+        |(?P<seconds>\d{1,3})(?:secs?|s)
+    )$"
+);
+
+/// Tries to find a compact duration such as "2h30" or "(90min)" directly following a time, or
+/// following a comma after it as in "11:00, 1,5h". Hours may have a decimal comma.
+/// Returns the duration and its end offset in `s_after_time`.
+///
+/// Only this position is searched, so that a "2m" elsewhere in the input, which could just as well
+/// mean meters, is never taken as a duration.
+pub fn find_compact_duration(s_after_time: &str) -> Option<(Span, usize)> {
+    let trimmed = s_after_time.trim_start();
+    let trimmed = trimmed.strip_prefix(',').map_or(trimmed, str::trim_start);
+    if trimmed.len() == s_after_time.len() {
+        return None;
+    }
+    let (inner, parenthesized) = trimmed
+        .strip_prefix('(')
+        .map_or((trimmed, false), |rest| (rest, true));
+    // A comma between digits is a decimal comma, as in "1,5h"
+    let token_len = inner
+        .char_indices()
+        .find(|&(i, separator)| {
+            let decimal_comma = separator == ','
+                && inner[..i].ends_with(|c: char| c.is_ascii_digit())
+                && inner[i + 1..].starts_with(|c: char| c.is_ascii_digit());
+            [' ', ',', '@', ')'].contains(&separator) && !decimal_comma
+        })
+        .map_or(inner.len(), |(i, _)| i);
+    let (token, rest) = inner.split_at(token_len);
+    if parenthesized && !rest.starts_with(')') {
+        return None;
+    }
+
+    let captures = COMPACT_DURATION.captures(token)?;
+    let number = |name: &str| {
+        captures
+            .name(name)
+            .map_or(Some(0), |m| m.as_str().parse::<i64>().ok())
+    };
+    let mut hour_minutes = number("hour_minutes")?;
+    if hour_minutes >= 60 {
+        return None;
+    }
+    if let Some(fraction) = captures.name("hour_fraction") {
+        // "1.5h" is 1h30, rounded to the nearest minute
+        let digits = fraction.as_str();
+        let scale = 10_i64.pow(u32::try_from(digits.len()).ok()?);
+        hour_minutes = (digits.parse::<i64>().ok()? * 60 + scale / 2) / scale;
+    }
+    let duration = number("hours")?
+        .hours()
+        .minutes(hour_minutes + number("minutes")?)
+        .seconds(number("seconds")?);
+    if duration.is_zero() {
+        return None;
+    }
+
+    let end = s_after_time.len() - rest.len() + usize::from(parenthesized);
+    Some((duration, end))
+}
+
+/// Units of a spelled out duration
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DurationUnit {
+    Hour,
+    Minute,
+    Day,
+    Week,
+    Second,
+}
+impl DurationUnit {
+    /// Returns the span of `halves` halves of this unit, `None` for half minutes and seconds
+    fn span_of_halves(self, halves: i64) -> Option<Span> {
+        let (whole, half) = (halves / 2, halves % 2);
+        match self {
+            DurationUnit::Hour => Some(whole.hours().minutes(half * 30)),
+            DurationUnit::Minute if half == 0 => Some(whole.minutes()),
+            DurationUnit::Minute => None,
+            DurationUnit::Day => Some(whole.days().hours(half * 12)),
+            // Counted in days, as ranges of dates are
+            DurationUnit::Week => Some((whole * 7 + half * 3).days().hours(half * 12)),
+            DurationUnit::Second if half == 0 => Some(whole.seconds()),
+            DurationUnit::Second => None,
+        }
+    }
+}
+
+/// Finds the unit named by `word` along with its language, and whether it means a single unit
+/// without a number, as the Finnish "tunnin" does
+fn parse_duration_unit(word: &str) -> Option<(DateRelativeLanguage, DurationUnit, bool)> {
+    let word = normalize_word(word);
+    DateRelativeLanguage::iter().find_map(|lang| {
+        let units = [
+            DurationUnit::Hour,
+            DurationUnit::Minute,
+            DurationUnit::Day,
+            DurationUnit::Week,
+            DurationUnit::Second,
+        ];
+        let plain = lang
+            .get_duration_units()
+            .into_iter()
+            .map(|names| (names, false));
+        let single = lang
+            .get_duration_units_single()
+            .into_iter()
+            .map(|names| (names, true));
+        single
+            .zip(units)
+            .chain(plain.zip(units))
+            .find(|((names, _), _)| names.contains(&word.as_str()))
+            .map(|((_, single), unit)| (lang, unit, single))
+    })
+}
+
+/// Tries to find a spelled out duration directly following a time, such as "for 2 hours",
+/// "for 90 minutes", "2 hours", "two hours", "an hour", "half an hour", "an hour and a half",
+/// "for 2 weeks", "2 tuntia", "puoli tuntia" or "tunnin ajan".
+/// After "for", compact durations such as "2h30" are accepted as well, see
+/// [`find_compact_duration`].
+/// Returns the duration and its end offset in `s_after_time`.
+pub fn find_duration(s_after_time: &str) -> Option<(Span, usize)> {
+    if !s_after_time.starts_with(char::is_whitespace) {
+        return None;
+    }
+    let words = split_duration_words(s_after_time);
+    let word = |i: usize| words.get(i).map(|(_, word)| normalize_word(word));
+
+    let mut i = 0;
+    let after_for = word(0).as_deref() == Some("for");
+    if after_for {
+        i += 1;
+        let (compact_start, _) = *words.get(i)?;
+        if let Some((duration, end)) = find_compact_duration(&s_after_time[compact_start - 1..]) {
+            return Some((duration, compact_start - 1 + end));
+        }
+    }
+    // "and a half" following the amount or the unit
+    let and_a_half = |at: usize| {
+        word(at).as_deref() == Some("and")
+            && matches!(word(at + 1).as_deref(), Some("a" | "an"))
+            && word(at + 2).as_deref() == Some("half")
+    };
+    // The number of halves of the unit, "puoli tuntia" being 1
+    let mut english_amount = true;
+    let mut halves = match word(i).as_deref()? {
+        "a" | "an" => Some(2),
+        "half" if matches!(word(i + 1).as_deref(), Some("a" | "an")) => {
+            i += 1;
+            Some(1)
+        }
+        "puoli" => {
+            english_amount = false;
+            Some(1)
+        }
+        amount => {
+            english_amount = !amount.starts_with(|c: char| c.is_ascii_digit());
+            amount
+                .parse::<i64>()
+                .ok()
+                .or_else(|| parse_number_word(amount))
+                .map(|amount| amount * 2)
+        }
+    };
+    if halves.is_some() {
+        i += 1;
+        // "two and a half hours"
+        if and_a_half(i) {
+            halves = halves.map(|halves| halves + 1);
+            i += 3;
+        }
+    }
+    let (unit_start, unit_word) = *words.get(i)?;
+    let (lang, unit, single) = parse_duration_unit(unit_word)?;
+    let english_words = halves.is_some() && english_amount;
+    let halves = halves.or(single.then_some(2))?;
+    if halves <= 0 || ((after_for || english_words) && lang != DateRelativeLanguage::English) {
+        return None;
+    }
+    // "an hour and a half"
+    let (halves, mut last) =
+        if lang == DateRelativeLanguage::English && halves % 2 == 0 && and_a_half(i + 1) {
+            (halves + 1, words[i + 3])
+        } else {
+            (halves, (unit_start, unit_word))
+        };
+    let duration = unit.span_of_halves(halves)?;
+    // "tunnin ajan", for an hour
+    if lang == DateRelativeLanguage::Finnish && word(i + 1).as_deref() == Some("ajan") {
+        last = words[i + 1];
+    }
+    let (last_start, last_word) = last;
+    Some((duration, last_start + last_word.len()))
+}
+
+/// Tries to find a spelled out duration at the very end of a location, as in
+/// "Retro tomorrow 14:00 @ A769 for 45 min", see [`find_duration`].
+/// Returns the duration and its start offset in `location`.
+pub fn find_trailing_duration(location: &str) -> Option<(Span, usize)> {
+    location
+        .match_indices(' ')
+        .map(|(start, _)| start)
+        .find_map(|start| {
+            let (duration, end) = find_duration(&location[start..])?;
+            location[start + end..]
+                .trim()
+                .is_empty()
+                .then_some((duration, start))
+        })
+}
+
+/// Splits `s` into words separated by whitespace, stopping at anything that may start a location
+/// or an annotation: ",", "@" or "("
+fn split_duration_words(s: &str) -> Vec<(usize, &str)> {
+    let stop = s.find([',', '@', '(']).unwrap_or(s.len());
+    s[..stop]
+        .split(' ')
+        .scan(0, |start, word| {
+            let word_start = *start;
+            *start += word.len() + 1;
+            Some((word_start, word))
+        })
+        .filter(|(_, word)| !word.is_empty())
+        .collect()
+}
+
+/// Whether `word` is the unit of a spelled out duration, as the "hours" in "2 hours"
+fn is_duration_unit(word: &str) -> bool {
+    parse_duration_unit(word).is_some()
+}
+
+/// Matches a time zone token: an abbreviation such as "UTC" or "EET", or a fixed offset such as
+/// "+02:00", "+0200", "+2" or "UTC+2"
+static TIME_ZONE: Lazy<Regex> = lazy_regex!(
+    r"^(?:(?P<name>[A-Z]{1,5})|(?:UTC|GMT)?(?P<sign>[+-])(?P<hours>\d{1,2})(?::?(?P<minutes>\d{2}))?)$"
+);
+
+/// Returns the offset of a time zone abbreviation.
+/// Only abbreviations with a single widely agreed upon meaning are listed.
+fn offset_of_abbreviation(name: &str) -> Option<Offset> {
+    let hours = match name {
+        "Z" | "UTC" | "UT" | "GMT" | "WET" => 0,
+        "CET" | "WEST" | "BST" => 1,
+        "EET" | "CEST" => 2,
+        "EEST" | "MSK" => 3,
+        "JST" | "KST" => 9,
+        "AEST" => 10,
+        "AEDT" => 11,
+        "EDT" => -4,
+        "EST" | "CDT" => -5,
+        "CST" | "MDT" => -6,
+        "MST" | "PDT" => -7,
+        "PST" | "AKDT" => -8,
+        "AKST" => -9,
+        "HST" => -10,
+        _ => return None,
+    };
+    Some(Offset::constant(hours))
+}
+
+/// A time zone written after a time
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimeZoneAnnotation {
+    /// The zone as written, or the canonical name for IANA time zones
+    pub name: String,
+    /// A fixed offset for abbreviations and offsets, the full zone with its daylight saving time
+    /// rules for IANA time zones
+    pub tz: TimeZone,
+}
+
+/// Tries to find a time zone annotation directly following a time, such as the "UTC" in
+/// "15:00 UTC", the "+02:00" in "9:00 +02:00" or the "Europe/Helsinki" in
+/// "9:00 Europe/Helsinki".
+/// Returns the time zone and its end offset in `s_after_time`.
+///
+/// Negative offsets directly following a time, as in "15:00 -05:00", are read as time ranges
+/// instead, write "UTC-5" to avoid this.
+pub fn find_time_zone(s_after_time: &str) -> Option<(TimeZoneAnnotation, usize)> {
+    let trimmed = s_after_time.trim_start();
+    if trimmed.len() == s_after_time.len() {
+        return None;
+    }
+    let token_len = trimmed.find([' ', ',', '@', '(']).unwrap_or(trimmed.len());
+    let (token, rest) = trimmed.split_at(token_len);
+    let end = s_after_time.len() - rest.len();
+    Some((parse_time_zone(token)?, end))
+}
+
+/// Reads a single time zone such as "UTC", "+02:00", "UTC-5" or "Europe/Helsinki", see
+/// [`find_time_zone`].
+pub fn parse_time_zone(token: &str) -> Option<TimeZoneAnnotation> {
+    if token.contains('/') {
+        let tz = TimeZone::get(token).ok()?;
+        let name = tz.iana_name().unwrap_or(token).to_owned();
+        return Some(TimeZoneAnnotation { name, tz });
+    }
+
+    let captures = TIME_ZONE.captures(token)?;
+    let offset = if let Some(name) = captures.name("name") {
+        offset_of_abbreviation(name.as_str())?
+    } else {
+        let number = |name: &str| {
+            captures
+                .name(name)
+                .map_or(Some(0), |m| m.as_str().parse::<i32>().ok())
+        };
+        let (hours, minutes) = (number("hours")?, number("minutes")?);
+        if hours > 18 || minutes >= 60 {
+            return None;
+        }
+        let seconds = (hours * 60 + minutes) * 60;
+        let sign = if &captures["sign"] == "-" { -1 } else { 1 };
+        Offset::from_seconds(sign * seconds).ok()?
+    };
+    Some(TimeZoneAnnotation {
+        name: token.to_owned(),
+        tz: TimeZone::fixed(offset),
+    })
+}
+
+/// Offsets from the current time, such as "in 25 minutes" or "puolen tunnin päästä"
+#[derive(Debug, PartialEq)]
+pub enum TimeRelative {
+    Minutes(DateRelativeLanguage, i64),
+    Hours(DateRelativeLanguage, i64),
+}
+impl TimeRelative {
+    /// Tries to match an offset phrase at the start of `words`.
+    /// usize is the number of words matched
+    fn parse_multiword(words: &[(usize, &str)]) -> Option<(Self, usize)> {
+        let lowercase = |i: usize| words.get(i).map(|(_, word)| normalize_word(word));
+        let parse_amount = |word: &str| match word {
+            "a" | "an" => Some(1),
+            _ => parse_number_word(word),
+        };
+
+        let first = lowercase(0)?;
+        if first == "in" {
+            // in (amount) (unit), in half an hour
+            let second = lowercase(1)?;
+            if second == "half" && lowercase(2)?.as_str() == "an" && lowercase(3)? == "hour" {
+                return Some((Self::Minutes(DateRelativeLanguage::English, 30), 4));
+            }
+            let amount = parse_amount(&second)?;
+            let relative = match lowercase(2)?.as_str() {
+                "minute" | "minutes" | "min" | "mins" => {
+                    Self::Minutes(DateRelativeLanguage::English, amount)
+                }
+                "hour" | "hours" | "h" => Self::Hours(DateRelativeLanguage::English, amount),
+                _ => return None,
+            };
+            return Some((relative, 3));
+        }
+
+        // (amount) (unit) ("päästä"/"kuluttua"), the amount is optional for a single hour
+        let (amount, unit_index) = match first.as_str() {
+            "puolen" if lowercase(1)?.as_str() == "tunnin" => (None, 1),
+            "tunnin" | "minuutin" => (Some(1), 0),
+            _ => (Some(parse_amount(&first)?), 1),
+        };
+        if !matches!(lowercase(unit_index + 1)?.as_str(), "päästä" | "kuluttua") {
+            return None;
+        }
+        let relative = match (amount, lowercase(unit_index)?.as_str()) {
+            (None, _) => Self::Minutes(DateRelativeLanguage::Finnish, 30),
+            (Some(amount), "minuutin") => Self::Minutes(DateRelativeLanguage::Finnish, amount),
+            (Some(amount), "tunnin") => Self::Hours(DateRelativeLanguage::Finnish, amount),
+            _ => return None,
+        };
+        Some((relative, unit_index + 2))
+    }
+
+    /// The offset from the current time, failing if it is too large to be represented
+    pub fn as_span(&self) -> Result<Span, EventParseError> {
+        match self {
+            TimeRelative::Minutes(_, minutes) => Span::new().try_minutes(*minutes),
+            TimeRelative::Hours(_, hours) => Span::new().try_hours(*hours),
+        }
+        .map_err(|_e| EventParseError::invalid_time())
+    }
+}
+
+/// Matches a part of the current day: "tonight", "this morning", "tänä iltana", ...
+static DAY_PART_TODAY: Lazy<Regex> = lazy_regex!(
+    r"(?:^|\s)(?P<phrase>(?i:(?P<tonight>tonight)|this\s+(?P<en>morning|afternoon|evening)|tänä\s+(?P<fi>aamuna|iltapäivänä|iltana|yönä)))(?:$|[\s,@(])"
+);
+
+/// Whether `word` is "klo" or "kello", which mark the following number as a time of day
+pub fn is_clock_prefix(word: &str) -> bool {
+    matches!(word.to_lowercase().as_str(), "klo" | "klo." | "kello")
+}
+
+/// Tries to find a time prefixed with "klo" or "kello", as in "Palaveri kello 16.30", which is
+/// a time even when no date is given.
+/// Returns the starting offset of the prefix.
+pub fn find_clock_time_start(s: &str, options: &ParserOptions) -> Option<usize> {
+    let (_, start, _) = find_time_with_options(s, options)?;
+    s[start..]
+        .split(' ')
+        .next()
+        .is_some_and(is_clock_prefix)
+        .then_some(start)
+}
+
+/// Tries to find a part of the current day from the supplied string, such as "tonight",
+/// "this morning", "this afternoon", "this evening", "tänä aamuna" or "tänä iltana".
+/// "tonight" is the night of the current day even if the night has already begun.
+/// Returns the part of the day and the start and end offsets of the phrase.
+pub fn find_day_part_today(s: &str) -> Option<(DayPart, usize, usize)> {
+    let captures = DAY_PART_TODAY.captures(s)?;
+    let phrase = captures.name("phrase")?;
+    let day_part = if captures.name("tonight").is_some() {
+        DayPart::Night
+    } else if let Some(word) = captures.name("en") {
+        word.as_str().parse().ok()?
+    } else {
+        match captures.name("fi")?.as_str().to_lowercase().as_str() {
+            "aamuna" => DayPart::Morning,
+            "iltapäivänä" => DayPart::Afternoon,
+            "iltana" => DayPart::Evening,
+            _ => DayPart::Night,
         }
+    };
+    Some((day_part, phrase.start(), phrase.end()))
+}
 
-        start = end + 1;
+/// Tries to find a time relative to the current time from the supplied string.
+/// The time can be expressed as
+/// - "in" (amount) ("minutes"/"hours"): in 25 minutes, in an hour, in two hours, ...
+/// - "in half an hour"
+/// - (amount) ("minuutin"/"tunnin") ("päästä"/"kuluttua"): 2 tunnin päästä, tunnin kuluttua,
+///   puolen tunnin kuluttua, ...
+pub fn find_relative_time(s: &str) -> Option<(TimeRelative, usize, usize)> {
+    let words = split_words(s);
+    for (i, &(start, _)) in words.iter().enumerate() {
+        if let Some((unit, words_matched)) = TimeRelative::parse_multiword(&words[i..]) {
+            let (last_start, last_word) = words[i + words_matched - 1];
+            return Some((unit, start, last_start + last_word.len()));
+        }
     }
     None
 }
@@ -95,30 +1274,73 @@ pub fn find_time(s_after_date: &str) -> Option<(TimeUnit, usize, usize)> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn find_day_part_today_tonight() {
+        assert_eq!(
+            find_day_part_today("Call tonight"),
+            Some((DayPart::Night, 5, 12))
+        );
+        assert_eq!(
+            find_day_part_today("Call Tonight, Home"),
+            Some((DayPart::Night, 5, 12))
+        );
+    }
+    #[test]
+    fn find_day_part_today_this() {
+        assert_eq!(
+            find_day_part_today("Meeting this afternoon @ A769"),
+            Some((DayPart::Afternoon, 8, 22))
+        );
+        assert_eq!(
+            find_day_part_today("Run this morning"),
+            Some((DayPart::Morning, 4, 16))
+        );
+        assert_eq!(
+            find_day_part_today("Sauna this evening"),
+            Some((DayPart::Evening, 6, 18))
+        );
+    }
+    #[test]
+    fn find_day_part_today_finnish() {
+        assert_eq!(
+            find_day_part_today("Sauna tänä iltana"),
+            Some((DayPart::Evening, 6, 19))
+        );
+    }
+    #[test]
+    fn find_day_part_today_invalid() {
+        assert!(find_day_part_today("Buy this morningstar").is_none());
+        assert!(find_day_part_today("Tonights plan").is_none());
+    }
+
     #[test]
     fn find_time_trivial_a() {
-        let (unit, start, end) = find_time("18:11").expect("parse failed");
+        let (unit, start, end) =
+            find_time_with_options("18:11", &ParserOptions::default()).expect("parse failed");
         assert_eq!(unit, TimeUnit::Structured(TimeStructured::Hm(18, 11)));
         assert_eq!(start, 0);
         assert_eq!(end, 5);
     }
     #[test]
     fn find_time_trivial_b() {
-        let (unit, start, end) = find_time("3:03").expect("parse failed");
+        let (unit, start, end) =
+            find_time_with_options("3:03", &ParserOptions::default()).expect("parse failed");
         assert_eq!(unit, TimeUnit::Structured(TimeStructured::Hm(3, 3)));
         assert_eq!(start, 0);
         assert_eq!(end, 4);
     }
     #[test]
     fn find_time_trivial_c() {
-        let (unit, start, end) = find_time("0:1").expect("parse failed");
+        let (unit, start, end) =
+            find_time_with_options("0:1", &ParserOptions::default()).expect("parse failed");
         assert_eq!(unit, TimeUnit::Structured(TimeStructured::Hm(0, 1)));
         assert_eq!(start, 0);
         assert_eq!(end, 3);
     }
     #[test]
     fn find_time_trivial_d() {
-        let (unit, start, end) = find_time("18").expect("parse failed");
+        let (unit, start, end) =
+            find_time_with_options("18", &ParserOptions::default()).expect("parse failed");
         assert_eq!(unit, TimeUnit::Structured(TimeStructured::H(18)));
         assert_eq!(start, 0);
         assert_eq!(end, 2);
@@ -126,14 +1348,16 @@ mod tests {
 
     #[test]
     fn find_time_whitespace_a() {
-        let (unit, start, end) = find_time(" 4:01").expect("parse failed");
+        let (unit, start, end) =
+            find_time_with_options(" 4:01", &ParserOptions::default()).expect("parse failed");
         assert_eq!(unit, TimeUnit::Structured(TimeStructured::Hm(4, 1)));
         assert_eq!(start, 1);
         assert_eq!(end, 5);
     }
     #[test]
     fn find_time_whitespace_b() {
-        let (unit, start, end) = find_time(" 23:59  ").expect("parse failed");
+        let (unit, start, end) =
+            find_time_with_options(" 23:59  ", &ParserOptions::default()).expect("parse failed");
         assert_eq!(unit, TimeUnit::Structured(TimeStructured::Hm(23, 59)));
         assert_eq!(start, 1);
         assert_eq!(end, 6);
@@ -141,28 +1365,38 @@ mod tests {
 
     #[test]
     fn find_time_junk_a() {
-        let (unit, start, end) = find_time(" iaksjdk 13:30").expect("parse failed");
+        let (unit, start, end) =
+            find_time_with_options(" iaksjdk 13:30", &ParserOptions::default())
+                .expect("parse failed");
         assert_eq!(unit, TimeUnit::Structured(TimeStructured::Hm(13, 30)));
         assert_eq!(start, 9);
         assert_eq!(end, 14);
     }
     #[test]
     fn find_time_junk_b() {
-        let (unit, start, end) = find_time("8:15 @ Annankatu 13").expect("parse failed");
+        let (unit, start, end) =
+            find_time_with_options("8:15 @ Annankatu 13", &ParserOptions::default())
+                .expect("parse failed");
         assert_eq!(unit, TimeUnit::Structured(TimeStructured::Hm(8, 15)));
         assert_eq!(start, 0);
         assert_eq!(end, 4);
     }
     #[test]
     fn find_time_junk_c() {
-        let (unit, start, end) = find_time("ab123.23. 14:13 @ Taajamankatu 5").expect("parse failed");
+        let (unit, start, end) = find_time_with_options(
+            "ab123.23. 14:13 @ Taajamankatu 5",
+            &ParserOptions::default(),
+        )
+        .expect("parse failed");
         assert_eq!(unit, TimeUnit::Structured(TimeStructured::Hm(14, 13)));
         assert_eq!(start, 10);
         assert_eq!(end, 15);
     }
     #[test]
     fn find_time_junk_d() {
-        let (unit, start, end) = find_time("ab123.23. 8 @ Taajamankatu 5").expect("parse failed");
+        let (unit, start, end) =
+            find_time_with_options("ab123.23. 8 @ Taajamankatu 5", &ParserOptions::default())
+                .expect("parse failed");
         assert_eq!(unit, TimeUnit::Structured(TimeStructured::H(8)));
         assert_eq!(start, 10);
         assert_eq!(end, 11);
@@ -170,23 +1404,1117 @@ mod tests {
 
     #[test]
     fn find_time_with_seconds_a() {
-        let (unit, start, end) = find_time("19:59:00").expect("parse failed");
+        let (unit, start, end) =
+            find_time_with_options("19:59:00", &ParserOptions::default()).expect("parse failed");
         assert_eq!(unit, TimeUnit::Structured(TimeStructured::Hms(19, 59, 0)));
         assert_eq!(start, 0);
         assert_eq!(end, 8);
     }
     #[test]
     fn find_time_with_seconds_b() {
-        let (unit, start, end) = find_time("11:09:59").expect("parse failed");
+        let (unit, start, end) =
+            find_time_with_options("11:09:59", &ParserOptions::default()).expect("parse failed");
         assert_eq!(unit, TimeUnit::Structured(TimeStructured::Hms(11, 9, 59)));
         assert_eq!(start, 0);
         assert_eq!(end, 8);
     }
     #[test]
     fn find_time_with_seconds_c() {
-        let (unit, start, end) = find_time("8:0:1").expect("parse failed");
+        let (unit, start, end) =
+            find_time_with_options("8:0:1", &ParserOptions::default()).expect("parse failed");
         assert_eq!(unit, TimeUnit::Structured(TimeStructured::Hms(8, 0, 1)));
         assert_eq!(start, 0);
         assert_eq!(end, 5);
     }
+
+    #[test]
+    fn find_time_with_fraction_a() {
+        let (unit, start, end) =
+            find_time_with_options(" 11:00:00.500 @ Lab", &ParserOptions::default())
+                .expect("parse failed");
+        assert_eq!(
+            unit,
+            TimeUnit::Structured(TimeStructured::Hmsn(11, 0, 0, 500_000_000))
+        );
+        assert_eq!(start, 1);
+        assert_eq!(end, 13);
+        assert_eq!(
+            unit.as_time(&ParserOptions::default()),
+            Ok(Time::constant(11, 0, 0, 500_000_000))
+        );
+    }
+    #[test]
+    fn find_time_with_fraction_b() {
+        let (unit, _, _) = find_time_with_options("23:59:59.123456789", &ParserOptions::default())
+            .expect("parse failed");
+        assert_eq!(
+            unit,
+            TimeUnit::Structured(TimeStructured::Hmsn(23, 59, 59, 123_456_789))
+        );
+    }
+    #[test]
+    fn find_time_with_fraction_invalid() {
+        assert!(find_time_with_options("11:00:00.", &ParserOptions::default()).is_none());
+        assert!(find_time_with_options("11:00:00.1234567890", &ParserOptions::default()).is_none());
+        assert!(find_time_with_options("11:00:00.5x", &ParserOptions::default()).is_none());
+    }
+
+    #[test]
+    fn find_time_four_digit_a() {
+        let (unit, start, end) =
+            find_time_with_options(" 0930 @ Gate 4", &ParserOptions::default())
+                .expect("parse failed");
+        assert_eq!(unit, TimeUnit::Structured(TimeStructured::Hm(9, 30)));
+        assert_eq!(start, 1);
+        assert_eq!(end, 5);
+    }
+    #[test]
+    fn find_time_four_digit_b() {
+        let (unit, _, _) =
+            find_time_with_options(" 1200", &ParserOptions::default()).expect("parse failed");
+        assert_eq!(unit, TimeUnit::Structured(TimeStructured::Hm(12, 0)));
+    }
+    #[test]
+    fn find_time_four_digit_c() {
+        let (unit, _, _) =
+            find_time_with_options(" 2359", &ParserOptions::default()).expect("parse failed");
+        assert_eq!(unit, TimeUnit::Structured(TimeStructured::Hm(23, 59)));
+    }
+    #[test]
+    fn find_time_four_digit_d() {
+        let (unit, _, _) =
+            find_time_with_options(" 800", &ParserOptions::default()).expect("parse failed");
+        assert_eq!(unit, TimeUnit::Structured(TimeStructured::Hm(8, 0)));
+    }
+    #[test]
+    fn find_time_four_digit_invalid() {
+        assert!(find_time_with_options(" 2460", &ParserOptions::default()).is_none());
+        assert!(find_time_with_options(" 0975", &ParserOptions::default()).is_none());
+        assert!(find_time_with_options(" 12345", &ParserOptions::default()).is_none());
+    }
+    #[test]
+    fn find_time_four_digit_address() {
+        assert!(find_time_with_options(" 120 Main St", &ParserOptions::default()).is_none());
+    }
+    #[test]
+    fn find_time_four_digit_disabled() {
+        let options = ParserOptions {
+            four_digit_times: false,
+            ..Default::default()
+        };
+        assert!(find_time_with_options(" 0930", &options).is_none());
+    }
+
+    #[test]
+    fn find_time_bare_hour_disabled() {
+        let options = ParserOptions {
+            bare_hour_times: false,
+            ..Default::default()
+        };
+        assert!(find_time_with_options(" 3 pizzas", &options).is_none());
+        let (unit, start, end) =
+            find_time_with_options(" 3 pizzas 18:30", &options).expect("parse failed");
+        assert_eq!(unit, TimeUnit::Structured(TimeStructured::Hm(18, 30)));
+        assert_eq!(start, 10);
+        assert_eq!(end, 15);
+    }
+    #[test]
+    fn find_time_bare_hour_disabled_prefixed() {
+        let options = ParserOptions {
+            bare_hour_times: false,
+            dot_times: true,
+            ..Default::default()
+        };
+        let (unit, start, end) =
+            find_time_with_options(" at 3 @ Pizzeria", &options).expect("parse failed");
+        assert_eq!(unit, TimeUnit::Structured(TimeStructured::H(3)));
+        assert_eq!(start, 4);
+        assert_eq!(end, 5);
+        assert_eq!(
+            find_time_with_options(" klo 3", &options).map(|(found, _, _)| found),
+            Some(TimeUnit::Structured(TimeStructured::H(3)))
+        );
+        assert_eq!(
+            find_time_with_options(" 16.30", &options).map(|(found, _, _)| found),
+            Some(TimeUnit::Structured(TimeStructured::Hm(16, 30)))
+        );
+    }
+
+    #[test]
+    fn find_time_in_the_evening() {
+        let (unit, start, end) =
+            find_time_with_options(" 7 in the evening @ Home", &ParserOptions::default())
+                .expect("parse failed");
+        assert_eq!(unit, TimeUnit::Structured(TimeStructured::Hm(19, 0)));
+        assert_eq!(start, 1);
+        assert_eq!(end, 17);
+    }
+    #[test]
+    fn find_time_in_the_morning() {
+        let (unit, start, end) =
+            find_time_with_options(" at 8 in the morning", &ParserOptions::default())
+                .expect("parse failed");
+        assert_eq!(unit, TimeUnit::Structured(TimeStructured::Hm(8, 0)));
+        assert_eq!(start, 4);
+        assert_eq!(end, 20);
+    }
+    #[test]
+    fn find_time_at_night() {
+        let (unit, _, end) = find_time_with_options(" 11:30 at night", &ParserOptions::default())
+            .expect("parse failed");
+        assert_eq!(unit, TimeUnit::Structured(TimeStructured::Hm(23, 30)));
+        assert_eq!(end, 15);
+    }
+    #[test]
+    fn find_time_spoken_in_the_afternoon() {
+        let (unit, _, end) = find_time_with_options(
+            " quarter to three in the afternoon",
+            &ParserOptions::default(),
+        )
+        .expect("parse failed");
+        assert_eq!(unit, TimeUnit::Structured(TimeStructured::Hm(14, 45)));
+        assert_eq!(end, 34);
+    }
+    #[test]
+    fn find_time_finnish_day_part_qualifier() {
+        let (unit, _, end) = find_time_with_options(" klo 7 illalla", &ParserOptions::default())
+            .expect("parse failed");
+        assert_eq!(unit, TimeUnit::Structured(TimeStructured::Hm(19, 0)));
+        assert_eq!(end, 14);
+    }
+    #[test]
+    fn find_time_day_part_conflict() {
+        let (unit, _, end) =
+            find_time_with_options(" 19 in the morning", &ParserOptions::default())
+                .expect("parse failed");
+        assert_eq!(unit, TimeUnit::Structured(TimeStructured::Hm(19, 0)));
+        assert_eq!(end, 18);
+    }
+    #[test]
+    fn find_time_day_part_not_a_qualifier() {
+        let (unit, _, end) =
+            find_time_with_options(" 7 in the eveningwear shop", &ParserOptions::default())
+                .expect("parse failed");
+        assert_eq!(unit, TimeUnit::Structured(TimeStructured::H(7)));
+        assert_eq!(end, 2);
+    }
+
+    #[test]
+    fn find_time_approximate_tilde() {
+        let (unit, start, end) = find_time_with_options(" ~17:00 @ Pub", &ParserOptions::default())
+            .expect("parse failed");
+        assert_eq!(unit, TimeUnit::Structured(TimeStructured::Hm(17, 0)));
+        assert_eq!(start, 1);
+        assert_eq!(end, 7);
+    }
+    #[test]
+    fn find_time_approximate_ish() {
+        let (unit, start, end) =
+            find_time_with_options(" 5ish", &ParserOptions::default()).expect("parse failed");
+        assert_eq!(unit, TimeUnit::Structured(TimeStructured::H(5)));
+        assert_eq!(start, 1);
+        assert_eq!(end, 5);
+    }
+    #[test]
+    fn approximate_marker_prefix() {
+        assert_eq!(find_approximate_marker(" around ", "5", ""), Some(0));
+        assert_eq!(find_approximate_marker(" About ", "5", " @ Pub"), Some(0));
+        assert_eq!(find_approximate_marker(" noin klo ", "17", ""), None);
+        assert_eq!(find_approximate_marker(" noin ", "klo 17", ""), Some(0));
+        assert_eq!(find_approximate_marker(" ~ ", "17:00", ""), Some(0));
+    }
+    #[test]
+    fn approximate_marker_attached() {
+        assert_eq!(find_approximate_marker(" ", "~17:00", ""), Some(0));
+        assert_eq!(find_approximate_marker(" ", "5ish", ""), Some(0));
+    }
+    #[test]
+    fn approximate_marker_suffix() {
+        assert_eq!(find_approximate_marker(" ", "5", "-ish @ Pub"), Some(4));
+        assert_eq!(find_approximate_marker(" ", "5", " ish"), Some(4));
+        assert_eq!(find_approximate_marker(" ", "5", " ishikawa"), None);
+    }
+    #[test]
+    fn approximate_marker_none() {
+        assert_eq!(find_approximate_marker(" at ", "5", " @ Pub"), None);
+    }
+
+    #[test]
+    fn find_time_dot_a() {
+        let options = ParserOptions {
+            dot_times: true,
+            ..Default::default()
+        };
+        let (unit, start, end) = find_time_with_options(" 16.30", &options).expect("parse failed");
+        assert_eq!(unit, TimeUnit::Structured(TimeStructured::Hm(16, 30)));
+        assert_eq!(start, 1);
+        assert_eq!(end, 6);
+    }
+    #[test]
+    fn find_time_dot_b() {
+        let options = ParserOptions {
+            dot_times: true,
+            ..Default::default()
+        };
+        let (unit, start, end) =
+            find_time_with_options("8.05 @ Kahvila", &options).expect("parse failed");
+        assert_eq!(unit, TimeUnit::Structured(TimeStructured::Hm(8, 5)));
+        assert_eq!(start, 0);
+        assert_eq!(end, 4);
+    }
+    #[test]
+    fn find_time_dot_disabled() {
+        assert!(find_time_with_options(" 16.30", &ParserOptions::default()).is_none());
+        let (unit, start, end) =
+            find_time_with_options(" klo 11.30", &ParserOptions::default()).expect("parse failed");
+        assert_eq!(unit, TimeUnit::Structured(TimeStructured::Hm(11, 30)));
+        assert_eq!(start, 1);
+        assert_eq!(end, 10);
+    }
+    #[test]
+    fn find_time_dot_date_is_not_time() {
+        assert!(find_time_with_options("18.11.", &ParserOptions::default()).is_none());
+        assert!(find_time_with_options("1.1", &ParserOptions::default()).is_none());
+    }
+
+    #[test]
+    fn find_time_spoken_a() {
+        let (unit, start, end) =
+            find_time_with_options(" half past five", &ParserOptions::default())
+                .expect("parse failed");
+        assert_eq!(unit, TimeUnit::Spoken(TimeSpoken::Past(30, 5)));
+        assert_eq!(start, 1);
+        assert_eq!(end, 15);
+        assert_eq!(
+            unit.as_time(&ParserOptions::default()),
+            Ok(Time::constant(5, 30, 0, 0))
+        );
+    }
+    #[test]
+    fn find_time_spoken_b() {
+        let (unit, start, end) =
+            find_time_with_options(" at quarter to six @ Cafe", &ParserOptions::default())
+                .expect("parse failed");
+        assert_eq!(unit, TimeUnit::Spoken(TimeSpoken::To(15, 6)));
+        assert_eq!(start, 4);
+        assert_eq!(end, 18);
+        assert_eq!(
+            unit.as_time(&ParserOptions::default()),
+            Ok(Time::constant(5, 45, 0, 0))
+        );
+    }
+    #[test]
+    fn find_time_spoken_c() {
+        let (unit, start, end) =
+            find_time_with_options("Ten past 9", &ParserOptions::default()).expect("parse failed");
+        assert_eq!(unit, TimeUnit::Spoken(TimeSpoken::Past(10, 9)));
+        assert_eq!(start, 0);
+        assert_eq!(end, 10);
+        assert_eq!(
+            unit.as_time(&ParserOptions::default()),
+            Ok(Time::constant(9, 10, 0, 0))
+        );
+    }
+    #[test]
+    fn find_time_spoken_d() {
+        let (unit, start, end) =
+            find_time_with_options(" twenty-five to twelve", &ParserOptions::default())
+                .expect("parse failed");
+        assert_eq!(unit, TimeUnit::Spoken(TimeSpoken::To(25, 12)));
+        assert_eq!(start, 1);
+        assert_eq!(end, 22);
+        assert_eq!(
+            unit.as_time(&ParserOptions::default()),
+            Ok(Time::constant(11, 35, 0, 0))
+        );
+    }
+    #[test]
+    fn find_time_oclock_word() {
+        let (unit, start, end) =
+            find_time_with_options(" at seven o'clock @ Home", &ParserOptions::default())
+                .expect("parse failed");
+        assert_eq!(unit, TimeUnit::Spoken(TimeSpoken::OClock(7)));
+        assert_eq!(start, 4);
+        assert_eq!(end, 17);
+        assert_eq!(
+            unit.as_time(&ParserOptions::default()),
+            Ok(Time::constant(7, 0, 0, 0))
+        );
+    }
+    #[test]
+    fn find_time_oclock_digit() {
+        let (unit, start, end) =
+            find_time_with_options(" 11 o’clock", &ParserOptions::default()).expect("parse failed");
+        assert_eq!(unit, TimeUnit::Spoken(TimeSpoken::OClock(11)));
+        assert_eq!(start, 1);
+        assert_eq!(end, 13);
+    }
+    #[test]
+    fn find_time_oclock_attached() {
+        let (unit, start, end) =
+            find_time_with_options(" 5o'clock @ Home", &ParserOptions::default())
+                .expect("parse failed");
+        assert_eq!(unit, TimeUnit::Spoken(TimeSpoken::OClock(5)));
+        assert_eq!(start, 1);
+        assert_eq!(end, 9);
+        let (spaced, spaced_start, spaced_end) =
+            find_time_with_options(" 5 o'clock", &ParserOptions::default()).expect("parse failed");
+        assert_eq!(spaced, TimeUnit::Spoken(TimeSpoken::OClock(5)));
+        assert_eq!((spaced_start, spaced_end), (1, 10));
+        let (oclock, _, _) =
+            find_time_with_options(" 11oclock", &ParserOptions::default()).expect("parse failed");
+        assert_eq!(oclock, TimeUnit::Spoken(TimeSpoken::OClock(11)));
+        assert!(find_time_with_options(" 17o'clock", &ParserOptions::default()).is_none());
+    }
+    #[test]
+    fn find_time_oclock_out_of_range() {
+        let (unit, _, _) =
+            find_time_with_options(" 17 o'clock", &ParserOptions::default()).expect("parse failed");
+        assert_eq!(unit, TimeUnit::Structured(TimeStructured::H(17)));
+    }
+    #[test]
+    fn spoken_daytime_meridiem() {
+        let options = ParserOptions {
+            meridiem: MeridiemInference::Daytime {
+                first_morning_hour: 8,
+            },
+            ..Default::default()
+        };
+        assert_eq!(
+            TimeSpoken::Past(30, 5).as_time(&options),
+            Ok(Time::constant(17, 30, 0, 0))
+        );
+        assert_eq!(
+            TimeSpoken::To(15, 6).as_time(&options),
+            Ok(Time::constant(17, 45, 0, 0))
+        );
+        // "seven o'clock" is a bare hour
+        assert_eq!(
+            TimeSpoken::OClock(7).as_time(&options),
+            Ok(Time::constant(7, 0, 0, 0))
+        );
+    }
+    #[test]
+    fn oclock_bare_hour_meridiem() {
+        assert_eq!(
+            TimeSpoken::OClock(7).as_time(&ParserOptions::default()),
+            Ok(Time::constant(7, 0, 0, 0))
+        );
+        let options = ParserOptions {
+            bare_hour_meridiem: MeridiemInference::Daytime {
+                first_morning_hour: 8,
+            },
+            ..Default::default()
+        };
+        assert_eq!(
+            TimeSpoken::OClock(7).as_time(&options),
+            Ok(Time::constant(19, 0, 0, 0))
+        );
+        assert_eq!(
+            TimeSpoken::OClock(8).as_time(&options),
+            Ok(Time::constant(8, 0, 0, 0))
+        );
+        assert_eq!(
+            TimeSpoken::OClock(12).as_time(&options),
+            Ok(Time::constant(12, 0, 0, 0))
+        );
+    }
+
+    #[test]
+    fn find_time_half_english() {
+        let (unit, start, end) =
+            find_time_with_options(" half five @ Pub", &ParserOptions::default())
+                .expect("parse failed");
+        assert_eq!(
+            unit,
+            TimeUnit::Spoken(TimeSpoken::Half(DateRelativeLanguage::English, 5))
+        );
+        assert_eq!(start, 1);
+        assert_eq!(end, 10);
+        assert_eq!(
+            unit.as_time(&ParserOptions::default()),
+            Ok(Time::constant(5, 30, 0, 0))
+        );
+    }
+    #[test]
+    fn find_time_half_finnish() {
+        let (unit, start, end) = find_time_with_options(" puoli kuusi", &ParserOptions::default())
+            .expect("parse failed");
+        assert_eq!(
+            unit,
+            TimeUnit::Spoken(TimeSpoken::Half(DateRelativeLanguage::Finnish, 6))
+        );
+        assert_eq!(start, 1);
+        assert_eq!(end, 12);
+        assert_eq!(
+            unit.as_time(&ParserOptions::default()),
+            Ok(Time::constant(5, 30, 0, 0))
+        );
+    }
+    #[test]
+    fn half_daytime_meridiem() {
+        let options = ParserOptions {
+            meridiem: MeridiemInference::Daytime {
+                first_morning_hour: 8,
+            },
+            ..Default::default()
+        };
+        let english = TimeSpoken::Half(DateRelativeLanguage::English, 5);
+        let finnish = TimeSpoken::Half(DateRelativeLanguage::Finnish, 6);
+        assert_eq!(english.as_time(&options), Ok(Time::constant(17, 30, 0, 0)));
+        assert_eq!(finnish.as_time(&options), Ok(Time::constant(17, 30, 0, 0)));
+    }
+    #[test]
+    fn find_time_half_past_is_not_half() {
+        let (unit, _, _) = find_time_with_options(" half past five", &ParserOptions::default())
+            .expect("parse failed");
+        assert_eq!(unit, TimeUnit::Spoken(TimeSpoken::Past(30, 5)));
+    }
+
+    #[test]
+    fn find_time_spoken_digits_are_not_minutes() {
+        let (unit, _, _) =
+            find_time_with_options(" 10 to 12", &ParserOptions::default()).expect("parse failed");
+        assert_eq!(unit, TimeUnit::Structured(TimeStructured::H(10)));
+    }
+
+    #[test]
+    fn find_time_klo_a() {
+        let (unit, start, end) =
+            find_time_with_options(" klo 16", &ParserOptions::default()).expect("parse failed");
+        assert_eq!(unit, TimeUnit::Structured(TimeStructured::H(16)));
+        assert_eq!(start, 1);
+        assert_eq!(end, 7);
+    }
+    #[test]
+    fn find_time_klo_b() {
+        let (unit, start, end) =
+            find_time_with_options(" Kello 16.30, Kirjasto", &ParserOptions::default())
+                .expect("parse failed");
+        assert_eq!(unit, TimeUnit::Structured(TimeStructured::Hm(16, 30)));
+        assert_eq!(start, 1);
+        assert_eq!(end, 12);
+    }
+    #[test]
+    fn find_time_klo_c() {
+        let (unit, start, end) =
+            find_time_with_options(" klo. 8:15", &ParserOptions::default()).expect("parse failed");
+        assert_eq!(unit, TimeUnit::Structured(TimeStructured::Hm(8, 15)));
+        assert_eq!(start, 1);
+        assert_eq!(end, 10);
+    }
+
+    #[test]
+    fn find_time_day_part_a() {
+        let (unit, start, end) =
+            find_time_with_options(" morning @ Dentist", &ParserOptions::default())
+                .expect("parse failed");
+        assert_eq!(unit, TimeUnit::DayPart(DayPart::Morning));
+        assert_eq!(start, 1);
+        assert_eq!(end, 8);
+        assert_eq!(
+            unit.as_time(&ParserOptions::default()),
+            Ok(Time::constant(9, 0, 0, 0))
+        );
+    }
+    #[test]
+    fn find_time_day_part_b() {
+        let (unit, start, end) =
+            find_time_with_options(" Illalla", &ParserOptions::default()).expect("parse failed");
+        assert_eq!(unit, TimeUnit::DayPart(DayPart::Evening));
+        assert_eq!(start, 1);
+        assert_eq!(end, 8);
+    }
+    #[test]
+    fn find_time_day_part_meal() {
+        let (unit, start, end) =
+            find_time_with_options(" lunchtime @ Cafe", &ParserOptions::default())
+                .expect("parse failed");
+        assert_eq!(unit, TimeUnit::DayPart(DayPart::Lunch));
+        assert_eq!(start, 1);
+        assert_eq!(end, 10);
+        assert_eq!(
+            unit.as_time(&ParserOptions::default()),
+            Ok(Time::constant(12, 0, 0, 0))
+        );
+    }
+    #[test]
+    fn find_time_day_part_meal_finnish() {
+        let (unit, _, _) = find_time_with_options(" lounasaikaan", &ParserOptions::default())
+            .expect("parse failed");
+        assert_eq!(unit, TimeUnit::DayPart(DayPart::Lunch));
+    }
+
+    #[test]
+    fn find_compact_duration_parenthesized() {
+        let (duration, end) = find_compact_duration(" (2h) @ A769").expect("parse failed");
+        assert_eq!(duration.fieldwise(), 2.hours().fieldwise());
+        assert_eq!(end, 5);
+    }
+    #[test]
+    fn find_compact_duration_hours_minutes() {
+        let (duration, end) = find_compact_duration(" 2h30").expect("parse failed");
+        assert_eq!(duration.fieldwise(), 2.hours().minutes(30).fieldwise());
+        assert_eq!(end, 5);
+    }
+    #[test]
+    fn find_compact_duration_minutes() {
+        let (duration, end) = find_compact_duration(" 90min, Gym").expect("parse failed");
+        assert_eq!(duration.fieldwise(), 90.minutes().fieldwise());
+        assert_eq!(end, 6);
+    }
+    #[test]
+    fn find_compact_duration_m() {
+        let (duration, _) = find_compact_duration(" 45m").expect("parse failed");
+        assert_eq!(duration.fieldwise(), 45.minutes().fieldwise());
+    }
+    #[test]
+    fn find_duration_for_hours() {
+        let (duration, end) = find_duration(" for 2 hours @ A769").expect("parse failed");
+        assert_eq!(duration.fieldwise(), 2.hours().fieldwise());
+        assert_eq!(end, 12);
+    }
+    #[test]
+    fn find_duration_for_minutes() {
+        let (duration, end) = find_duration(" for 90 minutes, Gym").expect("parse failed");
+        assert_eq!(duration.fieldwise(), 90.minutes().fieldwise());
+        assert_eq!(end, 15);
+    }
+    #[test]
+    fn find_duration_bare() {
+        let (duration, end) = find_duration(" 2 hours").expect("parse failed");
+        assert_eq!(duration.fieldwise(), 2.hours().fieldwise());
+        assert_eq!(end, 8);
+    }
+    #[test]
+    fn find_duration_days() {
+        let (duration, _) = find_duration(" for 3 days").expect("parse failed");
+        assert_eq!(duration.fieldwise(), 3.days().fieldwise());
+    }
+    #[test]
+    fn find_duration_weeks() {
+        let (duration, end) = find_duration(" for 2 weeks").expect("parse failed");
+        assert_eq!(duration.fieldwise(), 14.days().fieldwise());
+        assert_eq!(end, 12);
+    }
+    #[test]
+    fn find_duration_week_and_a_half() {
+        let (duration, _) = find_duration(" a week and a half").expect("parse failed");
+        assert_eq!(duration.fieldwise(), 10.days().hours(12).fieldwise());
+    }
+    #[test]
+    fn find_duration_weeks_finnish() {
+        let (duration, _) = find_duration(" 2 viikkoa").expect("parse failed");
+        assert_eq!(duration.fieldwise(), 14.days().fieldwise());
+    }
+    #[test]
+    fn find_duration_week_finnish_single() {
+        let (duration, end) = find_duration(" viikon ajan").expect("parse failed");
+        assert_eq!(duration.fieldwise(), 7.days().fieldwise());
+        assert_eq!(end, 12);
+    }
+    #[test]
+    fn find_duration_seconds() {
+        let (duration, end) = find_duration(" for 90 seconds").expect("parse failed");
+        assert_eq!(duration.fieldwise(), 90.seconds().fieldwise());
+        assert_eq!(end, 15);
+    }
+    #[test]
+    fn find_duration_seconds_finnish() {
+        let (duration, _) = find_duration(" 30 sekuntia").expect("parse failed");
+        assert_eq!(duration.fieldwise(), 30.seconds().fieldwise());
+        assert!(find_duration(" half a second").is_none());
+    }
+    #[test]
+    fn find_duration_article() {
+        let (duration, end) = find_duration(" for an hour").expect("parse failed");
+        assert_eq!(duration.fieldwise(), 1.hour().fieldwise());
+        assert_eq!(end, 12);
+    }
+    #[test]
+    fn find_duration_for_compact() {
+        let (duration, end) = find_duration(" for 2h30 @ Lab").expect("parse failed");
+        assert_eq!(duration.fieldwise(), 2.hours().minutes(30).fieldwise());
+        assert_eq!(end, 9);
+    }
+    #[test]
+    fn find_duration_half_hour() {
+        let (duration, end) = find_duration(" half an hour @ Lab").expect("parse failed");
+        assert_eq!(duration.fieldwise(), 30.minutes().fieldwise());
+        assert_eq!(end, 13);
+    }
+    #[test]
+    fn find_duration_finnish() {
+        let (duration, end) = find_duration(" 2 tuntia, Neukkari").expect("parse failed");
+        assert_eq!(duration.fieldwise(), 2.hours().fieldwise());
+        assert_eq!(end, 9);
+        let (minutes, _) = find_duration(" 45 minuuttia").expect("parse failed");
+        assert_eq!(minutes.fieldwise(), 45.minutes().fieldwise());
+    }
+    #[test]
+    fn find_duration_finnish_half_hour() {
+        let (duration, end) = find_duration(" puoli tuntia").expect("parse failed");
+        assert_eq!(duration.fieldwise(), 30.minutes().fieldwise());
+        assert_eq!(end, 13);
+    }
+    #[test]
+    fn find_duration_finnish_single() {
+        let (duration, end) = find_duration(" tunnin ajan @ Sali").expect("parse failed");
+        assert_eq!(duration.fieldwise(), 1.hour().fieldwise());
+        assert_eq!(end, 12);
+        let (two, _) = find_duration(" 2 tunnin ajan").expect("parse failed");
+        assert_eq!(two.fieldwise(), 2.hours().fieldwise());
+    }
+    #[test]
+    fn find_duration_invalid() {
+        assert!(find_duration(" for 0 minutes").is_none());
+        assert!(find_duration(" for 2 people").is_none());
+        assert!(find_duration(" 2 hoursish").is_none());
+        assert!(find_duration(" hours").is_none());
+        assert!(find_duration(" puoli minuuttia").is_none());
+        assert!(find_duration(" for 2 tuntia").is_none());
+    }
+    #[test]
+    fn find_duration_an_hour() {
+        let (duration, end) = find_duration(" an hour @ Lab").expect("parse failed");
+        assert_eq!(duration.fieldwise(), 1.hour().fieldwise());
+        assert_eq!(end, 8);
+    }
+    #[test]
+    fn find_duration_and_a_half() {
+        let (duration, end) = find_duration(" an hour and a half, Lab").expect("parse failed");
+        assert_eq!(duration.fieldwise(), 1.hour().minutes(30).fieldwise());
+        assert_eq!(end, 19);
+        let (two, two_end) = find_duration(" two and a half hours").expect("parse failed");
+        assert_eq!(two.fieldwise(), 2.hours().minutes(30).fieldwise());
+        assert_eq!(two_end, 21);
+        assert!(find_duration(" a minute and a half").is_none());
+    }
+    #[test]
+    fn find_duration_number_words() {
+        let (duration, end) = find_duration(" two hours").expect("parse failed");
+        assert_eq!(duration.fieldwise(), 2.hours().fieldwise());
+        assert_eq!(end, 10);
+        let (ninety, _) = find_duration(" for ninety minutes").expect("parse failed");
+        assert_eq!(ninety.fieldwise(), 90.minutes().fieldwise());
+        let (compound, _) = find_duration(" forty-five minutes").expect("parse failed");
+        assert_eq!(compound.fieldwise(), 45.minutes().fieldwise());
+        assert!(find_duration(" kaksi hours").is_none());
+        assert!(find_duration(" two tuntia").is_none());
+    }
+    #[test]
+    fn find_trailing_duration_after_location() {
+        let (duration, start) = find_trailing_duration("A769 for 45 min").expect("parse failed");
+        assert_eq!(duration.fieldwise(), 45.minutes().fieldwise());
+        assert_eq!(start, 4);
+        assert!(find_trailing_duration("Diner for 2").is_none());
+        assert!(find_trailing_duration("A769 for 2 hours tops").is_none());
+    }
+    #[test]
+    fn find_time_skips_duration_amount() {
+        assert!(find_time_with_options(" for 2 hours", &ParserOptions::default()).is_none());
+    }
+    #[test]
+    fn find_compact_duration_decimal_hours() {
+        let (duration, end) = find_compact_duration(" 1.5h @ Lab").expect("parse failed");
+        assert_eq!(duration.fieldwise(), 1.hour().minutes(30).fieldwise());
+        assert_eq!(end, 5);
+        let (quarter, _) = find_compact_duration(" 0.25h").expect("parse failed");
+        assert_eq!(quarter.fieldwise(), 15.minutes().fieldwise());
+    }
+    #[test]
+    fn find_compact_duration_combined() {
+        let (duration, _) = find_compact_duration(" 1h30m").expect("parse failed");
+        assert_eq!(duration.fieldwise(), 1.hour().minutes(30).fieldwise());
+    }
+    #[test]
+    fn find_compact_duration_seconds() {
+        let (duration, end) = find_compact_duration(" 30s @ Lab").expect("parse failed");
+        assert_eq!(duration.fieldwise(), 30.seconds().fieldwise());
+        assert_eq!(end, 4);
+        let (sec, _) = find_compact_duration(" (45sec)").expect("parse failed");
+        assert_eq!(sec.fieldwise(), 45.seconds().fieldwise());
+        assert!(find_compact_duration(" 1990s").is_none());
+        assert!(find_compact_duration(" 5th").is_none());
+        assert!(find_compact_duration(" 0s").is_none());
+    }
+    #[test]
+    fn find_compact_duration_decimal_comma() {
+        let (duration, end) = find_compact_duration(" 1,5h, A769").expect("parse failed");
+        assert_eq!(duration.fieldwise(), 1.hour().minutes(30).fieldwise());
+        assert_eq!(end, 5);
+        let (after_comma, comma_end) = find_compact_duration(", 1,5h").expect("parse failed");
+        assert_eq!(after_comma.fieldwise(), 1.hour().minutes(30).fieldwise());
+        assert_eq!(comma_end, 6);
+        assert!(find_compact_duration(",A769").is_none());
+        assert!(find_compact_duration(" 1,h").is_none());
+    }
+    #[test]
+    fn find_compact_duration_invalid() {
+        assert!(find_compact_duration(" 2h75").is_none());
+        assert!(find_compact_duration(" (2h").is_none());
+        assert!(find_compact_duration(" 0min").is_none());
+        assert!(find_compact_duration(" 0.0h").is_none());
+        assert!(find_compact_duration(" 1.5").is_none());
+        assert!(find_compact_duration(" 2 hours").is_none());
+        assert!(find_compact_duration("2h").is_none());
+    }
+
+    /// Returns the fixed offset of the time zone found in `s`
+    fn find_time_zone_offset(s: &str) -> Option<Offset> {
+        find_time_zone(s).and_then(|(zone, _)| zone.tz.to_fixed_offset().ok())
+    }
+
+    #[test]
+    fn find_time_zone_utc() {
+        let (zone, end) = find_time_zone(" UTC @ Meet").expect("parse failed");
+        assert_eq!(zone.name, "UTC");
+        assert_eq!(zone.tz, TimeZone::fixed(Offset::UTC));
+        assert_eq!(end, 4);
+    }
+    #[test]
+    fn find_time_zone_abbreviation() {
+        assert_eq!(find_time_zone_offset(" EET"), Some(Offset::constant(2)));
+        assert_eq!(find_time_zone_offset(" CDT"), Some(Offset::constant(-5)));
+    }
+    #[test]
+    fn find_time_zone_fixed_offset() {
+        let (zone, end) = find_time_zone(" +02:00, Room 4").expect("parse failed");
+        assert_eq!(zone.name, "+02:00");
+        assert_eq!(zone.tz, TimeZone::fixed(Offset::constant(2)));
+        assert_eq!(end, 7);
+    }
+    #[test]
+    fn find_time_zone_fixed_offset_minutes() {
+        assert_eq!(
+            find_time_zone_offset(" +0530"),
+            Some(Offset::from_seconds(5 * 3600 + 30 * 60).unwrap())
+        );
+    }
+    #[test]
+    fn find_time_zone_prefixed_offset() {
+        assert_eq!(find_time_zone_offset(" UTC-5"), Some(Offset::constant(-5)));
+        assert_eq!(find_time_zone_offset(" GMT+3"), Some(Offset::constant(3)));
+    }
+    #[test]
+    fn find_time_zone_iana() {
+        let (zone, end) = find_time_zone(" Europe/Helsinki @ Meet").expect("parse failed");
+        assert_eq!(zone.name, "Europe/Helsinki");
+        assert_eq!(zone.tz, TimeZone::get("Europe/Helsinki").unwrap());
+        assert_eq!(end, 16);
+    }
+    #[test]
+    fn find_time_zone_iana_case_insensitive() {
+        let (zone, _) = find_time_zone(" america/new_york").expect("parse failed");
+        assert_eq!(zone.name, "America/New_York");
+    }
+    #[test]
+    fn find_time_zone_iana_unknown() {
+        assert!(find_time_zone(" Europe/Atlantis").is_none());
+        assert!(find_time_zone(" 3/4").is_none());
+    }
+    #[test]
+    fn find_time_zone_invalid() {
+        assert!(find_time_zone(" Room").is_none());
+        assert!(find_time_zone(" ABC").is_none());
+        assert!(find_time_zone(" +25:00").is_none());
+        assert!(find_time_zone(" +02:75").is_none());
+        assert!(find_time_zone("UTC").is_none());
+    }
+
+    #[test]
+    fn find_range_end_a() {
+        let (unit, end) = find_range_end("-12:30 @ A769", false).expect("parse failed");
+        assert_eq!(unit, TimeUnit::Structured(TimeStructured::Hm(12, 30)));
+        assert_eq!(end, 6);
+    }
+    #[test]
+    fn find_range_end_b() {
+        let (unit, end) = find_range_end(" – 13", false).expect("parse failed");
+        assert_eq!(unit, TimeUnit::Structured(TimeStructured::H(13)));
+        assert_eq!(end, 7);
+    }
+    #[test]
+    fn find_range_end_c() {
+        let (unit, end) = find_range_end(" To 1:00, Home", false).expect("parse failed");
+        assert_eq!(unit, TimeUnit::Structured(TimeStructured::Hm(1, 0)));
+        assert_eq!(end, 8);
+    }
+    #[test]
+    fn find_range_end_d() {
+        let (unit, end) = find_range_end(" until 13 @ Office", false).expect("parse failed");
+        assert_eq!(unit, TimeUnit::Structured(TimeStructured::H(13)));
+        assert_eq!(end, 9);
+    }
+    #[test]
+    fn find_range_end_till() {
+        let (unit, end) = find_range_end(" till 17:00 @ Main hall", false).expect("parse failed");
+        assert_eq!(unit, TimeUnit::Structured(TimeStructured::Hm(17, 0)));
+        assert_eq!(end, 11);
+    }
+    #[test]
+    fn find_range_end_none() {
+        assert!(find_range_end(" @ A769", false).is_none());
+        assert!(find_range_end(" to the office", false).is_none());
+        assert!(find_range_end("", false).is_none());
+    }
+    #[test]
+    fn find_range_end_and() {
+        let (unit, end) = find_range_end(" and 3 pm @ Lobby", true).expect("parse failed");
+        assert_eq!(unit, TimeUnit::Meridiem(TimeStructured::H(3), Meridiem::Pm));
+        assert_eq!(end, 9);
+        assert!(find_range_end(" and 3 pm", false).is_none());
+    }
+    #[test]
+    fn find_time_between() {
+        let (unit, start, end) =
+            find_time_with_options(" between 2 and 3", &ParserOptions::default())
+                .expect("parse failed");
+        assert_eq!(unit, TimeUnit::Structured(TimeStructured::H(2)));
+        assert_eq!(start, 1);
+        assert_eq!(end, 10);
+    }
+    #[test]
+    fn find_time_meridiem_word() {
+        let (unit, start, end) =
+            find_time_with_options(" at 3 p.m. @ Cafe", &ParserOptions::default())
+                .expect("parse failed");
+        assert_eq!(unit, TimeUnit::Meridiem(TimeStructured::H(3), Meridiem::Pm));
+        assert_eq!(start, 4);
+        assert_eq!(end, 10);
+    }
+
+    #[test]
+    fn find_relative_time_a() {
+        let (unit, start, end) =
+            find_relative_time("Take the pizza out in 25 minutes").expect("parse failed");
+        assert_eq!(
+            unit,
+            TimeRelative::Minutes(DateRelativeLanguage::English, 25)
+        );
+        assert_eq!(start, 19);
+        assert_eq!(end, 32);
+    }
+    #[test]
+    fn find_relative_time_large_amount() {
+        let (unit, _, _) = find_relative_time("Call in 150 minutes").expect("parse failed");
+        assert_eq!(
+            unit,
+            TimeRelative::Minutes(DateRelativeLanguage::English, 150)
+        );
+        assert!(unit.as_span().unwrap().fieldwise() == 150.minutes());
+        let (too_large, _, _) =
+            find_relative_time("Call in 99999999999 hours").expect("parse failed");
+        assert_eq!(
+            too_large.as_span().err(),
+            Some(EventParseError::invalid_time())
+        );
+    }
+    #[test]
+    fn find_relative_time_b() {
+        let (unit, start, end) =
+            find_relative_time("Call back in an hour, office").expect("parse failed");
+        assert_eq!(unit, TimeRelative::Hours(DateRelativeLanguage::English, 1));
+        assert_eq!(start, 10);
+        assert_eq!(end, 20);
+    }
+    #[test]
+    fn find_relative_time_c() {
+        let (unit, start, end) = find_relative_time("Soita 2 tunnin päästä").expect("parse failed");
+        assert_eq!(unit, TimeRelative::Hours(DateRelativeLanguage::Finnish, 2));
+        assert_eq!(start, 6);
+        assert_eq!(end, 24);
+    }
+    #[test]
+    fn find_relative_time_d() {
+        let (unit, start, end) =
+            find_relative_time("Pizza uunista puolen tunnin kuluttua").expect("parse failed");
+        assert_eq!(
+            unit,
+            TimeRelative::Minutes(DateRelativeLanguage::Finnish, 30)
+        );
+        assert_eq!(start, 14);
+        assert_eq!(end, 36);
+    }
+    #[test]
+    fn find_relative_time_none() {
+        assert!(find_relative_time("Meeting in Helsinki").is_none());
+        assert!(find_relative_time("Sauna 2 tuntia").is_none());
+    }
+
+    #[test]
+    fn find_time_from() {
+        let (unit, start, end) =
+            find_time_with_options(" from 11:00 to 13:00", &ParserOptions::default())
+                .expect("parse failed");
+        assert_eq!(unit, TimeUnit::Structured(TimeStructured::Hm(11, 0)));
+        assert_eq!(start, 1);
+        assert_eq!(end, 11);
+    }
+
+    #[test]
+    fn find_time_skip_out_of_range_a() {
+        let (unit, start, end) =
+            find_time_with_options(" 99 Main St at 18:00", &ParserOptions::default())
+                .expect("parse failed");
+        assert_eq!(unit, TimeUnit::Structured(TimeStructured::Hm(18, 0)));
+        assert_eq!(start, 15);
+        assert_eq!(end, 20);
+    }
+    #[test]
+    fn find_time_skip_out_of_range_b() {
+        assert!(find_time_with_options(" 45 Elm Street", &ParserOptions::default()).is_none());
+    }
+    #[test]
+    fn find_time_skip_out_of_range_c() {
+        let (unit, start, end) =
+            find_time_with_options(" 25:00 room", &ParserOptions::default()).expect("parse failed");
+        assert_eq!(unit, TimeUnit::Structured(TimeStructured::Hm(25, 0)));
+        assert_eq!(start, 1);
+        assert_eq!(end, 6);
+        assert!(!unit.is_valid());
+    }
+    #[test]
+    fn find_time_skip_out_of_range_d() {
+        let (unit, _, _) = find_time_with_options(" 25:00 or 18:30", &ParserOptions::default())
+            .expect("parse failed");
+        assert_eq!(unit, TimeUnit::Structured(TimeStructured::Hm(18, 30)));
+    }
+
+    #[test]
+    fn split_meridiem_suffixes() {
+        assert_eq!(split_meridiem("4pm"), ("4", Some(Meridiem::Pm)));
+        assert_eq!(split_meridiem("11:30AM"), ("11:30", Some(Meridiem::Am)));
+        assert_eq!(split_meridiem("9a.m."), ("9", Some(Meridiem::Am)));
+        assert_eq!(split_meridiem("spam"), ("spam", None));
+        assert_eq!(split_meridiem("16:00"), ("16:00", None));
+    }
+    #[test]
+    fn find_time_meridiem() {
+        let (unit, start, end) =
+            find_time_with_options(" 12am", &ParserOptions::default()).expect("parse failed");
+        assert_eq!(
+            unit,
+            TimeUnit::Meridiem(TimeStructured::H(12), Meridiem::Am)
+        );
+        assert_eq!(start, 1);
+        assert_eq!(end, 5);
+        assert_eq!(
+            unit.as_time(&ParserOptions::default()),
+            Ok(Time::midnight())
+        );
+    }
+    #[test]
+    fn meridiem_out_of_range() {
+        let unit = TimeUnit::Meridiem(TimeStructured::H(13), Meridiem::Pm);
+        assert_eq!(
+            unit.as_time(&ParserOptions::default()),
+            Err(EventParseError::invalid_time())
+        );
+    }
+    #[test]
+    fn share_meridiem_same_side() {
+        let options = ParserOptions::default();
+        let end = TimeUnit::Meridiem(TimeStructured::H(4), Meridiem::Pm);
+        let start = share_meridiem(TimeUnit::Structured(TimeStructured::H(2)), &end, &options);
+        assert_eq!(
+            start,
+            TimeUnit::Meridiem(TimeStructured::H(2), Meridiem::Pm)
+        );
+    }
+    #[test]
+    fn share_meridiem_crossing_noon() {
+        let options = ParserOptions::default();
+        let end = TimeUnit::Meridiem(TimeStructured::H(1), Meridiem::Pm);
+        let start = share_meridiem(TimeUnit::Structured(TimeStructured::H(11)), &end, &options);
+        assert_eq!(
+            start,
+            TimeUnit::Meridiem(TimeStructured::H(11), Meridiem::Am)
+        );
+    }
+    #[test]
+    fn share_meridiem_24_hour_start() {
+        let options = ParserOptions::default();
+        let end = TimeUnit::Meridiem(TimeStructured::H(5), Meridiem::Pm);
+        let start = share_meridiem(TimeUnit::Structured(TimeStructured::H(14)), &end, &options);
+        assert_eq!(start, TimeUnit::Structured(TimeStructured::H(14)));
+    }
+
+    #[test]
+    fn find_time_named_a() {
+        let (unit, start, end) =
+            find_time_with_options(" at noon", &ParserOptions::default()).expect("parse failed");
+        assert_eq!(
+            unit,
+            TimeUnit::Named(TimeNamed::Noon(DateRelativeLanguage::English))
+        );
+        assert_eq!(start, 4);
+        assert_eq!(end, 8);
+        assert_eq!(
+            unit.as_time(&ParserOptions::default()),
+            Ok(Time::constant(12, 0, 0, 0))
+        );
+    }
+    #[test]
+    fn find_time_named_b() {
+        let (unit, start, end) =
+            find_time_with_options(" Midnight @ Server room", &ParserOptions::default())
+                .expect("parse failed");
+        assert_eq!(
+            unit,
+            TimeUnit::Named(TimeNamed::Midnight(DateRelativeLanguage::English))
+        );
+        assert_eq!(start, 1);
+        assert_eq!(end, 9);
+        assert_eq!(
+            unit.as_time(&ParserOptions::default()),
+            Ok(Time::midnight())
+        );
+    }
+    #[test]
+    fn find_time_named_c() {
+        let (unit, start, end) =
+            find_time_with_options(" keskipäivällä", &ParserOptions::default())
+                .expect("parse failed");
+        assert_eq!(
+            unit,
+            TimeUnit::Named(TimeNamed::Noon(DateRelativeLanguage::Finnish))
+        );
+        assert_eq!(start, 1);
+        assert_eq!(end, 17);
+    }
+    #[test]
+    fn find_time_named_d() {
+        let (unit, start, end) =
+            find_time_with_options(" midday", &ParserOptions::default()).expect("parse failed");
+        assert_eq!(
+            unit,
+            TimeUnit::Named(TimeNamed::Noon(DateRelativeLanguage::English))
+        );
+        assert_eq!(start, 1);
+        assert_eq!(end, 7);
+    }
+    #[test]
+    fn find_time_named_end_of_day() {
+        let (unit, start, end) =
+            find_time_with_options(" EOD", &ParserOptions::default()).expect("parse failed");
+        assert_eq!(unit, TimeUnit::Named(TimeNamed::EndOfDay));
+        assert_eq!(start, 1);
+        assert_eq!(end, 4);
+        assert_eq!(
+            unit.as_time(&ParserOptions::default()),
+            Ok(Time::constant(17, 0, 0, 0))
+        );
+    }
+    #[test]
+    fn find_time_named_close_of_business() {
+        let (unit, _, _) =
+            find_time_with_options(" cob", &ParserOptions::default()).expect("parse failed");
+        assert_eq!(unit, TimeUnit::Named(TimeNamed::CloseOfBusiness));
+        let options = ParserOptions {
+            close_of_business: Time::constant(16, 30, 0, 0),
+            ..Default::default()
+        };
+        assert_eq!(unit.as_time(&options), Ok(Time::constant(16, 30, 0, 0)));
+    }
 }
@@ -0,0 +1,127 @@
+//! Soft issues noticed during a successful parse
+
+use serde::{Deserialize, Serialize};
+
+use crate::NewEvent;
+
+/// Something the parser had to assume to produce an event, which a user interface may want to
+/// point out, as in "Assumed 3rd of April".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "wasm", derive(tsify::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+pub enum ParseWarning {
+    /// The date could be read in either order, as with "3/4", and was resolved using
+    /// [`crate::ParserOptions::date_order`]
+    AmbiguousDate,
+    /// The date had a two digit year, as with "18.11.24", which was placed in `year` using
+    /// [`crate::ParserOptions::two_digit_year_pivot`]
+    TwoDigitYear {
+        /// The full year the two digit year was resolved to
+        year: i16,
+    },
+    /// The time was a vague time of day such as "morning", resolved to the clock time configured
+    /// in [`crate::ParserOptions::day_parts`]
+    VagueTime,
+    /// The time was marked as approximate, as in "around 5" or "5ish"
+    ApproximateTime,
+}
+
+/// A [`NewEvent`] along with any [`ParseWarning`]s, see [`NewEvent::parse_with_warnings`].
+/// ```rust
+/// use jiff::civil::date;
+/// use nlcep::{NewEvent, ParseWarning, ParserOptions};
+/// let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+/// let outcome =
+///     NewEvent::parse_with_warnings("Dentist 3/4 10:00", now, ParserOptions::default()).unwrap();
+/// assert_eq!(outcome.warnings, vec![ParseWarning::AmbiguousDate]);
+/// ```
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "wasm", derive(tsify::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+pub struct ParseOutcome {
+    /// The parsed event
+    pub event: NewEvent,
+    /// What the parser had to assume, in the order the date and time were read
+    pub warnings: Vec<ParseWarning>,
+}
+
+/// Collects the warnings for a parsed `event`. `ambiguous_date` and `short_year` are as reported
+/// by [`crate::find_datetime_with_options`].
+pub(crate) fn warnings(
+    event: &NewEvent,
+    ambiguous_date: bool,
+    short_year: bool,
+) -> Vec<ParseWarning> {
+    let mut warnings = Vec::new();
+    if ambiguous_date {
+        warnings.push(ParseWarning::AmbiguousDate);
+    }
+    if short_year {
+        warnings.push(ParseWarning::TwoDigitYear {
+            year: event.date.year(),
+        });
+    }
+    if event.day_part.is_some() {
+        warnings.push(ParseWarning::VagueTime);
+    }
+    if event.time_approximate {
+        warnings.push(ParseWarning::ApproximateTime);
+    }
+    warnings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use jiff::civil::date;
+
+    use crate::ParserOptions;
+
+    fn parse(s: &str) -> ParseOutcome {
+        let now = date(2024, 6, 1).in_tz("UTC").unwrap();
+        NewEvent::parse_with_warnings(s, now, ParserOptions::default()).unwrap()
+    }
+
+    #[test]
+    fn warnings_none() {
+        assert_eq!(parse("Meetup 18.11.2024 16:00").warnings, vec![]);
+    }
+
+    #[test]
+    fn warnings_ambiguous_slash_date() {
+        let outcome = parse("Dentist 3/4 10:00");
+        assert_eq!(outcome.event.date, date(2025, 4, 3));
+        assert_eq!(outcome.warnings, vec![ParseWarning::AmbiguousDate]);
+        assert_eq!(parse("Dentist 13/4 10:00").warnings, vec![]);
+    }
+
+    #[test]
+    fn warnings_two_digit_year() {
+        let outcome = parse("Reunion 18.11.99");
+        assert_eq!(outcome.event.date, date(1999, 11, 18));
+        assert_eq!(
+            outcome.warnings,
+            vec![ParseWarning::TwoDigitYear { year: 1999 }]
+        );
+        assert_eq!(
+            parse("Party 3/4/25").warnings,
+            vec![
+                ParseWarning::AmbiguousDate,
+                ParseWarning::TwoDigitYear { year: 2025 }
+            ]
+        );
+    }
+
+    #[test]
+    fn warnings_vague_time() {
+        assert_eq!(
+            parse("Run tomorrow morning").warnings,
+            vec![ParseWarning::VagueTime]
+        );
+        assert_eq!(
+            parse("Call tomorrow around 5").warnings,
+            vec![ParseWarning::ApproximateTime]
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use jiff::{tz::TimeZone, civil::DateTime, Timestamp, Zoned};
+use jiff::{civil::DateTime, tz::TimeZone, Timestamp, Zoned};
 use js_sys::Date;
 use serde::{Deserialize, Serialize};
 use tsify::Tsify;
@@ -6,12 +6,10 @@ use wasm_bindgen::prelude::*;
 
 use crate::{EventParseError, NewEvent};
 
-
 #[derive(Debug, Tsify, Serialize, Deserialize)]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct EventResult(Result<NewEvent, EventParseError>);
 
-
 #[wasm_bindgen]
 pub fn parse(string: String) -> EventResult {
     EventResult(string.parse())
@@ -20,7 +18,10 @@ pub fn parse(string: String) -> EventResult {
 #[wasm_bindgen]
 pub fn parse_at_time(string: String, at: Date) -> EventResult {
     let millis = at.get_milliseconds();
-    let now = Zoned::new(Timestamp::from_millisecond(millis as i64).expect("failed to construct Zoned from js Date"), TimeZone::UTC);
+    let now = Zoned::new(
+        Timestamp::from_millisecond(millis as i64).expect("failed to construct Zoned from js Date"),
+        TimeZone::UTC,
+    );
     EventResult(NewEvent::parse_at_time(&string, now))
 }
 
@@ -32,6 +33,11 @@ pub fn to_datetime(event: NewEvent) -> DateTimeWrapper {
     DateTimeWrapper(event.datetime())
 }
 
+#[wasm_bindgen]
+pub fn to_end_datetime(event: NewEvent) -> Option<DateTimeWrapper> {
+    event.end_datetime().map(DateTimeWrapper)
+}
+
 #[wasm_bindgen]
 pub fn set_panic_hook() {
     // When the `console_error_panic_hook` feature is enabled, we can call the